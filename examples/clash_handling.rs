@@ -10,11 +10,13 @@ use leafwing_input_manager::prelude::*;
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugin(InputManagerPlugin::<TestAction>::default())
+        // Change this strategy (or the value of the `ClashStrategy` resource) to change how clashes should be handled in your game
+        .add_plugin(
+            InputManagerPlugin::<TestAction>::new()
+                .with_clash_strategy(ClashStrategy::PrioritizeLongest),
+        )
         .add_startup_system(spawn_input_map)
         .add_system(report_pressed_actions)
-        // Change the value of this resource to change how clashes should be handled in your game
-        .insert_resource(ClashStrategy::PrioritizeLongest)
         .run()
 }
 
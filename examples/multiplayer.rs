@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use leafwing_input_manager::input_map::GamepadAssignment;
 use leafwing_input_manager::prelude::*;
 
 fn main() {
@@ -26,6 +27,7 @@ enum Player {
 #[derive(Bundle)]
 struct PlayerBundle {
     player: Player,
+    gamepad_assignment: GamepadAssignment,
     #[bundle]
     input_manager: InputManagerBundle<Action>,
 }
@@ -37,22 +39,16 @@ impl PlayerBundle {
                 (Action::Left, KeyCode::A),
                 (Action::Right, KeyCode::D),
                 (Action::Jump, KeyCode::W),
-            ])
-            // This is a quick and hacky solution:
-            // you should coordinate with the `Gamepads` resource to determine the correct gamepad for each player
-            // and gracefully handle disconnects
-            .set_gamepad(Gamepad(0))
-            .build(),
+            ]),
             Player::Two => InputMap::new([
                 (Action::Left, KeyCode::A),
                 (Action::Right, KeyCode::D),
                 (Action::Jump, KeyCode::W),
-            ])
-            .set_gamepad(Gamepad(1))
-            .build(),
+            ]),
         };
 
-        // Each player will use the same gamepad controls, but on seperate gamepads
+        // Each player will use the same gamepad controls, but on seperate gamepads,
+        // which are assigned automatically using the `GamepadAssignment` component
         input_map.insert_multiple([
             (Action::Left, GamepadButtonType::DPadLeft),
             (Action::Right, GamepadButtonType::DPadRight),
@@ -67,6 +63,7 @@ impl PlayerBundle {
 fn spawn_players(mut commands: Commands) {
     commands.spawn_bundle(PlayerBundle {
         player: Player::One,
+        gamepad_assignment: GamepadAssignment { slot: 0 },
         input_manager: InputManagerBundle {
             input_map: PlayerBundle::input_map(Player::One),
             ..Default::default()
@@ -75,6 +72,7 @@ fn spawn_players(mut commands: Commands) {
 
     commands.spawn_bundle(PlayerBundle {
         player: Player::Two,
+        gamepad_assignment: GamepadAssignment { slot: 1 },
         input_manager: InputManagerBundle {
             input_map: PlayerBundle::input_map(Player::Two),
             ..Default::default()
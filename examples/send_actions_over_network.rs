@@ -11,7 +11,6 @@ use bevy_ecs::event::{Events, ManualEventReader};
 use bevy_input::InputPlugin;
 use leafwing_input_manager::action_state::ActionDiff;
 use leafwing_input_manager::prelude::*;
-use leafwing_input_manager::systems::{generate_action_diffs, process_action_diffs};
 use leafwing_input_manager::MockInput;
 
 use std::fmt::Debug;
@@ -35,25 +34,16 @@ fn main() {
     client_app
         .add_plugins(MinimalPlugins)
         .add_plugin(InputPlugin)
-        .add_plugin(InputManagerPlugin::<FpsAction>::default())
         // Creates an event stream of `ActionDiffs` to send to the server
-        .add_system_to_stage(
-            CoreStage::PostUpdate,
-            generate_action_diffs::<FpsAction, StableId>,
-        )
-        .add_event::<ActionDiff<FpsAction, StableId>>()
+        .add_plugin(InputManagerPlugin::<FpsAction>::new().with_action_diffs::<StableId>())
         .add_startup_system(spawn_player);
 
     let mut server_app = App::new();
     server_app
         .add_plugins(MinimalPlugins)
-        .add_plugin(InputManagerPlugin::<FpsAction>::server())
-        .add_event::<ActionDiff<FpsAction, StableId>>()
-        // Reads in the event stream of `ActionDiffs` to update the `ActionState`
-        .add_system_to_stage(
-            CoreStage::PreUpdate,
-            process_action_diffs::<FpsAction, StableId>,
-        )
+        // Reads in the event stream of `ActionDiffs` to update the `ActionState`,
+        // without processing any inputs on the server itself
+        .add_plugin(InputManagerPlugin::<FpsAction>::server().with_action_diffs::<StableId>())
         // Typically, the rest of this information would synchronized as well
         .add_startup_system(spawn_player);
 
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use leafwing_input_manager::prelude::*;
-use leafwing_input_manager::{errors::NearlySingularConversion, orientation::Direction};
+use leafwing_input_manager::user_input::InputButton;
+use leafwing_input_manager::{action_state::DirectionalAxis, orientation::Direction};
 
 fn main() {
     App::new()
@@ -28,6 +29,7 @@ enum ArpgAction {
     Down,
     Left,
     Right,
+    Move,
     // Abilities
     Ability1,
     Ability2,
@@ -37,23 +39,13 @@ enum ArpgAction {
 }
 
 impl ArpgAction {
-    // Lists like this can be very useful for quickly matching subsets of actions
-    const DIRECTIONS: [Self; 4] = [
-        ArpgAction::Up,
-        ArpgAction::Down,
-        ArpgAction::Left,
-        ArpgAction::Right,
-    ];
-
-    fn direction(self) -> Option<Direction> {
-        match self {
-            ArpgAction::Up => Some(Direction::NORTH),
-            ArpgAction::Down => Some(Direction::SOUTH),
-            ArpgAction::Left => Some(Direction::EAST),
-            ArpgAction::Right => Some(Direction::WEST),
-            _ => None,
-        }
-    }
+    // Groups like this can be very useful for quickly working with subsets of actions
+    const MOVEMENT: DirectionalAxis<Self> = DirectionalAxis {
+        up: ArpgAction::Up,
+        down: ArpgAction::Down,
+        left: ArpgAction::Left,
+        right: ArpgAction::Right,
+    };
 }
 
 #[derive(Component)]
@@ -81,46 +73,45 @@ impl PlayerBundle {
         input_map.set_gamepad(Gamepad(0));
 
         // Movement
-        input_map.insert(Up, KeyCode::Up);
-        input_map.insert(Up, GamepadButtonType::DPadUp);
-
-        input_map.insert(Down, KeyCode::Down);
-        input_map.insert(Down, GamepadButtonType::DPadDown);
-
-        input_map.insert(Left, KeyCode::Left);
-        input_map.insert(Left, GamepadButtonType::DPadLeft);
+        for (action, key, button) in [
+            (Up, KeyCode::Up, GamepadButtonType::DPadUp),
+            (Down, KeyCode::Down, GamepadButtonType::DPadDown),
+            (Left, KeyCode::Left, GamepadButtonType::DPadLeft),
+            (Right, KeyCode::Right, GamepadButtonType::DPadRight),
+        ] {
+            input_map.insert_one_to_many(action, [InputButton::from(key), button.into()]);
+        }
 
-        input_map.insert(Right, KeyCode::Right);
-        input_map.insert(Right, GamepadButtonType::DPadRight);
+        // Analog movement can be read directly from the stick
+        input_map.insert(Move, UserInput::left_stick());
 
         // Abilities
-        input_map.insert(Ability1, KeyCode::Q);
-        input_map.insert(Ability1, GamepadButtonType::West);
+        for (action, key, button) in [
+            (Ability1, KeyCode::Q, GamepadButtonType::West),
+            (Ability2, KeyCode::W, GamepadButtonType::North),
+            (Ability3, KeyCode::E, GamepadButtonType::East),
+            (Ability4, KeyCode::Space, GamepadButtonType::South),
+            (Ultimate, KeyCode::R, GamepadButtonType::LeftTrigger2),
+        ] {
+            input_map.insert_one_to_many(action, [InputButton::from(key), button.into()]);
+        }
         input_map.insert(Ability1, MouseButton::Left);
-
-        input_map.insert(Ability2, KeyCode::W);
-        input_map.insert(Ability2, GamepadButtonType::North);
         input_map.insert(Ability2, MouseButton::Right);
 
-        input_map.insert(Ability3, KeyCode::E);
-        input_map.insert(Ability3, GamepadButtonType::East);
-
-        input_map.insert(Ability4, KeyCode::Space);
-        input_map.insert(Ability4, GamepadButtonType::South);
-
-        input_map.insert(Ultimate, KeyCode::R);
-        input_map.insert(Ultimate, GamepadButtonType::LeftTrigger2);
-
         input_map
     }
 }
 
 fn spawn_player(mut commands: Commands) {
+    // Registering the movement axis caches its net direction, so every system can cheaply reuse it
+    let mut action_state = ActionState::default();
+    action_state.register_directional_axis(&ArpgAction::MOVEMENT);
+
     commands.spawn_bundle(PlayerBundle {
         player: Player,
         input_manager: InputManagerBundle {
             input_map: PlayerBundle::default_input_map(),
-            action_state: ActionState::default(),
+            action_state,
         },
     });
 }
@@ -137,22 +128,8 @@ fn player_dash(query: Query<&ActionState<ArpgAction>, With<Player>>) {
     let action_state = query.single();
 
     if action_state.just_pressed(ArpgAction::Ability4) {
-        let mut direction_vector = Vec2::ZERO;
-
-        for input_direction in ArpgAction::DIRECTIONS {
-            if action_state.pressed(input_direction) {
-                if let Some(direction) = input_direction.direction() {
-                    // Sum the directions as 2D vectors
-                    direction_vector += Vec2::from(direction);
-                }
-            }
-        }
-
-        // Then reconvert at the end, normalizing the magnitude
-        let net_direction: Result<Direction, NearlySingularConversion> =
-            direction_vector.try_into();
-
-        if let Ok(direction) = net_direction {
+        // Opposing directions cancel out, leaving no direction to dash in
+        if let Some(direction) = action_state.net_direction(&ArpgAction::MOVEMENT) {
             println!("Dashing in {direction:?}");
         }
     }
@@ -168,21 +145,14 @@ fn player_walks(
 ) {
     let action_state = query.single();
 
-    let mut direction_vector = Vec2::ZERO;
-
-    for input_direction in ArpgAction::DIRECTIONS {
-        if action_state.pressed(input_direction) {
-            if let Some(direction) = input_direction.direction() {
-                // Sum the directions as 2D vectors
-                direction_vector += Vec2::from(direction);
-            }
-        }
-    }
-
-    // Then reconvert at the end, normalizing the magnitude
-    let net_direction: Result<Direction, NearlySingularConversion> = direction_vector.try_into();
+    // The stick takes priority, falling back to the buttons if it is at rest
+    // Opposing buttons cancel out, and diagonals are normalized
+    let direction = match action_state.axis_pair(ArpgAction::Move) {
+        Some(axis_pair) => axis_pair.direction(),
+        None => action_state.net_direction(&ArpgAction::MOVEMENT),
+    };
 
-    if let Ok(direction) = net_direction {
+    if let Some(direction) = direction {
         event_writer.send(PlayerWalk { direction });
     }
 }
@@ -2,7 +2,7 @@ use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use proc_macro_crate::{crate_name, FoundCrate};
 use quote::quote;
-use syn::{Data, DeriveInput, Ident};
+use syn::{Data, DeriveInput, Ident, Lit, Meta, NestedMeta, Variant};
 
 /// This approach and implementation is inspired by the `strum` crate,
 /// Copyright (c) 2019 Peter Glotfelty
@@ -42,11 +42,46 @@ pub(crate) fn actionlike_inner(ast: &DeriveInput) -> TokenStream {
     // Populate the array
     let mut get_at_match_items = Vec::new();
     let mut index_match_items = Vec::new();
+    // The index of the first action generated by the current variant
+    let mut index: usize = 0;
 
-    for (index, variant) in variants.iter().enumerate() {
+    for variant in variants.iter() {
         // The name of the enum variant
         let variant_identifier = variant.ident.clone();
 
+        // Variants marked with `#[actionlike(count = N)]` generate one action for each value of their field
+        let count = match variant_count(variant) {
+            Ok(count) => count,
+            Err(error) => return error.to_compile_error(),
+        };
+
+        if let Some(count) = count {
+            let end = index + count;
+            let variant_name = variant_identifier.to_string();
+
+            get_at_match_items.push(quote! {
+                i if (#index..#end).contains(&i) => Some(#enum_name::#variant_identifier((i - #index) as _)),
+            });
+
+            index_match_items.push(quote! {
+                #enum_name::#variant_identifier(value) => {
+                    let value = *value as usize;
+                    assert!(
+                        value < #count,
+                        "The value {} of `{}` is out of bounds: `#[actionlike(count = {})]` only allows values below {}.",
+                        value,
+                        #variant_name,
+                        #count,
+                        #count
+                    );
+                    #index + value
+                }
+            });
+
+            index = end;
+            continue;
+        }
+
         let get_at_params = match &variant.fields {
             // Unit fields have no parameters
             syn::Fields::Unit => quote! {},
@@ -92,9 +127,11 @@ pub(crate) fn actionlike_inner(ast: &DeriveInput) -> TokenStream {
         index_match_items.push(quote! {
             #enum_name::#variant_identifier #index_params => #index,
         });
+
+        index += 1;
     }
 
-    let n_variants = variants.iter().len();
+    let n_variants = index;
 
     quote! {
         impl #impl_generics #crate_path::Actionlike for #enum_name #type_generics #where_clause {
@@ -116,3 +153,56 @@ pub(crate) fn actionlike_inner(ast: &DeriveInput) -> TokenStream {
         }
     }
 }
+
+/// Parses the `N` in the `#[actionlike(count = N)]` attribute of the `variant`, if any
+///
+/// Only variants with a single unnamed field, such as `SelectSlot(u8)`, can be given a count.
+fn variant_count(variant: &Variant) -> syn::Result<Option<usize>> {
+    let mut count = None;
+
+    for attribute in variant
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("actionlike"))
+    {
+        let malformed = || {
+            syn::Error::new_spanned(
+                attribute,
+                "expected `#[actionlike(count = N)]`, where `N` is the number of distinct values of the field",
+            )
+        };
+
+        let nested = match attribute.parse_meta()? {
+            Meta::List(list) => list.nested,
+            _ => return Err(malformed()),
+        };
+
+        for meta in nested.iter() {
+            match meta {
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("count") =>
+                {
+                    match &name_value.lit {
+                        Lit::Int(lit) => count = Some(lit.base10_parse::<usize>()?),
+                        _ => return Err(malformed()),
+                    }
+                }
+                _ => return Err(malformed()),
+            }
+        }
+    }
+
+    if count.is_some() {
+        match &variant.fields {
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &variant.ident,
+                    "`#[actionlike(count = N)]` can only be used on variants with a single unnamed integer field, such as `SelectSlot(u8)`",
+                ))
+            }
+        }
+    }
+
+    Ok(count)
+}
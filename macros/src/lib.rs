@@ -9,7 +9,27 @@ mod actionlike;
 use proc_macro::TokenStream;
 use syn::DeriveInput;
 
-#[proc_macro_derive(Actionlike)]
+/// Derives [`Actionlike`] for enums, assigning each variant an index in declaration order
+///
+/// Variants with fields are a single action each: [`Actionlike::get_at`] constructs them with the [`Default`] values of their fields,
+/// and every value of those fields maps to the same index.
+///
+/// To treat each value of a field as a distinct action, mark a variant with a single unnamed integer field
+/// with `#[actionlike(count = N)]`.
+/// The values `0..N` of the field are then distinct actions, with consecutive indexes,
+/// and are all returned by [`Actionlike::variants`].
+/// Calling [`Actionlike::index`] on a value of `N` or more panics.
+///
+/// ```rust,ignore
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq)]
+/// enum Action {
+///     Jump,
+///     // `SelectSlot(0)` to `SelectSlot(3)` are four separate actions
+///     #[actionlike(count = 4)]
+///     SelectSlot(u8),
+/// }
+/// ```
+#[proc_macro_derive(Actionlike, attributes(actionlike))]
 pub fn actionlike(input: TokenStream) -> TokenStream {
     let ast = syn::parse_macro_input!(input as DeriveInput);
 
@@ -1,14 +1,35 @@
 //! This module contains [`ActionState`] and its supporting methods and impls.
 
+use crate::axislike::{AxisData, DualAxisData};
 use crate::buttonlike::ButtonState;
+use crate::errors::SerializedInputsLength;
+use crate::orientation::Direction;
 use crate::user_input::UserInput;
 use crate::Actionlike;
 
 use bevy_ecs::{component::Component, entity::Entity};
+use bevy_input::Input;
+use bevy_math::Vec2;
 use bevy_utils::{Duration, Instant};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::hash::Hash;
 use std::marker::PhantomData;
 
+/// The number of recent presses of each action whose timestamps are stored, for use in [`ActionState::presses_within`]
+pub const RECENT_PRESS_CAPACITY: usize = 32;
+
+/// The time constant over which the contribution of each press to [`ActionState::mash_rate`] decays
+///
+/// Each press contributes to the rate with an exponentially decaying weight,
+/// falling to about 37% of its initial value after this much time has passed.
+pub const MASH_RATE_TIME_CONSTANT: Duration = Duration::from_millis(500);
+
+/// The smallest change in an analog value that [`generate_action_diffs`](crate::systems::generate_action_diffs) will send as an [`ActionDiff`]
+///
+/// Smaller changes are accumulated until they exceed this threshold, so the remote value never drifts by more than this.
+pub const ACTION_DIFF_EPSILON: f32 = 0.01;
+
 /// Metadata about an [`Actionlike`] action
 ///
 /// If a button is released, its `reasons_pressed` should be empty.
@@ -25,12 +46,107 @@ pub struct ActionData {
     /// Actions that are consumed cannot be pressed again until they are explicitly released.
     /// This ensures that consumed actions are not immediately re-pressed by continued inputs.
     pub consumed: bool,
+    /// What last set the state of this action?
+    ///
+    /// This describes the current tick on this machine, and so is not serialized:
+    /// the serialized form of [`ActionData`] is the same whether or not the `action_source` feature is enabled.
+    #[cfg(feature = "action_source")]
+    #[serde(skip)]
+    pub source: ActionSource,
+    /// Is this action being held down by code, via [`ActionState::hold`]?
+    ///
+    /// Held actions stay pressed during [`ActionState::update`], even if none of their inputs are pressed.
+    /// Cleared when the action is released or consumed.
+    pub held: bool,
+    /// The discrete steps accumulated by this action that have not yet been read by [`ActionState::steps`]
+    ///
+    /// Each press adds one step, and continuous inputs can add fractional steps via [`ActionState::accumulate_steps`].
+    pub pending_steps: f32,
+    /// Chords bound to this action for which some, but not all, of the buttons are currently pressed
+    ///
+    /// This is populated by [`InputMap::which_pressed`](crate::input_map::InputMap::which_pressed).
+    pub partial_chords: Vec<UserInput>,
+    /// Was this action released during this tick because one of the buttons of a pressed chord was released,
+    /// while the rest of the chord remained held?
+    ///
+    /// Reset to `false` by [`ActionState::tick`].
+    pub chord_broken: bool,
+    /// The number of times this action has been pressed since its counts were last reset
+    ///
+    /// Reset using [`ActionState::reset_press_counts`].
+    pub press_count: u32,
+    /// The number of times this action has been released since its counts were last reset
+    ///
+    /// Reset using [`ActionState::reset_press_counts`].
+    pub release_count: u32,
+    /// The number of times this action has been pressed since [`ActionState::tick`] was last called
+    ///
+    /// Read this using [`ActionState::presses_this_tick`].
+    pub presses_this_tick: u8,
+    /// Was the most recent press of this action consumed, so that it no longer counts as [buffered](ActionState::buffered_just_pressed)?
+    ///
+    /// Set by [`ActionState::consume`], and cleared whenever the action is pressed again.
+    pub buffered_press_consumed: bool,
+    /// The start of the tick during which each recent press of this action occurred, oldest first
+    ///
+    /// At most [`RECENT_PRESS_CAPACITY`] presses are stored.
+    /// Presses that occur before [`ActionState::tick`] is first called are not recorded.
+    #[serde(skip)]
+    pub recent_presses: VecDeque<Instant>,
+    /// The windows within which the most recent press completed a [double press](ActionState::double_pressed)
+    ///
+    /// This is stored as a sorted list of boundaries: the press completed a double press within a window
+    /// if an odd number of these boundaries are no longer than it.
+    /// Each press either completes the double press started by the previous one, or starts a new one,
+    /// so this is updated whenever a press is recorded, regardless of how many presses came before it.
+    #[serde(skip)]
+    pub double_press_windows: Vec<Duration>,
+    /// Was this action released during the most recent update because it was overruled by an opposing action?
+    ///
+    /// This is set by [`SocdCleaning`](crate::clashing_inputs::SocdCleaning).
+    pub suppressed: bool,
+    /// Is the value of this action currently outside of the [`NeutralZone`]?
+    ///
+    /// Read this using [`ActionState::is_neutral`].
+    pub outside_neutral: bool,
+    /// Did the value of this action return to the [`NeutralZone`] since the last time [`ActionState::tick`] was called?
+    ///
+    /// Read this using [`ActionState::just_returned_to_neutral`].
+    pub returned_to_neutral: bool,
+    /// The analog value of this action
+    pub axis_data: AxisData,
+}
+
+/// Records what was responsible for the most recent change to an action's [`ActionData`]
+///
+/// This is useful for networked prediction and rollback,
+/// where reconciliation logic needs to know which actions were driven by fresh local input.
+/// Access it using [`ActionState::source`].
+///
+/// Only available with the `action_source` feature, so that simple games do not pay for tracking it.
+#[cfg(feature = "action_source")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ActionSource {
+    /// The action was set from local user input during this tick, via [`ActionState::update`]
+    Input,
+    /// The action was set from an [`ActionDiff`] during this tick
+    Diff,
+    /// The action was set manually during this tick, such as by UI or AI via [`ActionState::press`]
+    Manual,
+    /// The action has not been set since the last time [`ActionState::tick`] was called
+    #[default]
+    Carried,
 }
 
 /// Stores the canonical input-method-agnostic representation of the inputs received
 ///
 /// Can be used as either a resource or as a [`Component`] on entities that you wish to control directly from player input.
 ///
+/// An [`ActionState`] does not need a matching [`InputMap`](crate::input_map::InputMap).
+/// Without one, it is skipped by [`update_action_state`](crate::systems::update_action_state) but still [ticked](ActionState::tick),
+/// so it can be driven purely by code, such as AI-controlled characters that share the same action-handling systems as players.
+/// Actions pressed this way stay pressed until they are [released](ActionState::release).
+///
 /// # Example
 /// ```rust
 /// use leafwing_input_manager::prelude::*;
@@ -73,6 +189,27 @@ pub struct ActionState<A: Actionlike> {
     ///
     /// The position in this vector corresponds to [`Actionlike::index`].
     pub action_data: Vec<ActionData>,
+    /// The `current_time` passed to the most recent call to [`ActionState::tick`]
+    #[serde(skip)]
+    last_tick: Option<Instant>,
+    /// The `current_time` passed to the call to [`ActionState::tick`] before the most recent one
+    #[serde(skip)]
+    previous_tick: Option<Instant>,
+    /// The registered [`DirectionalAxis`] action indexes, and their cached net movement
+    #[serde(skip)]
+    directional_axes: Vec<([usize; 4], Vec2)>,
+    /// Is this state being driven by replayed [`ActionDiff`]s, rather than live input?
+    #[serde(skip)]
+    replaying: bool,
+    /// The thresholds used by [`ActionState::is_neutral`]
+    #[serde(skip)]
+    neutral_zone: NeutralZone,
+    /// The [`Cooldown`] of each action, if any, indexed in the same way as `action_data`
+    #[serde(skip)]
+    cooldowns: Vec<Option<Cooldown>>,
+    /// Which of the `cooldowns` were set by an [`InputMap`](crate::input_map::InputMap), rather than by code
+    #[serde(skip)]
+    map_cooldowns: Vec<bool>,
     _phantom: PhantomData<A>,
 }
 
@@ -85,15 +222,49 @@ impl<A: Actionlike> ActionState<A> {
         assert_eq!(action_data.len(), A::N_VARIANTS);
 
         for (i, action) in A::variants().enumerate() {
+            // Actions held by code are left alone until their inputs take over
+            if self.action_data[i].held && action_data[i].state.released() {
+                continue;
+            }
+
+            // The value must be set before pressing, so that the neutral zone is checked against it
+            self.action_data[i].axis_data = action_data[i].axis_data.clone();
+
+            // A chord was broken if one of the chords that was pressing this action is now only partially pressed
+            if self.action_data[i].state.pressed() && action_data[i].state.released() {
+                let previous_reasons = &self.action_data[i].reasons_pressed;
+                self.action_data[i].chord_broken = action_data[i]
+                    .partial_chords
+                    .iter()
+                    .any(|chord| previous_reasons.contains(chord));
+            }
+
             match action_data[i].state {
-                ButtonState::JustPressed => self.press(action),
-                ButtonState::Pressed => self.press(action),
-                ButtonState::JustReleased => self.release(action),
-                ButtonState::Released => self.release(action),
+                ButtonState::JustPressed => self.press(action.clone()),
+                ButtonState::Pressed => self.press(action.clone()),
+                ButtonState::JustReleased => self.release(action.clone()),
+                ButtonState::Released => self.release(action.clone()),
+            }
+
+            // Consumed actions cannot be pressed, and so have no value
+            if self.action_data[i].state.released()
+                && self.action_data[i].axis_data != AxisData::default()
+            {
+                self.action_data[i].axis_data = AxisData::default();
+                self.refresh_neutral(action);
+                self.refresh_directional_axes();
             }
 
             self.action_data[i].reasons_pressed = action_data[i].reasons_pressed.clone();
+            self.action_data[i].partial_chords = action_data[i].partial_chords.clone();
+            self.action_data[i].suppressed = action_data[i].suppressed;
+            #[cfg(feature = "action_source")]
+            {
+                self.action_data[i].source = ActionSource::Input;
+            }
         }
+
+        self.replaying = false;
     }
 
     /// Advances the time for all actions
@@ -134,9 +305,33 @@ impl<A: Actionlike> ActionState<A> {
     /// assert!(!action_state.just_pressed(Action::Jump));
     /// ```
     pub fn tick(&mut self, current_time: Instant) {
+        let delta = match self.last_tick {
+            Some(last_tick) => current_time.saturating_duration_since(last_tick),
+            None => Duration::ZERO,
+        };
+        self.previous_tick = self.last_tick;
+        self.last_tick = Some(current_time);
+
+        // Count down the cooldowns
+        self.cooldowns
+            .iter_mut()
+            .flatten()
+            .for_each(|cooldown| cooldown.tick(delta));
+
         // Advanced the ButtonState
         self.action_data.iter_mut().for_each(|ad| ad.state.tick());
 
+        // Anything that is not set again this tick has been carried over
+        self.action_data.iter_mut().for_each(|ad| {
+            #[cfg(feature = "action_source")]
+            {
+                ad.source = ActionSource::Carried;
+            }
+            ad.chord_broken = false;
+            ad.returned_to_neutral = false;
+            ad.presses_this_tick = 0;
+        });
+
         // Advance the Timings
         self.action_data
             .iter_mut()
@@ -204,6 +399,7 @@ impl<A: Actionlike> ActionState<A> {
     #[inline]
     pub fn set_action_data(&mut self, action: A, data: ActionData) {
         self.action_data[action.index()] = data;
+        self.refresh_directional_axes();
     }
 
     /// Press the `action`
@@ -218,8 +414,97 @@ impl<A: Actionlike> ActionState<A> {
             return;
         }
 
+        // Only new presses should count as steps or reset the timing information
+        if self.action_data[index].state.released() {
+            let action_data = &mut self.action_data[index];
+            action_data.pending_steps += 1.0;
+            action_data.timing.flip();
+            action_data.press_count += 1;
+            action_data.presses_this_tick = action_data.presses_this_tick.saturating_add(1);
+            action_data.buffered_press_consumed = false;
+
+            if let Some(last_tick) = self.last_tick {
+                match action_data.recent_presses.back() {
+                    Some(&previous_press) => {
+                        let gap = last_tick.saturating_duration_since(previous_press);
+                        // Within a window, this press completes a double press if it lands within the window
+                        // and the previous press was not itself completing one
+                        let windows = &mut action_data.double_press_windows;
+                        let previous_completed =
+                            windows.iter().filter(|&&w| w <= gap).count() % 2 == 1;
+                        windows.retain(|&window| window > gap);
+                        if !previous_completed {
+                            windows.insert(0, gap);
+                        }
+                    }
+                    None => action_data.double_press_windows.clear(),
+                }
+
+                if action_data.recent_presses.len() == RECENT_PRESS_CAPACITY {
+                    action_data.recent_presses.pop_front();
+                }
+                action_data.recent_presses.push_back(last_tick);
+            }
+        }
+
         self.action_data[index].state.press();
-        self.action_data[index].timing.flip();
+        #[cfg(feature = "action_source")]
+        {
+            self.action_data[index].source = ActionSource::Manual;
+        }
+        // Manually pressed actions behave like buttons, unless they already have an analog value
+        if self.action_data[index].axis_data.value == 0.0 {
+            self.action_data[index].axis_data.value = 1.0;
+        }
+        self.refresh_neutral(action);
+        self.refresh_directional_axes();
+    }
+
+    /// Press the `action`, and keep it pressed until it is explicitly released
+    ///
+    /// Unlike actions pressed by [`ActionState::press`], held actions are not released by [`ActionState::update`]
+    /// when none of their inputs are pressed, so they do not trigger a fresh `just_pressed` each frame.
+    /// This is used by [`ScriptedInput`](crate::scripted_input::ScriptedInput) and [`InputMacros`](crate::scripted_input::InputMacros).
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::action_state::ActionData;
+    /// use bevy_utils::Instant;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.hold(Action::Jump);
+    ///
+    /// // Updating from inputs that are not pressed does not release held actions
+    /// action_state.tick(Instant::now());
+    /// action_state.update(vec![ActionData::default()]);
+    /// assert!(action_state.pressed(Action::Jump));
+    /// assert!(!action_state.just_pressed(Action::Jump));
+    ///
+    /// action_state.release(Action::Jump);
+    /// action_state.update(vec![ActionData::default()]);
+    /// assert!(action_state.released(Action::Jump));
+    /// ```
+    #[inline]
+    pub fn hold(&mut self, action: A) {
+        self.press(action.clone());
+        // Consumed actions cannot be pressed, and so are not held either
+        let action_data = &mut self.action_data[action.index()];
+        action_data.held = action_data.state.pressed();
+    }
+
+    /// Stops [holding](ActionState::hold) the `action`, without releasing it
+    ///
+    /// The action stays pressed until the next [`ActionState::update`],
+    /// which releases it unless one of its inputs is pressed.
+    #[inline]
+    pub fn stop_holding(&mut self, action: A) {
+        self.action_data[action.index()].held = false;
     }
 
     /// Release the `action`
@@ -231,9 +516,21 @@ impl<A: Actionlike> ActionState<A> {
         let index = action.index();
         // Once released, consumed actions can be pressed again
         self.action_data[index].consumed = false;
+        self.action_data[index].held = false;
+        // Releasing an action that is already released should not reset its timing information
+        if self.action_data[index].state.pressed() {
+            self.action_data[index].timing.flip();
+            self.action_data[index].release_count += 1;
+        }
         self.action_data[index].state.release();
         self.action_data[index].reasons_pressed = Vec::new();
-        self.action_data[index].timing.flip();
+        #[cfg(feature = "action_source")]
+        {
+            self.action_data[index].source = ActionSource::Manual;
+        }
+        self.action_data[index].axis_data = AxisData::default();
+        self.refresh_neutral(action);
+        self.refresh_directional_axes();
     }
 
     /// Consumes the `action`
@@ -245,6 +542,12 @@ impl<A: Actionlike> ActionState<A> {
     /// No initial instant will be recorded
     /// Instead, this is set through [`ActionState::tick()`]
     ///
+    /// This is the standard way to "eat" an input once it has been handled,
+    /// so that systems later in the frame do not also respond to it.
+    /// Consuming persists across ticks: while its inputs remain held, each [`ActionState::update`] leaves the action released,
+    /// so a held button will not trigger a fresh `just_pressed` on the next frame.
+    /// Once the inputs are released, the action is recomputed from input as usual.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -279,9 +582,21 @@ impl<A: Actionlike> ActionState<A> {
         let index = action.index();
         // This is the only difference from release(action)
         self.action_data[index].consumed = true;
+        self.action_data[index].buffered_press_consumed = true;
+        self.action_data[index].held = false;
+        if self.action_data[index].state.pressed() {
+            self.action_data[index].timing.flip();
+            self.action_data[index].release_count += 1;
+        }
         self.action_data[index].state.release();
         self.action_data[index].reasons_pressed = Vec::new();
-        self.action_data[index].timing.flip();
+        #[cfg(feature = "action_source")]
+        {
+            self.action_data[index].source = ActionSource::Manual;
+        }
+        self.action_data[index].axis_data = AxisData::default();
+        self.refresh_neutral(action);
+        self.refresh_directional_axes();
     }
 
     /// Releases all actions
@@ -291,6 +606,325 @@ impl<A: Actionlike> ActionState<A> {
         }
     }
 
+    /// Captures the state of every action, so that it can later be reinstated using [`ActionState::restore`]
+    ///
+    /// This includes whether each action is pressed, as well as its timing and analog value.
+    /// Configuration, such as the [`NeutralZone`] and registered [`DirectionalAxis`] actions, is not captured.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_utils::Instant;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Run,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Run);
+    /// action_state.tick(Instant::now());
+    ///
+    /// // Pausing the game lets the menu take over the inputs
+    /// let snapshot = action_state.snapshot();
+    /// action_state.release_all();
+    ///
+    /// // Resuming does not produce a fresh `just_pressed` for held actions
+    /// action_state.restore(snapshot);
+    /// assert!(action_state.pressed(Action::Run));
+    /// assert!(!action_state.just_pressed(Action::Run));
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> ActionStateSnapshot<A> {
+        ActionStateSnapshot {
+            action_data: self.action_data.clone(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Reinstates the state of every action from a `snapshot` created by [`ActionState::snapshot`]
+    ///
+    /// As timing is recorded using absolute [`Instant`]s, durations measured after restoring
+    /// include any time that passed while the snapshot was stored.
+    ///
+    /// Restoring never re-fires edges: actions that were just pressed or just released when the snapshot was taken
+    /// are restored as simply pressed or released.
+    /// Counters, such as the [press count](ActionState::press_count), pending [steps](ActionState::steps)
+    /// and whether the action was [consumed](ActionState::consume), keep their current values rather than rolling back,
+    /// so that presses which happened while the snapshot was stored are not counted twice.
+    /// Likewise, actions that are currently [held](ActionState::hold) by code stay held and pressed,
+    /// and consumed actions stay released until their inputs are released.
+    pub fn restore(&mut self, snapshot: ActionStateSnapshot<A>) {
+        for (current, mut restored) in self.action_data.iter_mut().zip(snapshot.action_data) {
+            restored.state = match restored.state {
+                ButtonState::JustPressed | ButtonState::Pressed => ButtonState::Pressed,
+                ButtonState::JustReleased | ButtonState::Released => ButtonState::Released,
+            };
+            restored.chord_broken = false;
+            restored.returned_to_neutral = false;
+
+            restored.consumed = current.consumed;
+            if restored.consumed {
+                restored.state = ButtonState::Released;
+                restored.reasons_pressed = Vec::new();
+                restored.axis_data = AxisData::default();
+            }
+            restored.held = current.held;
+            if restored.held {
+                restored.state = ButtonState::Pressed;
+            }
+            restored.buffered_press_consumed = current.buffered_press_consumed;
+            restored.press_count = current.press_count;
+            restored.release_count = current.release_count;
+            restored.presses_this_tick = current.presses_this_tick;
+            restored.pending_steps = current.pending_steps;
+            restored.recent_presses = core::mem::take(&mut current.recent_presses);
+            restored.double_press_windows = core::mem::take(&mut current.double_press_windows);
+
+            *current = restored;
+        }
+        self.refresh_directional_axes();
+    }
+
+    /// The [`ActionDiff`]s that bring the `previous` state up to date with this one
+    ///
+    /// This compares two arbitrary states, such as the [`ActionState`]s stored for two frames of a rollback buffer,
+    /// rather than tracking the changes made since the last frame like [`generate_action_diffs`](crate::systems::generate_action_diffs).
+    /// Diffs are ordered and filtered in the same way:
+    /// first all presses, then all releases, then all analog value changes larger than [`ACTION_DIFF_EPSILON`].
+    /// Newly pressed actions are assumed to have a value of `1.0`, unless they already had an analog value.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::action_state::ActionDiff;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Run,
+    ///     Jump,
+    /// }
+    ///
+    /// let mut previous = ActionState::<Action>::default();
+    /// previous.press(Action::Run);
+    ///
+    /// let mut current = previous.clone();
+    /// current.release(Action::Run);
+    /// current.press(Action::Jump);
+    ///
+    /// assert_eq!(
+    ///     current.diff_against(&previous),
+    ///     vec![
+    ///         ActionDiff::Pressed { action: Action::Jump, id: () },
+    ///         ActionDiff::Released { action: Action::Run, id: () },
+    ///     ]
+    /// );
+    /// assert!(current.diff_against(&current).is_empty());
+    /// ```
+    #[must_use]
+    pub fn diff_against(&self, previous: &ActionState<A>) -> Vec<ActionDiff<A, ()>> {
+        let mut diffs = Vec::new();
+
+        for action in A::variants() {
+            if self.pressed(action.clone()) && previous.released(action.clone()) {
+                diffs.push(ActionDiff::Pressed { action, id: () });
+            }
+        }
+
+        for action in A::variants() {
+            if self.released(action.clone()) && previous.pressed(action.clone()) {
+                diffs.push(ActionDiff::Released { action, id: () });
+            }
+        }
+
+        for action in A::variants() {
+            // Released actions have no analog values
+            if self.released(action.clone()) {
+                continue;
+            }
+
+            let mut previous_value = previous.axis_value(action.clone());
+            if previous.released(action.clone()) && previous_value == 0.0 {
+                previous_value = 1.0;
+            }
+
+            let value = self.axis_value(action.clone());
+            if (value - previous_value).abs() > ACTION_DIFF_EPSILON {
+                diffs.push(ActionDiff::ValueChanged {
+                    action: action.clone(),
+                    id: (),
+                    value,
+                });
+            }
+
+            if let Some(axis_pair) = self.axis_pair(action.clone()) {
+                let axis_pair = axis_pair.xy();
+                let changed = match previous.axis_pair(action.clone()) {
+                    Some(previous_axis_pair) => {
+                        previous_axis_pair.xy().distance(axis_pair) > ACTION_DIFF_EPSILON
+                    }
+                    None => true,
+                };
+
+                if changed {
+                    diffs.push(ActionDiff::AxisPairChanged {
+                        action,
+                        id: (),
+                        axis_pair,
+                    });
+                }
+            }
+        }
+
+        diffs
+    }
+
+    /// Encodes which actions are pressed as a compact bitset, for rollback netcode
+    ///
+    /// Action `i`, as given by [`Actionlike::index`], is stored in bit `i % 8` of byte `i / 8`,
+    /// so the format only depends on the order of the variants of `A`, and not on any [`InputMap`](crate::input_map::InputMap).
+    /// Analog values and timing information are not included.
+    ///
+    /// Decode the result with [`ActionState::from_serialized_inputs`] or [`ActionState::apply_serialized_inputs`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Left,
+    ///     Right,
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Right);
+    /// action_state.press(Action::Jump);
+    ///
+    /// let inputs = action_state.to_serialized_inputs();
+    /// assert_eq!(inputs, vec![0b110]);
+    ///
+    /// let restored = ActionState::<Action>::from_serialized_inputs(&inputs).unwrap();
+    /// assert!(restored.released(Action::Left));
+    /// assert!(restored.pressed(Action::Right));
+    /// assert!(restored.pressed(Action::Jump));
+    /// ```
+    #[must_use]
+    pub fn to_serialized_inputs(&self) -> Vec<u8> {
+        let mut bytes = vec![0; serialized_inputs_len::<A>()];
+        for action in self.get_pressed() {
+            let index = action.index();
+            bytes[index / 8] |= 1 << (index % 8);
+        }
+        bytes
+    }
+
+    /// Creates a new [`ActionState`] whose actions are pressed according to the `bytes` created by [`ActionState::to_serialized_inputs`]
+    ///
+    /// # Errors
+    /// Returns a [`SerializedInputsLength`] error if there is not exactly one bit for each action, rounded up to a whole byte.
+    pub fn from_serialized_inputs(bytes: &[u8]) -> Result<Self, SerializedInputsLength> {
+        let mut action_state = ActionState::default();
+        action_state.apply_serialized_inputs(bytes)?;
+        Ok(action_state)
+    }
+
+    /// Presses and releases each action according to the `bytes` created by [`ActionState::to_serialized_inputs`]
+    ///
+    /// Unlike [`ActionState::from_serialized_inputs`], this preserves the existing state,
+    /// so actions that change are [just pressed](ActionState::just_pressed) or [just released](ActionState::just_released)
+    /// as they would be with live input. This makes it suitable for re-simulating frames after a rollback.
+    ///
+    /// # Errors
+    /// Returns a [`SerializedInputsLength`] error, without changing any actions,
+    /// if there is not exactly one bit for each action, rounded up to a whole byte.
+    pub fn apply_serialized_inputs(&mut self, bytes: &[u8]) -> Result<(), SerializedInputsLength> {
+        let expected = serialized_inputs_len::<A>();
+        if bytes.len() != expected {
+            return Err(SerializedInputsLength {
+                expected,
+                found: bytes.len(),
+            });
+        }
+
+        for action in A::variants() {
+            let index = action.index();
+            let pressed = bytes[index / 8] & (1 << (index % 8)) != 0;
+            if pressed && self.released(action.clone()) {
+                self.press(action);
+            } else if !pressed && self.pressed(action.clone()) {
+                self.release(action);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Consumes all actions
+    ///
+    /// Each action will stay released until its inputs are released, as described in [`ActionState::consume`].
+    pub fn consume_all(&mut self) {
+        for action in A::variants() {
+            self.consume(action);
+        }
+    }
+
+    /// If any of the actions in the `group` was just pressed, consumes every other pressed action in the `group`
+    ///
+    /// This ensures that only one action in the `group` is active at once, with the most recent press winning.
+    /// If several actions in the `group` were just pressed, the first of them in the `group` wins.
+    /// Consumed actions stay released until their inputs are released, as described in [`ActionState::consume`].
+    ///
+    /// Typically called via [`ExclusiveGroups`](crate::plugin::ExclusiveGroups).
+    pub fn enforce_exclusive(&mut self, group: &[A]) {
+        let winner = match group
+            .iter()
+            .find(|action| self.just_pressed((*action).clone()))
+        {
+            Some(winner) => winner.index(),
+            None => return,
+        };
+
+        for action in group {
+            if action.index() != winner && self.pressed(action.clone()) {
+                self.consume(action.clone());
+            }
+        }
+    }
+
+    /// Releases any pressed actions that are no longer held by any of their bound inputs
+    ///
+    /// The `action_data` is typically constructed from [`InputMap::which_pressed`](crate::input_map::InputMap::which_pressed),
+    /// and is used as a consistency check against the stored state.
+    /// Only actions that were pressed by their inputs (as recorded by their `reasons_pressed`) are reconciled:
+    /// actions pressed by code, such as by [`ActionState::press`], [`ActionState::hold`] or an [`ActionDiff`], are left alone,
+    /// even once they have been carried over into later ticks.
+    ///
+    /// Returns the actions that were released.
+    /// This is used by the [`reconcile_action_state`](crate::systems::reconcile_action_state) system
+    /// to prevent actions from becoming stuck after errors.
+    pub fn reconcile(&mut self, action_data: &[ActionData]) -> Vec<A> {
+        assert_eq!(action_data.len(), A::N_VARIANTS);
+
+        let orphaned: Vec<A> = A::variants()
+            .filter(|action| {
+                let index = action.index();
+                self.action_data[index].state.pressed()
+                    && action_data[index].state.released()
+                    && !self.action_data[index].reasons_pressed.is_empty()
+            })
+            .collect();
+
+        for action in orphaned.iter() {
+            self.release(action.clone());
+            #[cfg(feature = "action_source")]
+            self.set_source(action.clone(), ActionSource::Input);
+        }
+
+        orphaned
+    }
+
     /// Is this `action` currently pressed?
     #[inline]
     #[must_use]
@@ -349,90 +983,1384 @@ impl<A: Actionlike> ActionState<A> {
             .collect()
     }
 
-    /// The reasons (in terms of [`UserInput`]) that the button was pressed
+    /// Are any of the `actions` currently pressed?
     ///
-    /// If the button is currently released, the `Vec<UserInput`> returned will be empty
+    /// This is useful for working with groups of actions, such as every direction of movement.
+    /// Returns `false` if `actions` is empty.
     ///
     /// # Example
-    ///
     /// ```rust
     /// use leafwing_input_manager::prelude::*;
-    /// use leafwing_input_manager::buttonlike::ButtonState;
-    /// use leafwing_input_manager::action_state::ActionData;
-    /// use bevy_input::keyboard::KeyCode;
     ///
-    /// #[derive(Actionlike, Clone)]
-    /// enum PlatformerAction{
-    ///     Move,
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Up,
+    ///     Down,
     ///     Jump,
     /// }
     ///
-    /// let mut action_state = ActionState::<PlatformerAction>::default();
+    /// impl Action {
+    ///     const DIRECTIONS: [Action; 2] = [Action::Up, Action::Down];
+    /// }
     ///
-    /// // Usually this will be done automatically for you, via [`ActionState::update`]
-    /// action_state.set_action_data(PlatformerAction::Jump,
-    ///   ActionData {
-    ///         state: ButtonState::JustPressed,
-    ///         // Manually setting the reason why this action was pressed
-    ///         reasons_pressed: vec![KeyCode::Space.into()],
-    ///         // For the sake of this example, we don't care about any other fields
-    ///         ..Default::default()
-    ///     }
-    /// );
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Up);
     ///
-    /// let reasons_jumped = action_state.reasons_pressed(PlatformerAction::Jump);
-    /// assert_eq!(reasons_jumped[0], KeyCode::Space.into());
+    /// assert!(action_state.any_pressed(Action::DIRECTIONS));
+    /// assert!(!action_state.all_pressed(Action::DIRECTIONS));
     /// ```
-    #[inline]
     #[must_use]
-    pub fn reasons_pressed(&self, action: A) -> Vec<UserInput> {
-        self.action_data[action.index()].reasons_pressed.clone()
+    pub fn any_pressed(&self, actions: impl IntoIterator<Item = A>) -> bool {
+        actions.into_iter().any(|action| self.pressed(action))
     }
 
-    /// The [`Instant`] that the action was last pressed or released
+    /// Are all of the `actions` currently pressed?
     ///
-    /// If the action was pressed or released since the last time [`ActionState::tick`] was called
-    /// the value will be [`None`].
-    /// This ensures that all of our actions are assigned a timing and duration
-    /// that corresponds exactly to the start of a frame, rather than relying on idiosyncratic timing.
-    pub fn instant_started(&self, action: A) -> Option<Instant> {
-        self.action_data[action.index()].timing.instant_started
+    /// Returns `true` if `actions` is empty.
+    /// See [`ActionState::any_pressed`] for an example.
+    #[must_use]
+    pub fn all_pressed(&self, actions: impl IntoIterator<Item = A>) -> bool {
+        actions.into_iter().all(|action| self.pressed(action))
     }
 
-    /// The [`Duration`] for which the action has been held or released
-    pub fn current_duration(&self, action: A) -> Duration {
-        self.action_data[action.index()].timing.current_duration
+    /// How many times has this `action` been pressed since [`ActionState::reset_press_counts`] was last called?
+    #[inline]
+    #[must_use]
+    pub fn press_count(&self, action: A) -> u32 {
+        self.action_data[action.index()].press_count
     }
 
-    /// The [`Duration`] for which the action was last held or released
-    ///
-    /// This is a snapshot of the [`ActionState::current_duration`] state at the time
-    /// the action was last pressed or released.
-    pub fn previous_duration(&self, action: A) -> Duration {
-        self.action_data[action.index()].timing.previous_duration
+    /// How many times has this `action` been released since [`ActionState::reset_press_counts`] was last called?
+    #[inline]
+    #[must_use]
+    pub fn release_count(&self, action: A) -> u32 {
+        self.action_data[action.index()].release_count
     }
-}
 
-impl<A: Actionlike> Default for ActionState<A> {
+    /// How many discrete times has this `action` been pressed since the most recent [tick](ActionState::tick)?
+    ///
+    /// For live input this is `0` or `1`, but buffered input and playback can press and release an action
+    /// several times between ticks, which [`ActionState::just_pressed`] cannot distinguish.
+    /// Holding an action does not count as another press, and the count saturates at [`u8::MAX`].
+    #[inline]
+    #[must_use]
+    pub fn presses_this_tick(&self, action: A) -> u8 {
+        self.action_data[action.index()].presses_this_tick
+    }
+
+    /// How many times was this `action` pressed within the `window` before the most recent [tick](ActionState::tick)?
+    ///
+    /// Presses are timestamped with the time of the tick during which they occurred,
+    /// so presses made since the most recent tick are included.
+    /// At most [`RECENT_PRESS_CAPACITY`] presses can be counted.
+    /// This is useful for detecting button mashing or rapid combos.
+    #[must_use]
+    pub fn presses_within(&self, action: A, window: Duration) -> usize {
+        let last_tick = match self.last_tick {
+            Some(last_tick) => last_tick,
+            None => return 0,
+        };
+
+        self.action_data[action.index()]
+            .recent_presses
+            .iter()
+            .filter(|&&instant| last_tick.saturating_duration_since(instant) <= window)
+            .count()
+    }
+
+    /// Was this `action` pressed within the `window` before the most recent [tick](ActionState::tick), without being consumed since?
+    ///
+    /// This buffers inputs: a jump or attack pressed slightly before it becomes possible still counts,
+    /// as long as it is checked within the `window`.
+    /// Call [`ActionState::consume`] once the buffered press has been acted on, so that it only triggers once.
+    /// Unlike [`ActionState::just_pressed`], this does not depend on when [`ActionState::tick`] clears the `just_pressed` edge.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_utils::{Duration, Instant};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// let t0 = Instant::now();
+    /// let window = Duration::from_millis(100);
+    ///
+    /// action_state.tick(t0);
+    /// action_state.press(Action::Jump);
+    /// action_state.tick(t0 + Duration::from_millis(50));
+    ///
+    /// // The press is no longer new, but is still within the buffer window
+    /// assert!(!action_state.just_pressed(Action::Jump));
+    /// assert!(action_state.buffered_just_pressed(Action::Jump, window));
+    ///
+    /// // Once acted upon, the press is cleared from the buffer
+    /// action_state.consume(Action::Jump);
+    /// assert!(!action_state.buffered_just_pressed(Action::Jump, window));
+    /// ```
+    #[must_use]
+    pub fn buffered_just_pressed(&self, action: A, window: Duration) -> bool {
+        let action_data = &self.action_data[action.index()];
+        if action_data.buffered_press_consumed {
+            return false;
+        }
+
+        if action_data.state.just_pressed() {
+            return true;
+        }
+
+        match (self.last_tick, action_data.recent_presses.back()) {
+            (Some(last_tick), Some(&pressed_at)) => {
+                last_tick.saturating_duration_since(pressed_at) <= window
+            }
+            _ => false,
+        }
+    }
+
+    /// Was this `action` just pressed for the second time in quick succession?
+    ///
+    /// Returns `true` on the tick that the action is [just pressed](ActionState::just_pressed),
+    /// if its previous press was no more than `window` earlier.
+    /// Each press can only belong to a single double press:
+    /// mashing the action fires on every second press, rather than on every press after the first.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_utils::{Duration, Instant};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Dash,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// let window = Duration::from_millis(300);
+    /// let start = Instant::now();
+    ///
+    /// let tap = |action_state: &mut ActionState<Action>, millis: u64| {
+    ///     action_state.tick(start + Duration::from_millis(millis));
+    ///     action_state.press(Action::Dash);
+    ///     let double_pressed = action_state.double_pressed(Action::Dash, window);
+    ///     action_state.tick(start + Duration::from_millis(millis + 50));
+    ///     action_state.release(Action::Dash);
+    ///     double_pressed
+    /// };
+    ///
+    /// assert!(!tap(&mut action_state, 0));
+    /// assert!(tap(&mut action_state, 200));
+    /// // A third rapid press starts a new double press
+    /// assert!(!tap(&mut action_state, 400));
+    /// assert!(tap(&mut action_state, 600));
+    /// ```
+    #[must_use]
+    pub fn double_pressed(&self, action: A, window: Duration) -> bool {
+        let action_data = &self.action_data[action.index()];
+
+        // The current press must have been recorded
+        if !action_data.state.just_pressed()
+            || self.last_tick.is_none()
+            || action_data.recent_presses.back() != self.last_tick.as_ref()
+        {
+            return false;
+        }
+
+        // Presses are paired off from the start of each streak of presses that land within `window` of each other
+        action_data
+            .double_press_windows
+            .iter()
+            .filter(|&&boundary| boundary <= window)
+            .count()
+            % 2
+            == 1
+    }
+
+    /// How rapidly is this `action` being pressed, in presses per second?
+    ///
+    /// The rate is smoothed: each press contributes a weight that decays exponentially
+    /// (with a time constant of [`MASH_RATE_TIME_CONSTANT`]) rather than abruptly leaving a fixed window.
+    /// For presses at a steady rate, this converges to that rate,
+    /// and it falls back towards zero smoothly once the presses stop.
+    /// This is useful for building mash meters for quick-time events.
+    ///
+    /// The rate is measured at the time of the most recent [tick](ActionState::tick).
+    #[must_use]
+    pub fn mash_rate(&self, action: A) -> f32 {
+        match self.last_tick {
+            Some(last_tick) => self.mash_rate_at(action, last_tick),
+            None => 0.0,
+        }
+    }
+
+    /// Did the [`mash_rate`](ActionState::mash_rate) of this `action` reach the provided `rate` during the most recent tick?
+    ///
+    /// This only returns `true` for the tick during which the threshold was crossed.
+    #[must_use]
+    pub fn just_reached_mash(&self, action: A, rate: f32) -> bool {
+        let reached_now = self.mash_rate(action.clone()) >= rate;
+        let reached_before = match self.previous_tick {
+            Some(previous_tick) => self.mash_rate_at(action, previous_tick) >= rate,
+            None => false,
+        };
+
+        reached_now && !reached_before
+    }
+
+    /// The smoothed press rate of the `action`, as measured at the instant `time`
+    fn mash_rate_at(&self, action: A, time: Instant) -> f32 {
+        let time_constant = MASH_RATE_TIME_CONSTANT.as_secs_f32();
+
+        self.action_data[action.index()]
+            .recent_presses
+            .iter()
+            .filter(|&&instant| instant <= time)
+            .map(|&instant| (-(time - instant).as_secs_f32() / time_constant).exp())
+            .sum::<f32>()
+            / time_constant
+    }
+
+    /// Resets the press and release counts of the `action`, and forgets its recent presses
+    pub fn reset_press_counts(&mut self, action: A) {
+        let action_data = &mut self.action_data[action.index()];
+        action_data.press_count = 0;
+        action_data.release_count = 0;
+        action_data.recent_presses.clear();
+        action_data.double_press_windows.clear();
+    }
+
+    /// Which of the currently pressed actions was pressed most recently?
+    ///
+    /// Actions are compared by their [`current_duration`](ActionState::current_duration):
+    /// the action that has been held for the shortest time wins.
+    /// Ties are broken in favor of the action with the lowest [index](Actionlike::index).
+    /// Returns [`None`] if no actions are pressed.
+    #[must_use]
+    pub fn most_recent_action(&self) -> Option<A> {
+        A::variants()
+            .filter(|action| self.pressed(action.clone()))
+            .min_by_key(|action| self.current_duration(action.clone()))
+    }
+
+    /// Combines four directional actions into a single movement vector
+    ///
+    /// Each pressed action contributes a unit vector in its direction (with `up` as positive y and `right` as positive x),
+    /// so opposing actions cancel out.
+    /// The result is clamped to a length of at most 1, so diagonal movement is not faster than straight movement.
+    ///
+    /// To get a [`Direction`] rather than a vector, use [`ActionState::net_direction`] with a [`DirectionalAxis`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::math::Vec2;
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Up,
+    ///     Down,
+    ///     Left,
+    ///     Right,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Up);
+    /// action_state.press(Action::Right);
+    ///
+    /// let movement = action_state.movement(Action::Up, Action::Down, Action::Left, Action::Right);
+    /// assert!((movement - Vec2::new(1.0, 1.0).normalize()).length() < 1e-6);
+    /// ```
+    #[must_use]
+    #[doc(alias = "clamped_axis_pair_from_dpad")]
+    pub fn movement(&self, up: A, down: A, left: A, right: A) -> Vec2 {
+        let value = |action: A| self.axis_value(action);
+
+        let movement = Vec2::new(value(right) - value(left), value(up) - value(down));
+        movement.clamp_length_max(1.0)
+    }
+
+    /// Is the [`axis_value`](ActionState::axis_value) of the `action` within the [`NeutralZone`]?
+    ///
+    /// Once the value leaves the neutral zone by exceeding [`NeutralZone::exit`],
+    /// it only returns to neutral once its magnitude falls to [`NeutralZone::enter`] or below.
+    /// This hysteresis prevents values that hover near the boundary from rapidly flickering in and out of neutral.
+    #[inline]
+    #[must_use]
+    pub fn is_neutral(&self, action: A) -> bool {
+        !self.action_data[action.index()].outside_neutral
+    }
+
+    /// Did the `action` return to the [`NeutralZone`] since the last time [tick](ActionState::tick) was called?
+    ///
+    /// This is useful for re-arming flicks and resetting gestures once a stick has been released.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    /// use bevy_utils::Instant;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Aim,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// assert!(action_state.is_neutral(Action::Aim));
+    ///
+    /// action_state.press(Action::Aim);
+    /// assert!(!action_state.is_neutral(Action::Aim));
+    ///
+    /// action_state.release(Action::Aim);
+    /// assert!(action_state.just_returned_to_neutral(Action::Aim));
+    ///
+    /// action_state.tick(Instant::now());
+    /// assert!(action_state.is_neutral(Action::Aim));
+    /// assert!(!action_state.just_returned_to_neutral(Action::Aim));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn just_returned_to_neutral(&self, action: A) -> bool {
+        self.action_data[action.index()].returned_to_neutral
+    }
+
+    /// The [`NeutralZone`] used by [`ActionState::is_neutral`]
+    #[inline]
+    #[must_use]
+    pub fn neutral_zone(&self) -> NeutralZone {
+        self.neutral_zone
+    }
+
+    /// Sets the [`NeutralZone`] used by [`ActionState::is_neutral`]
+    ///
+    /// This takes effect the next time the value of each action changes.
+    #[inline]
+    pub fn set_neutral_zone(&mut self, neutral_zone: NeutralZone) {
+        self.neutral_zone = neutral_zone;
+    }
+
+    /// The [`Cooldown`] of the `action`, if one has been set
+    #[inline]
+    #[must_use]
+    pub fn cooldown(&self, action: A) -> Option<Cooldown> {
+        self.cooldowns.get(action.index()).copied().flatten()
+    }
+
+    /// Sets the length of the [`Cooldown`] that starts each time the `action` is [triggered](ActionState::trigger)
+    ///
+    /// If the `action` is already cooling down, the time remaining is capped at the new `duration`.
+    /// Cooldowns count down each time the [`ActionState`] [ticks](ActionState::tick), and are not serialized.
+    ///
+    /// If the [`InputMap`](crate::input_map::InputMap) driving this [`ActionState`] has its own
+    /// [cooldown](crate::input_map::InputMap::set_cooldown) for the `action`, the map's duration replaces this one
+    /// at the next update. Otherwise, cooldowns set here are left alone by the map.
+    pub fn set_cooldown(&mut self, action: A, duration: Duration) {
+        self.set_map_cooldown_owned(action.clone(), false);
+        let cooldown = self.cooldown_mut(action);
+        match cooldown {
+            Some(cooldown) => cooldown.set_duration(duration),
+            None => *cooldown = Some(Cooldown::new(duration)),
+        }
+    }
+
+    /// Removes the [`Cooldown`] of the `action`, so that it is always [ready](ActionState::ready)
+    pub fn clear_cooldown(&mut self, action: A) {
+        self.set_map_cooldown_owned(action.clone(), false);
+        *self.cooldown_mut(action) = None;
+    }
+
+    /// Copies the cooldown `duration` of an [`InputMap`](crate::input_map::InputMap) to the `action`
+    ///
+    /// A `duration` of `None` only removes cooldowns that were previously set by a map,
+    /// so cooldowns set with [`ActionState::set_cooldown`] are preserved.
+    pub(crate) fn set_map_cooldown(&mut self, action: A, duration: Option<Duration>) {
+        let owned = self
+            .map_cooldowns
+            .get(action.index())
+            .copied()
+            .unwrap_or(false);
+        let current_duration = self
+            .cooldown(action.clone())
+            .map(|cooldown| cooldown.duration());
+
+        match duration {
+            Some(duration) => {
+                if current_duration != Some(duration) {
+                    self.set_cooldown(action.clone(), duration);
+                }
+                self.set_map_cooldown_owned(action, true);
+            }
+            None if owned => self.clear_cooldown(action),
+            None => (),
+        }
+    }
+
+    /// Records whether the cooldown of the `action` was set by an [`InputMap`](crate::input_map::InputMap)
+    fn set_map_cooldown_owned(&mut self, action: A, owned: bool) {
+        self.map_cooldowns.resize(A::N_VARIANTS, false);
+        self.map_cooldowns[action.index()] = owned;
+    }
+
+    /// Is the `action` off cooldown?
+    ///
+    /// Actions without a [`Cooldown`] are always ready.
+    #[inline]
+    #[must_use]
+    pub fn ready(&self, action: A) -> bool {
+        match self.cooldown(action) {
+            Some(cooldown) => cooldown.ready(),
+            None => true,
+        }
+    }
+
+    /// Was the `action` [just pressed](ActionState::just_pressed) while [ready](ActionState::ready)?
+    ///
+    /// This does not start the cooldown: call [`ActionState::trigger`] once the action has actually been performed.
+    #[inline]
+    #[must_use]
+    pub fn just_pressed_and_ready(&self, action: A) -> bool {
+        self.just_pressed(action.clone()) && self.ready(action)
+    }
+
+    /// Starts the [`Cooldown`] of the `action`, if it is [ready](ActionState::ready)
+    ///
+    /// Returns `true` if the action was ready, and `false` if it was still cooling down,
+    /// in which case the cooldown is not restarted.
+    /// Actions without a cooldown can always be triggered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_utils::{Duration, Instant};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Fireball,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.set_cooldown(Action::Fireball, Duration::from_secs(1));
+    ///
+    /// let start = Instant::now();
+    /// action_state.tick(start);
+    /// assert!(action_state.trigger(Action::Fireball));
+    /// assert!(!action_state.ready(Action::Fireball));
+    /// assert!(!action_state.trigger(Action::Fireball));
+    ///
+    /// action_state.tick(start + Duration::from_secs(1));
+    /// assert!(action_state.ready(Action::Fireball));
+    /// ```
+    pub fn trigger(&mut self, action: A) -> bool {
+        match self.cooldown_mut(action) {
+            Some(cooldown) => cooldown.trigger(),
+            None => true,
+        }
+    }
+
+    /// The stored [`Cooldown`] of the `action`, which may be missing if this [`ActionState`] was deserialized
+    fn cooldown_mut(&mut self, action: A) -> &mut Option<Cooldown> {
+        self.cooldowns.resize(A::N_VARIANTS, None);
+        &mut self.cooldowns[action.index()]
+    }
+
+    /// Updates whether the `action` is outside of the [`NeutralZone`], based on its current value
+    fn refresh_neutral(&mut self, action: A) {
+        let magnitude = self.axis_value(action.clone()).abs();
+        let action_data = &mut self.action_data[action.index()];
+
+        if action_data.outside_neutral {
+            if magnitude <= self.neutral_zone.enter {
+                action_data.outside_neutral = false;
+                action_data.returned_to_neutral = true;
+            }
+        } else if magnitude > self.neutral_zone.exit {
+            action_data.outside_neutral = true;
+        }
+    }
+
+    /// Registers the `axis`, so that its net movement is cached each time the state of its actions changes
+    ///
+    /// Registering the same axis more than once has no further effect.
+    /// Registered axes are not serialized.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_math::Vec2;
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::action_state::DirectionalAxis;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Up,
+    ///     Down,
+    ///     Left,
+    ///     Right,
+    /// }
+    ///
+    /// const DPAD: DirectionalAxis<Action> = DirectionalAxis {
+    ///     up: Action::Up,
+    ///     down: Action::Down,
+    ///     left: Action::Left,
+    ///     right: Action::Right,
+    /// };
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.register_directional_axis(&DPAD);
+    /// assert_eq!(action_state.net_direction(&DPAD), None);
+    ///
+    /// action_state.press(Action::Left);
+    /// assert_eq!(action_state.net_movement(&DPAD), Vec2::new(-1.0, 0.0));
+    /// assert!(action_state.net_direction(&DPAD).is_some());
+    /// ```
+    pub fn register_directional_axis(&mut self, axis: &DirectionalAxis<A>) {
+        let indexes = axis.indexes();
+        if self
+            .directional_axes
+            .iter()
+            .all(|(registered, _)| *registered != indexes)
+        {
+            let movement = self.movement_from_indexes(indexes);
+            self.directional_axes.push((indexes, movement));
+        }
+    }
+
+    /// The net movement of the `axis`, as computed by [`ActionState::movement`]
+    ///
+    /// If the `axis` was registered using [`ActionState::register_directional_axis`],
+    /// the cached value is returned; otherwise it is computed on demand.
+    #[must_use]
+    pub fn net_movement(&self, axis: &DirectionalAxis<A>) -> Vec2 {
+        let indexes = axis.indexes();
+        self.directional_axes
+            .iter()
+            .find(|(registered, _)| *registered == indexes)
+            .map(|(_, movement)| *movement)
+            .unwrap_or_else(|| self.movement_from_indexes(indexes))
+    }
+
+    /// The net [`Direction`] of the `axis`, or `None` if none of its actions are pressed or they cancel out
+    #[must_use]
+    pub fn net_direction(&self, axis: &DirectionalAxis<A>) -> Option<Direction> {
+        self.net_movement(axis).try_into().ok()
+    }
+
+    /// Recomputes the cached net movement of each registered [`DirectionalAxis`]
+    fn refresh_directional_axes(&mut self) {
+        for i in 0..self.directional_axes.len() {
+            let indexes = self.directional_axes[i].0;
+            self.directional_axes[i].1 = self.movement_from_indexes(indexes);
+        }
+    }
+
+    /// [`ActionState::movement`], for actions identified by their `[up, down, left, right]` [indexes](Actionlike::index)
+    fn movement_from_indexes(&self, [up, down, left, right]: [usize; 4]) -> Vec2 {
+        self.movement(
+            A::get_at(up).unwrap(),
+            A::get_at(down).unwrap(),
+            A::get_at(left).unwrap(),
+            A::get_at(right).unwrap(),
+        )
+    }
+
+    /// The analog value of the `action`, ranging from -1 to 1, inclusive
+    ///
+    /// This is set from analog inputs like [`UserInput::SingleAxis`] by [`ActionState::update`].
+    /// Buttons report `1.0` when pressed and `0.0` when released.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Throttle,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// assert_eq!(action_state.axis_value(Action::Throttle), 0.0);
+    ///
+    /// action_state.press(Action::Throttle);
+    /// assert_eq!(action_state.axis_value(Action::Throttle), 1.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn axis_value(&self, action: A) -> f32 {
+        self.action_data[action.index()].axis_data.value
+    }
+
+    /// The values of the [`UserInput::DualAxis`] input that is pressing the `action`, such as a joystick
+    ///
+    /// If several of these inputs are pressed, the one with the largest magnitude is used.
+    /// Returns `None` if the action is not being pressed by a [`UserInput::DualAxis`].
+    #[inline]
+    #[must_use]
+    pub fn axis_pair(&self, action: A) -> Option<DualAxisData> {
+        self.action_data[action.index()].axis_data.axis_pair
+    }
+
+    /// The distance scrolled this frame by the [`UserInput::MouseWheel`] inputs bound to the `action`, measured in lines
+    ///
+    /// Scrolling measured in pixels is normalized using [`MOUSE_WHEEL_PIXELS_PER_LINE`](crate::user_input::MOUSE_WHEEL_PIXELS_PER_LINE),
+    /// so trackpads and mouse wheels can be treated alike.
+    /// This is always non-negative, and is `0.0` whenever the action is released.
+    #[inline]
+    #[must_use]
+    pub fn mouse_wheel_value(&self, action: A) -> f32 {
+        self.action_data[action.index()].axis_data.mouse_wheel
+    }
+
+    /// The distance moved this frame by the mouse in the directions of the [`UserInput::MouseMotion`] inputs bound to the `action`
+    ///
+    /// This is measured in the same units as [`MouseMotion`](bevy_input::mouse::MouseMotion) events,
+    /// after applying the [`MouseMotionConfig`](crate::user_input::MouseMotionConfig) resource, if it exists.
+    /// This is always non-negative, and is `0.0` whenever the action is released.
+    #[inline]
+    #[must_use]
+    pub fn mouse_motion_value(&self, action: A) -> f32 {
+        self.action_data[action.index()].axis_data.mouse_motion
+    }
+
+    /// Sets the analog [`axis_value`](ActionState::axis_value) of the `action`, without pressing or releasing it
+    ///
+    /// The `value` is clamped to the range from -1 to 1.
+    /// This is used to apply [`ActionDiff::ValueChanged`], and is useful for AI or testing.
+    pub fn set_axis_value(&mut self, action: A, value: f32) {
+        self.action_data[action.index()].axis_data.value = value.clamp(-1.0, 1.0);
+        self.refresh_neutral(action);
+        self.refresh_directional_axes();
+    }
+
+    /// Sets the [`axis_pair`](ActionState::axis_pair) of the `action`, without pressing or releasing it
+    ///
+    /// This is used to apply [`ActionDiff::AxisPairChanged`].
+    pub fn set_axis_pair(&mut self, action: A, axis_pair: DualAxisData) {
+        self.action_data[action.index()].axis_data.axis_pair = Some(axis_pair);
+    }
+
+    /// Iterates over each action whose [`axis_value`](ActionState::axis_value) has a magnitude of at least `threshold`, along with that value
+    ///
+    /// Actions are returned in the order of their [index](Actionlike::index).
+    /// This is useful for HUDs that highlight every action that is currently held past some point,
+    /// and for debugging the behavior of analog inputs.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Ability {
+    ///     Fireball,
+    ///     Shield,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Ability>::default();
+    /// action_state.press(Ability::Shield);
+    ///
+    /// let above: Vec<(Ability, f32)> = action_state.above(0.5).collect();
+    /// assert_eq!(above, vec![(Ability::Shield, 1.0)]);
+    /// ```
+    pub fn above(&self, threshold: f32) -> impl Iterator<Item = (A, f32)> + '_ {
+        A::variants().filter_map(move |action| {
+            let value = self.axis_value(action.clone());
+            if value.abs() >= threshold {
+                Some((action, value))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The reasons (in terms of [`UserInput`]) that the button was pressed
+    ///
+    /// If the button is currently released, the `Vec<UserInput`> returned will be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::buttonlike::ButtonState;
+    /// use leafwing_input_manager::action_state::ActionData;
+    /// use bevy_input::keyboard::KeyCode;
+    ///
+    /// #[derive(Actionlike, Clone)]
+    /// enum PlatformerAction{
+    ///     Move,
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<PlatformerAction>::default();
+    ///
+    /// // Usually this will be done automatically for you, via [`ActionState::update`]
+    /// action_state.set_action_data(PlatformerAction::Jump,
+    ///   ActionData {
+    ///         state: ButtonState::JustPressed,
+    ///         // Manually setting the reason why this action was pressed
+    ///         reasons_pressed: vec![KeyCode::Space.into()],
+    ///         // For the sake of this example, we don't care about any other fields
+    ///         ..Default::default()
+    ///     }
+    /// );
+    ///
+    /// let reasons_jumped = action_state.reasons_pressed(PlatformerAction::Jump);
+    /// assert_eq!(reasons_jumped[0], KeyCode::Space.into());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn reasons_pressed(&self, action: A) -> Vec<UserInput> {
+        self.action_data[action.index()].reasons_pressed.clone()
+    }
+
+    /// Should a navigation step occur for the `action` this tick?
+    ///
+    /// Returns `true` when the action was just pressed,
+    /// and then again at the cadence described by `nav_repeat` while it remains held.
+    /// This is intended for menu navigation, where holding a direction should scroll through options.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::action_state::NavRepeat;
+    /// use bevy_utils::{Duration, Instant};
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum MenuAction {
+    ///     Down,
+    /// }
+    ///
+    /// let nav_repeat = NavRepeat {
+    ///     delay: Duration::from_millis(500),
+    ///     interval: Duration::from_millis(100),
+    /// };
+    ///
+    /// let mut action_state = ActionState::<MenuAction>::default();
+    /// action_state.press(MenuAction::Down);
+    /// // The first press always navigates
+    /// assert!(action_state.navigate(MenuAction::Down, nav_repeat));
+    ///
+    /// let start = Instant::now();
+    /// action_state.tick(start);
+    /// assert!(!action_state.navigate(MenuAction::Down, nav_repeat));
+    ///
+    /// // Once the delay has elapsed, navigation repeats
+    /// action_state.tick(start + Duration::from_millis(500));
+    /// assert!(action_state.navigate(MenuAction::Down, nav_repeat));
+    /// action_state.tick(start + Duration::from_millis(550));
+    /// assert!(!action_state.navigate(MenuAction::Down, nav_repeat));
+    /// action_state.tick(start + Duration::from_millis(600));
+    /// assert!(action_state.navigate(MenuAction::Down, nav_repeat));
+    /// ```
+    #[must_use]
+    pub fn navigate(&self, action: A, nav_repeat: NavRepeat) -> bool {
+        let action_data = &self.action_data[action.index()];
+
+        if action_data.state.just_pressed() {
+            return true;
+        }
+
+        if !action_data.state.pressed() {
+            return false;
+        }
+
+        nav_repeat.repeats(action_data.timing.current_duration)
+            > nav_repeat.repeats(action_data.timing.previous_tick_duration)
+    }
+
+    /// Which level of `hold_levels` did the `action` fire this tick, if any?
+    ///
+    /// Levels are numbered from 0, in order of increasing threshold.
+    /// When the [`HoldTrigger`] is [`OnCrossing`](HoldTrigger::OnCrossing), each level fires once as its threshold is crossed,
+    /// with only the highest level reported if several are crossed during the same tick.
+    /// When it is [`OnRelease`](HoldTrigger::OnRelease), only the highest level reached fires, as the action is released.
+    ///
+    /// This is intended for multi-stage charge abilities, where holding an action for longer performs a stronger action.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::action_state::{HoldLevels, HoldTrigger};
+    /// use bevy_utils::{Duration, Instant};
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Charge,
+    /// }
+    ///
+    /// let hold_levels = HoldLevels::new(
+    ///     [Duration::from_millis(200), Duration::from_millis(800)],
+    ///     HoldTrigger::OnRelease,
+    /// );
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// let start = Instant::now();
+    /// action_state.tick(start);
+    /// action_state.press(Action::Charge);
+    /// action_state.tick(start);
+    ///
+    /// // Nothing fires while the action is held
+    /// action_state.tick(start + Duration::from_millis(500));
+    /// assert_eq!(action_state.current_hold_level(Action::Charge, &hold_levels), Some(0));
+    /// assert_eq!(action_state.hold_level(Action::Charge, &hold_levels), None);
+    ///
+    /// // Releasing fires the level that was reached
+    /// action_state.release(Action::Charge);
+    /// assert_eq!(action_state.hold_level(Action::Charge, &hold_levels), Some(0));
+    /// ```
+    #[must_use]
+    pub fn hold_level(&self, action: A, hold_levels: &HoldLevels) -> Option<usize> {
+        let action_data = &self.action_data[action.index()];
+
+        match hold_levels.trigger {
+            HoldTrigger::OnCrossing => {
+                if !action_data.state.pressed() {
+                    return None;
+                }
+
+                let reached = hold_levels.levels_reached(action_data.timing.current_duration);
+                let reached_before = if action_data.state.just_pressed() {
+                    0
+                } else {
+                    hold_levels.levels_reached(action_data.timing.previous_tick_duration)
+                };
+
+                (reached > reached_before).then(|| reached - 1)
+            }
+            HoldTrigger::OnRelease => {
+                if !action_data.state.just_released() {
+                    return None;
+                }
+
+                hold_levels
+                    .levels_reached(action_data.timing.previous_duration)
+                    .checked_sub(1)
+            }
+        }
+    }
+
+    /// Which level of `hold_levels` has the `action` reached while it has been held, if any?
+    ///
+    /// Unlike [`hold_level`](ActionState::hold_level), this is reported every tick while the action is pressed,
+    /// and is useful for displaying charge meters.
+    #[must_use]
+    pub fn current_hold_level(&self, action: A, hold_levels: &HoldLevels) -> Option<usize> {
+        let action_data = &self.action_data[action.index()];
+
+        if !action_data.state.pressed() {
+            return None;
+        }
+
+        hold_levels
+            .levels_reached(action_data.timing.current_duration)
+            .checked_sub(1)
+    }
+
+    /// Was the chord pressing this `action` broken since the last time [tick](ActionState::tick) was called?
+    ///
+    /// A chord is broken when one of its buttons is released while at least one of the others remains held.
+    /// Releasing every button of the chord at once does not count.
+    /// This is useful for cancelling charged abilities that are performed by holding a chord.
+    #[inline]
+    #[must_use]
+    pub fn chord_broken(&self, action: A) -> bool {
+        self.action_data[action.index()].chord_broken
+    }
+
+    /// Was the chord pressing this `action` completed since the last time [tick](ActionState::tick) was called?
+    ///
+    /// A chord action is only pressed once every one of its buttons is held,
+    /// so this is true only on the tick that the final required button goes down:
+    /// holding some of the buttons beforehand never presses the action.
+    /// This is [`just_pressed`](ActionState::just_pressed), restricted to presses caused by a [`UserInput::Chord`],
+    /// and so is false if the action was instead pressed by one of its other bindings.
+    #[inline]
+    #[must_use]
+    pub fn chord_just_completed(&self, action: A) -> bool {
+        let action_data = &self.action_data[action.index()];
+
+        action_data.state.just_pressed()
+            && action_data
+                .reasons_pressed
+                .iter()
+                .any(|input| matches!(input, UserInput::Chord(_)))
+    }
+
+    /// Adds `amount` (which may be fractional or negative) to the steps accumulated by the `action`
+    ///
+    /// This is used to quantize continuous inputs into discrete steps, to be read with [`ActionState::steps`].
+    #[inline]
+    pub fn accumulate_steps(&mut self, action: A, amount: f32) {
+        self.action_data[action.index()].pending_steps += amount;
+    }
+
+    /// Returns the whole number of steps accumulated by the `action` since this method was last called
+    ///
+    /// Each press of the action counts as one step, as do fractional amounts added by [`ActionState::accumulate_steps`].
+    /// Only whole steps are returned and removed: any fractional remainder carries over to the next call.
+    /// This is useful for stepper UIs, like adjusting volume by notches.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     VolumeUp,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    ///
+    /// action_state.press(Action::VolumeUp);
+    /// action_state.release(Action::VolumeUp);
+    /// action_state.press(Action::VolumeUp);
+    /// assert_eq!(action_state.steps(Action::VolumeUp), 2);
+    ///
+    /// // Steps are reset once read
+    /// assert_eq!(action_state.steps(Action::VolumeUp), 0);
+    ///
+    /// // Fractional steps carry over
+    /// action_state.accumulate_steps(Action::VolumeUp, 0.75);
+    /// assert_eq!(action_state.steps(Action::VolumeUp), 0);
+    /// action_state.accumulate_steps(Action::VolumeUp, 0.75);
+    /// assert_eq!(action_state.steps(Action::VolumeUp), 1);
+    /// ```
+    pub fn steps(&mut self, action: A) -> i32 {
+        let pending_steps = &mut self.action_data[action.index()].pending_steps;
+        let whole_steps = pending_steps.trunc();
+        *pending_steps -= whole_steps;
+
+        whole_steps as i32
+    }
+
+    /// What was responsible for the current state of the `action`?
+    ///
+    /// This is reset to [`ActionSource::Carried`] each time [`ActionState::tick`] is called.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::action_state::ActionSource;
+    /// use bevy_utils::Instant;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Run,
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Jump);
+    /// assert_eq!(action_state.source(Action::Jump), ActionSource::Manual);
+    ///
+    /// action_state.tick(Instant::now());
+    /// assert_eq!(action_state.source(Action::Jump), ActionSource::Carried);
+    /// ```
+    #[cfg(feature = "action_source")]
+    #[inline]
+    #[must_use]
+    pub fn source(&self, action: A) -> ActionSource {
+        self.action_data[action.index()].source
+    }
+
+    /// Overrides the recorded [`ActionSource`] of the `action`
+    ///
+    /// Systems that drive an [`ActionState`] from an unusual source should call this after changing its state.
+    #[cfg(feature = "action_source")]
+    #[inline]
+    pub fn set_source(&mut self, action: A, source: ActionSource) {
+        self.action_data[action.index()].source = source;
+    }
+
+    /// Is this [`ActionState`] being driven by replayed [`ActionDiff`]s, rather than live input?
+    ///
+    /// This is set by [`process_action_diffs`](crate::systems::process_action_diffs) whenever it applies a diff,
+    /// and cleared whenever the state is updated from live input by [`ActionState::update`].
+    /// Use this to indicate replays (such as ghost races) in the UI, or to branch gameplay logic.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// assert!(!action_state.is_replaying());
+    ///
+    /// action_state.set_replaying(true);
+    /// assert!(action_state.is_replaying());
+    ///
+    /// // Live input ends the replay
+    /// action_state.update(vec![Default::default(); Action::N_VARIANTS]);
+    /// assert!(!action_state.is_replaying());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_replaying(&self) -> bool {
+        self.replaying
+    }
+
+    /// Records whether or not this [`ActionState`] is being driven by replayed [`ActionDiff`]s
+    ///
+    /// Systems that feed replays into an [`ActionState`] by some other means should call this.
+    #[inline]
+    pub fn set_replaying(&mut self, replaying: bool) {
+        self.replaying = replaying;
+    }
+
+    /// The [`Instant`] that the action was last pressed or released
+    ///
+    /// If the action was pressed or released since the last time [`ActionState::tick`] was called
+    /// the value will be [`None`].
+    /// This ensures that all of our actions are assigned a timing and duration
+    /// that corresponds exactly to the start of a frame, rather than relying on idiosyncratic timing.
+    pub fn instant_started(&self, action: A) -> Option<Instant> {
+        self.action_data[action.index()].timing.instant_started
+    }
+
+    /// The [`Duration`] for which the action has been held or released
+    pub fn current_duration(&self, action: A) -> Duration {
+        self.action_data[action.index()].timing.current_duration
+    }
+
+    /// The [`Duration`] for which the action was last held or released
+    ///
+    /// This is a snapshot of the [`ActionState::current_duration`] state at the time
+    /// the action was last pressed or released.
+    pub fn previous_duration(&self, action: A) -> Duration {
+        self.action_data[action.index()].timing.previous_duration
+    }
+
+    /// The [`Duration`] for which the action has been held
+    ///
+    /// Unlike [`ActionState::current_duration`], this is [`Duration::ZERO`] whenever the action is released.
+    #[must_use]
+    pub fn held_for(&self, action: A) -> Duration {
+        let action_data = &self.action_data[action.index()];
+
+        if action_data.state.pressed() {
+            action_data.timing.current_duration
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// The [`Duration`] since the action was last released
+    ///
+    /// Unlike [`ActionState::current_duration`], this is [`Duration::ZERO`] whenever the action is pressed.
+    /// Actions that have never been pressed count from the first [`ActionState::tick`].
+    /// This is useful for leniency windows, such as "coyote time" for jumping just after walking off a ledge.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_utils::{Duration, Instant};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Grounded,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// let start = Instant::now();
+    /// action_state.press(Action::Grounded);
+    /// action_state.tick(start);
+    /// assert_eq!(action_state.released_for(Action::Grounded), Duration::ZERO);
+    ///
+    /// action_state.release(Action::Grounded);
+    /// action_state.tick(start + Duration::from_millis(100));
+    /// action_state.tick(start + Duration::from_millis(200));
+    /// assert_eq!(action_state.released_for(Action::Grounded), Duration::from_millis(100));
+    /// assert!(action_state.released_for(Action::Grounded) < Duration::from_millis(150));
+    /// ```
+    #[must_use]
+    pub fn released_for(&self, action: A) -> Duration {
+        let action_data = &self.action_data[action.index()];
+
+        if action_data.state.released() {
+            action_data.timing.current_duration
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// The number of seconds for which the action has been held, as an [`f32`]
+    ///
+    /// This is intended for driving shader and animation parameters, and is 0.0 whenever the action is released.
+    /// Unlike [`ActionState::held_for`], which is measured from the [`ActionState::tick`] after a press,
+    /// this is measured from the start of the tick during which the action was pressed.
+    /// On the frame that the action is just pressed, that tick is still in progress,
+    /// so the length of the previous tick is used instead, as an estimate of one frame.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_utils::{Duration, Instant};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Charge,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// let start = Instant::now();
+    /// action_state.tick(start);
+    /// assert_eq!(action_state.press_duration_secs(Action::Charge), 0.0);
+    ///
+    /// // Without a previous tick, there is no frame length to estimate from
+    /// action_state.press(Action::Charge);
+    /// assert_eq!(action_state.press_duration_secs(Action::Charge), 0.0);
+    ///
+    /// action_state.tick(start + Duration::from_millis(100));
+    /// assert_eq!(action_state.press_duration_secs(Action::Charge), 0.1);
+    /// action_state.tick(start + Duration::from_millis(600));
+    /// assert_eq!(action_state.press_duration_secs(Action::Charge), 0.6);
+    ///
+    /// // The final duration remains available on the frame that the action is released
+    /// action_state.release(Action::Charge);
+    /// assert_eq!(action_state.press_duration_secs(Action::Charge), 0.0);
+    /// assert_eq!(action_state.released_press_duration_secs(Action::Charge), 0.6);
+    ///
+    /// action_state.tick(start + Duration::from_millis(700));
+    /// assert_eq!(action_state.released_press_duration_secs(Action::Charge), 0.0);
+    ///
+    /// // Actions that were just pressed have been held for about one frame
+    /// action_state.press(Action::Charge);
+    /// assert_eq!(action_state.press_duration_secs(Action::Charge), 0.1);
+    /// ```
+    #[must_use]
+    pub fn press_duration_secs(&self, action: A) -> f32 {
+        let state = self.action_data[action.index()].state;
+
+        if state.just_pressed() {
+            return match (self.previous_tick, self.last_tick) {
+                (Some(previous_tick), Some(last_tick)) => last_tick
+                    .saturating_duration_since(previous_tick)
+                    .as_secs_f32(),
+                _ => 0.0,
+            };
+        }
+
+        if state.released() {
+            return 0.0;
+        }
+
+        self.time_since_press_tick(action.clone())
+            .unwrap_or_else(|| self.held_for(action))
+            .as_secs_f32()
+    }
+
+    /// The number of seconds for which the action was held before it was released, as an [`f32`]
+    ///
+    /// This is only non-zero on the frame that the action is just released, and is 0.0 otherwise.
+    /// See [`ActionState::press_duration_secs`] for an example.
+    #[must_use]
+    pub fn released_press_duration_secs(&self, action: A) -> f32 {
+        let action_data = &self.action_data[action.index()];
+
+        if !action_data.state.just_released() {
+            return 0.0;
+        }
+
+        self.time_since_press_tick(action)
+            .unwrap_or(action_data.timing.previous_duration)
+            .as_secs_f32()
+    }
+
+    /// The time from the start of the tick during which the most recent press of the `action` occurred until the most recent tick
+    ///
+    /// Returns `None` if that press was not recorded in the `recent_presses` of the action.
+    fn time_since_press_tick(&self, action: A) -> Option<Duration> {
+        let press_tick = *self.action_data[action.index()].recent_presses.back()?;
+        Some(self.last_tick?.saturating_duration_since(press_tick))
+    }
+
+    /// Did the [`ActionState::held_for`] duration of the `action` reach `threshold` during the most recent tick?
+    ///
+    /// This is true for exactly one tick per press, making it easy to implement "hold to activate" actions.
+    /// A `threshold` of [`Duration::ZERO`] is reached as soon as the action is pressed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_utils::{Duration, Instant};
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Open,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// let threshold = Duration::from_secs(1);
+    ///
+    /// action_state.press(Action::Open);
+    /// let start = Instant::now();
+    /// action_state.tick(start);
+    /// assert!(!action_state.just_exceeded(Action::Open, threshold));
+    ///
+    /// action_state.tick(start + Duration::from_millis(1200));
+    /// assert_eq!(action_state.held_for(Action::Open), Duration::from_millis(1200));
+    /// assert!(action_state.just_exceeded(Action::Open, threshold));
+    ///
+    /// // Continuing to hold the action does not fire again
+    /// action_state.tick(start + Duration::from_millis(1300));
+    /// assert!(!action_state.just_exceeded(Action::Open, threshold));
+    /// ```
+    #[must_use]
+    pub fn just_exceeded(&self, action: A, threshold: Duration) -> bool {
+        let action_data = &self.action_data[action.index()];
+
+        if !action_data.state.pressed() {
+            return false;
+        }
+
+        if threshold == Duration::ZERO {
+            return action_data.state.just_pressed();
+        }
+
+        action_data.timing.previous_tick_duration < threshold
+            && action_data.timing.current_duration >= threshold
+    }
+}
+
+// Interoperability with `bevy_input`
+impl<A: Actionlike + Copy + Eq + Hash> ActionState<A> {
+    /// Creates an [`Input<A>`] with the same pressed, just pressed and just released actions as this [`ActionState`]
+    ///
+    /// This allows actions to be consumed by systems and crates that expect Bevy's [`Input`] API.
+    /// Just like the button states of an [`ActionState`], the returned [`Input`] reports
+    /// each `just_pressed` and `just_released` action from the [`update`](ActionState::update) that changed its state
+    /// until the next [`tick`](ActionState::tick).
+    /// To keep an [`Input<A>`] resource synchronized automatically, use [`update_input_from_action_state`](crate::systems::update_input_from_action_state).
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    /// enum Action {
+    ///     Jump,
+    ///     Run,
+    /// }
+    ///
+    /// let mut action_state = ActionState::<Action>::default();
+    /// action_state.press(Action::Jump);
+    ///
+    /// let input = action_state.to_input();
+    /// assert!(input.pressed(Action::Jump));
+    /// assert!(input.just_pressed(Action::Jump));
+    /// assert!(!input.pressed(Action::Run));
+    /// ```
+    #[must_use]
+    pub fn to_input(&self) -> Input<A> {
+        let mut input = Input::default();
+        self.write_to_input(&mut input);
+        input
+    }
+
+    /// Overwrites the state of the `input` to match this [`ActionState`]
+    ///
+    /// See [`ActionState::to_input`] for more details.
+    pub fn write_to_input(&self, input: &mut Input<A>) {
+        *input = Input::default();
+
+        // Actions that were already held before this update must not be reported as just pressed
+        for action in A::variants() {
+            let state = &self.action_data[action.index()].state;
+            if (state.pressed() && !state.just_pressed()) || state.just_released() {
+                input.press(action);
+            }
+        }
+        input.clear();
+
+        for action in A::variants() {
+            let state = &self.action_data[action.index()].state;
+            if state.just_pressed() {
+                input.press(action);
+            } else if state.just_released() {
+                input.release(action);
+            }
+        }
+    }
+}
+
+impl<A: Actionlike> Default for ActionState<A> {
     fn default() -> ActionState<A> {
         ActionState {
             action_data: A::variants().map(|_| ActionData::default()).collect(),
+            last_tick: None,
+            previous_tick: None,
+            directional_axes: Vec::new(),
+            replaying: false,
+            neutral_zone: NeutralZone::default(),
+            cooldowns: vec![None; A::N_VARIANTS],
+            map_cooldowns: vec![false; A::N_VARIANTS],
             _phantom: PhantomData::default(),
         }
     }
 }
 
+/// The number of bytes used by [`ActionState::to_serialized_inputs`]: one bit per action, rounded up
+fn serialized_inputs_len<A: Actionlike>() -> usize {
+    let full_bytes = A::N_VARIANTS / 8;
+    if A::N_VARIANTS % 8 == 0 {
+        full_bytes
+    } else {
+        full_bytes + 1
+    }
+}
+
+/// The state of every action in an [`ActionState`], captured by [`ActionState::snapshot`]
+///
+/// Reinstate it with [`ActionState::restore`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionStateSnapshot<A: Actionlike> {
+    action_data: Vec<ActionData>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> ActionStateSnapshot<A> {
+    /// The captured [`ActionData`] of the `action`
+    #[must_use]
+    pub fn action_data(&self, action: A) -> &ActionData {
+        &self.action_data[action.index()]
+    }
+}
+
 /// A component that allows the attached entity to drive the [`ActionState`] of the associated entity
 ///
+/// Every action in `actions` is pressed at once, so a single button can trigger several actions.
+///
 /// Used in [`update_action_state_from_interaction`](crate::systems::update_action_state_from_interaction).
-#[derive(Component, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Component, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct ActionStateDriver<A: Actionlike> {
-    /// The action triggered by this entity
-    pub action: A,
+    /// The actions triggered by this entity
+    pub actions: Vec<A>,
     /// The entity whose action state should be updated
     pub entity: Entity,
 }
 
+impl<A: Actionlike> ActionStateDriver<A> {
+    /// Creates a new [`ActionStateDriver`] which triggers a single `action` of the `entity`
+    #[must_use]
+    pub fn new(action: A, entity: Entity) -> Self {
+        ActionStateDriver {
+            actions: vec![action],
+            entity,
+        }
+    }
+
+    /// Creates a new [`ActionStateDriver`] which triggers all of the `actions` of the `entity` at once
+    #[must_use]
+    pub fn with_actions(actions: impl IntoIterator<Item = A>, entity: Entity) -> Self {
+        ActionStateDriver {
+            actions: actions.into_iter().collect(),
+            entity,
+        }
+    }
+}
+
 /// Stores information about when an action was pressed or released
 ///
 /// This struct is principally used as a field on [`ActionData`],
@@ -450,6 +2378,10 @@ pub struct Timing {
     pub current_duration: Duration,
     /// The [`Duration`] for which the button was pressed or released before the state last changed.
     pub previous_duration: Duration,
+    /// The value of `current_duration` before the most recent call to [`Timing::tick`]
+    ///
+    /// Used to detect when a duration threshold was crossed during the most recent tick.
+    pub previous_tick_duration: Duration,
 }
 
 impl PartialOrd for Timing {
@@ -464,6 +2396,8 @@ impl Timing {
     /// If the `instant_started` is None, it will be set to the current time.
     /// This design allows us to ensure that the timing is always synchronized with the start of each frame.
     pub fn tick(&mut self, current_time: Instant) {
+        self.previous_tick_duration = self.current_duration;
+
         if let Some(instant_started) = self.instant_started {
             self.current_duration = current_time - instant_started;
         } else {
@@ -477,19 +2411,224 @@ impl Timing {
     pub fn flip(&mut self) {
         self.previous_duration = self.current_duration;
         self.current_duration = Duration::ZERO;
+        self.previous_tick_duration = Duration::ZERO;
         self.instant_started = None;
     }
 }
 
-/// Stores presses and releases of buttons without timing information
+/// Controls the cadence of repeated steps when a navigation action is held, for use with [`ActionState::navigate`]
+///
+/// The first step occurs immediately when the action is pressed.
+/// If it is held for `delay`, another step occurs, and then another after each additional `interval`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NavRepeat {
+    /// How long the action must be held before it begins to repeat
+    pub delay: Duration,
+    /// How long to wait between each repeat, once repeating has begun
+    ///
+    /// If this is [`Duration::ZERO`], the action will repeat every tick once `delay` has elapsed.
+    pub interval: Duration,
+}
+
+impl Default for NavRepeat {
+    fn default() -> Self {
+        NavRepeat {
+            delay: Duration::from_millis(400),
+            interval: Duration::from_millis(100),
+        }
+    }
+}
+
+impl NavRepeat {
+    /// The number of repeats that should have occurred once the action has been held for `held_duration`
+    #[must_use]
+    fn repeats(&self, held_duration: Duration) -> u128 {
+        if held_duration < self.delay {
+            return 0;
+        }
+
+        if self.interval.is_zero() {
+            return held_duration.as_nanos();
+        }
+
+        1 + (held_duration - self.delay).as_nanos() / self.interval.as_nanos()
+    }
+}
+
+/// Four actions which together describe movement in a plane, for use with [`ActionState::net_direction`]
+///
+/// Register these with [`ActionState::register_directional_axis`] to cache their net movement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DirectionalAxis<A: Actionlike> {
+    /// The action that moves in the positive y direction
+    pub up: A,
+    /// The action that moves in the negative y direction
+    pub down: A,
+    /// The action that moves in the negative x direction
+    pub left: A,
+    /// The action that moves in the positive x direction
+    pub right: A,
+}
+
+impl<A: Actionlike> DirectionalAxis<A> {
+    /// The [`Actionlike::index`] of each action, in the order `[up, down, left, right]`
+    #[must_use]
+    fn indexes(&self) -> [usize; 4] {
+        [
+            self.up.index(),
+            self.down.index(),
+            self.left.index(),
+            self.right.index(),
+        ]
+    }
+}
+
+/// The thresholds around zero within which the value of an action is considered neutral, for use with [`ActionState::is_neutral`]
+///
+/// These are compared to the magnitude of the value of each action.
+/// `exit` should be at least as large as `enter`: the gap between them provides hysteresis.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NeutralZone {
+    /// Values with a magnitude at or below this return to neutral
+    pub enter: f32,
+    /// Values with a magnitude above this leave neutral
+    pub exit: f32,
+}
+
+impl Default for NeutralZone {
+    fn default() -> Self {
+        NeutralZone {
+            enter: 0.1,
+            exit: 0.2,
+        }
+    }
+}
+
+/// The time until an action can be [triggered](ActionState::trigger) again
+///
+/// Set with [`ActionState::set_cooldown`], or for every [`ActionState`] driven by an
+/// [`InputMap`](crate::input_map::InputMap) with [`InputMap::set_cooldown`](crate::input_map::InputMap::set_cooldown).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Cooldown {
+    duration: Duration,
+    remaining: Duration,
+}
+
+impl Cooldown {
+    /// Creates a new [`Cooldown`] of the provided `duration`, which starts ready
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        Cooldown {
+            duration,
+            remaining: Duration::ZERO,
+        }
+    }
+
+    /// How long the action is unavailable for after being triggered
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Changes the `duration` of this cooldown, capping the time remaining to match
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+        self.remaining = self.remaining.min(duration);
+    }
+
+    /// How long until the action is ready again
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+
+    /// Has this cooldown finished?
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        self.remaining.is_zero()
+    }
+
+    /// Starts this cooldown if it is ready, returning whether it was
+    pub fn trigger(&mut self) -> bool {
+        if self.ready() {
+            self.remaining = self.duration;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Ends this cooldown immediately
+    pub fn refresh(&mut self) {
+        self.remaining = Duration::ZERO;
+    }
+
+    /// Advances this cooldown by `delta`
+    pub fn tick(&mut self, delta: Duration) {
+        self.remaining = self.remaining.saturating_sub(delta);
+    }
+}
+
+/// When should the levels of a [`HoldLevels`] fire?
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum HoldTrigger {
+    /// Each level fires as soon as its threshold is crossed, while the action is still held
+    #[default]
+    OnCrossing,
+    /// Only the highest level reached fires, when the action is released
+    OnRelease,
+}
+
+/// A series of held-duration thresholds for a single action, for use with [`ActionState::hold_level`]
+///
+/// This generalizes tap and hold bindings: an action released before the first threshold reaches no level.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct HoldLevels {
+    /// How long the action must be held to reach each level, sorted from shortest to longest
+    thresholds: Vec<Duration>,
+    /// When the levels fire
+    pub trigger: HoldTrigger,
+}
+
+impl HoldLevels {
+    /// Creates a new [`HoldLevels`] from the provided `thresholds`, which may be in any order
+    #[must_use]
+    pub fn new(thresholds: impl IntoIterator<Item = Duration>, trigger: HoldTrigger) -> Self {
+        let mut thresholds: Vec<Duration> = thresholds.into_iter().collect();
+        thresholds.sort();
+
+        HoldLevels {
+            thresholds,
+            trigger,
+        }
+    }
+
+    /// The thresholds of each level, sorted from shortest to longest
+    #[inline]
+    #[must_use]
+    pub fn thresholds(&self) -> &[Duration] {
+        &self.thresholds
+    }
+
+    /// The number of levels that have been reached once the action has been held for `held_duration`
+    #[must_use]
+    fn levels_reached(&self, held_duration: Duration) -> usize {
+        self.thresholds
+            .partition_point(|&threshold| threshold <= held_duration)
+    }
+}
+
+/// Stores presses, releases and analog values of actions without timing information
 ///
 /// These are typically accessed using the `Events<ActionDiff>` resource.
 /// Uses a minimal storage format, in order to facilitate transport over the network.
+/// Analog values are only sent when they change by more than [`ACTION_DIFF_EPSILON`].
 ///
 /// `ID` should be a component type that stores a unique stable identifier for the entity
 /// that stores the corresponding [`ActionState`].
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum ActionDiff<A: Actionlike, ID: Eq + Clone + Component> {
+/// Diffs that are not tied to an entity, such as those created by [`ActionState::diff_against`], use `()`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ActionDiff<A: Actionlike, ID: Eq + Clone> {
     /// The action was pressed
     Pressed {
         /// The value of the action
@@ -504,13 +2643,87 @@ pub enum ActionDiff<A: Actionlike, ID: Eq + Clone + Component> {
         /// The stable identifier of the entity
         id: ID,
     },
+    /// The [`axis_value`](ActionState::axis_value) of the action changed
+    ValueChanged {
+        /// The value of the action
+        action: A,
+        /// The stable identifier of the entity
+        id: ID,
+        /// The new analog value
+        value: f32,
+    },
+    /// The [`axis_pair`](ActionState::axis_pair) of the action changed
+    AxisPairChanged {
+        /// The value of the action
+        action: A,
+        /// The stable identifier of the entity
+        id: ID,
+        /// The new (x, y) values
+        axis_pair: Vec2,
+    },
+}
+
+impl<A: Actionlike, ID: Eq + Clone> ActionDiff<A, ID> {
+    /// The stable identifier of the entity that this diff applies to
+    #[must_use]
+    pub fn id(&self) -> &ID {
+        match self {
+            ActionDiff::Pressed { id, .. }
+            | ActionDiff::Released { id, .. }
+            | ActionDiff::ValueChanged { id, .. }
+            | ActionDiff::AxisPairChanged { id, .. } => id,
+        }
+    }
+
+    /// The action that this diff applies to
+    #[must_use]
+    pub fn action(&self) -> &A {
+        match self {
+            ActionDiff::Pressed { action, .. }
+            | ActionDiff::Released { action, .. }
+            | ActionDiff::ValueChanged { action, .. }
+            | ActionDiff::AxisPairChanged { action, .. } => action,
+        }
+    }
+}
+
+/// Every [`ActionDiff`] generated for a single entity during one frame
+///
+/// Sending these instead of individual [`ActionDiff`]s lets transport layers send one message per entity per tick.
+/// Created by [`generate_action_diff_batches`](crate::systems::generate_action_diff_batches)
+/// and applied by [`process_action_diff_batches`](crate::systems::process_action_diff_batches).
+///
+/// The `diffs` are deterministically ordered, so chords are replicated identically every time:
+/// first all presses, then all releases, then all analog value changes,
+/// with the actions of each kind ordered by their [index](Actionlike::index).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActionDiffBatch<A: Actionlike, ID: Eq + Clone + Component> {
+    /// The stable identifier of the entity
+    pub id: ID,
+    /// The diffs for this entity, in the order that they should be applied
+    pub diffs: Vec<ActionDiff<A, ID>>,
+}
+
+/// An event sent whenever the [`axis_value`](ActionState::axis_value) of an action changes, for systems that prefer events over polling
+///
+/// Sent by [`generate_axis_events`](crate::systems::generate_axis_events),
+/// which is added by [`InputManagerPlugin::with_axis_events`](crate::plugin::InputManagerPlugin::with_axis_events).
+/// Buttons have a value of `1.0` while pressed, so they also send these events when pressed and released.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AxisChanged<A: Actionlike> {
+    /// The entity with the [`ActionState`] component that changed, or `None` if the [`ActionState`] resource changed
+    pub entity: Option<Entity>,
+    /// The action whose value changed
+    pub action: A,
+    /// The new value of the action
+    pub value: f32,
 }
 
 mod tests {
     use crate as leafwing_input_manager;
     use crate::Actionlike;
 
-    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
     enum Action {
         Run,
         Jump,
@@ -614,6 +2827,34 @@ mod tests {
         assert!(!action_state.just_pressed(Action::Jump));
     }
 
+    #[test]
+    fn most_recent_action() {
+        use crate::action_state::ActionState;
+        use bevy_utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let t0 = Instant::now();
+        assert_eq!(action_state.most_recent_action(), None);
+
+        action_state.press(Action::Run);
+        action_state.tick(t0);
+        assert_eq!(action_state.most_recent_action(), Some(Action::Run));
+
+        // Actions pressed on the same tick are tied, and broken by index
+        action_state.press(Action::Hide);
+        action_state.press(Action::Jump);
+        action_state.tick(t0 + Duration::from_secs(1));
+        assert_eq!(action_state.most_recent_action(), Some(Action::Jump));
+
+        // Released actions are not considered
+        action_state.release(Action::Jump);
+        action_state.tick(t0 + Duration::from_secs(2));
+        assert_eq!(action_state.most_recent_action(), Some(Action::Hide));
+
+        action_state.release_all();
+        assert_eq!(action_state.most_recent_action(), None);
+    }
+
     #[test]
     fn durations() {
         use crate::action_state::ActionState;
@@ -658,4 +2899,644 @@ mod tests {
         assert_eq!(action_state.current_duration(Action::Jump), Duration::ZERO);
         assert_eq!(action_state.previous_duration(Action::Jump), t1 - t0,);
     }
+
+    #[test]
+    fn restore_snapshot() {
+        use crate::action_state::ActionState;
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(Action::Run);
+        action_state.press(Action::Jump);
+
+        // Snapshots taken mid-frame still hold edges
+        let snapshot = action_state.snapshot();
+        action_state.release_all();
+        action_state.press(Action::Jump);
+        action_state.consume(Action::Jump);
+
+        action_state.restore(snapshot);
+        // Edges are not re-fired
+        assert!(action_state.pressed(Action::Run));
+        assert!(!action_state.just_pressed(Action::Run));
+        // And counters do not roll back
+        assert_eq!(action_state.press_count(Action::Run), 1);
+        assert_eq!(action_state.press_count(Action::Jump), 2);
+        assert_eq!(action_state.release_count(Action::Jump), 2);
+        assert!(action_state.action_data(Action::Jump).consumed);
+    }
+
+    #[test]
+    fn restore_keeps_consumed_actions_released() {
+        use crate::action_state::ActionState;
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(Action::Jump);
+
+        let snapshot = action_state.snapshot();
+        action_state.consume(Action::Jump);
+
+        action_state.restore(snapshot);
+        assert!(action_state.released(Action::Jump));
+        assert!(action_state.action_data(Action::Jump).consumed);
+    }
+
+    #[test]
+    fn registered_directional_axes_stay_up_to_date() {
+        use crate::action_state::{ActionData, ActionState, DirectionalAxis};
+        use bevy::math::Vec2;
+
+        #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        enum Move {
+            Up,
+            Down,
+            Left,
+            Right,
+        }
+
+        const DPAD: DirectionalAxis<Move> = DirectionalAxis {
+            up: Move::Up,
+            down: Move::Down,
+            left: Move::Left,
+            right: Move::Right,
+        };
+
+        let mut action_state = ActionState::<Move>::default();
+        action_state.press(Move::Left);
+        // Registering computes the movement of actions that are already pressed
+        action_state.register_directional_axis(&DPAD);
+        assert_eq!(action_state.net_movement(&DPAD), Vec2::new(-1.0, 0.0));
+
+        let snapshot = action_state.snapshot();
+        action_state.set_axis_value(Move::Left, 0.5);
+        assert_eq!(action_state.net_movement(&DPAD), Vec2::new(-0.5, 0.0));
+
+        // Updating from inputs refreshes the cache
+        action_state.update(vec![ActionData::default(); 4]);
+        assert_eq!(action_state.net_movement(&DPAD), Vec2::ZERO);
+        assert_eq!(action_state.net_direction(&DPAD), None);
+
+        // As does restoring a snapshot
+        action_state.restore(snapshot);
+        assert_eq!(action_state.net_movement(&DPAD), Vec2::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn restore_keeps_held_actions() {
+        use crate::action_state::ActionState;
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.hold(Action::Run);
+
+        // Actions stay held by code, even if they were not held when the snapshot was taken
+        let released = action_state.snapshot();
+        action_state.hold(Action::Jump);
+        action_state.restore(released);
+        assert!(action_state.action_data(Action::Jump).held);
+        assert!(action_state.pressed(Action::Jump));
+        assert!(!action_state.just_pressed(Action::Jump));
+
+        // And stop being held once code has stopped holding them
+        let held = action_state.snapshot();
+        action_state.stop_holding(Action::Run);
+        action_state.restore(held);
+        assert!(!action_state.action_data(Action::Run).held);
+    }
+
+    #[test]
+    #[cfg(feature = "action_source")]
+    fn action_sources() {
+        use crate::action_state::{ActionSource, ActionState};
+        use crate::clashing_inputs::ClashStrategy;
+        use crate::input_map::InputMap;
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
+        use bevy_utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        let input_map = InputMap::new([(Action::Run, KeyCode::R)]);
+        let mut keyboard_input_stream = Input::<KeyCode>::default();
+        keyboard_input_stream.press(KeyCode::R);
+        let input_streams = InputStreams::from_keyboard(&keyboard_input_stream);
+
+        // Nothing has happened yet
+        assert_eq!(action_state.source(Action::Run), ActionSource::Carried);
+
+        // Updating from inputs marks every action as fresh
+        action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        assert_eq!(action_state.source(Action::Run), ActionSource::Input);
+        assert_eq!(action_state.source(Action::Jump), ActionSource::Input);
+
+        // Manual changes override that
+        action_state.press(Action::Jump);
+        assert_eq!(action_state.source(Action::Jump), ActionSource::Manual);
+
+        // Ticking marks all actions as stale
+        action_state.tick(Instant::now());
+        assert_eq!(action_state.source(Action::Run), ActionSource::Carried);
+        assert_eq!(action_state.source(Action::Jump), ActionSource::Carried);
+        assert!(action_state.pressed(Action::Jump));
+
+        // Sources are not serialized, so the format does not depend on the feature
+        let serialized = ron::to_string(&action_state.action_data(Action::Jump)).unwrap();
+        assert!(!serialized.contains("source"));
+    }
+
+    #[test]
+    fn double_presses_while_mashing() {
+        use crate::action_state::{ActionState, RECENT_PRESS_CAPACITY};
+        use bevy_utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let window = Duration::from_millis(300);
+        let start = Instant::now();
+
+        let tap = |action_state: &mut ActionState<Action>, millis: u64| {
+            action_state.tick(start + Duration::from_millis(millis));
+            action_state.press(Action::Jump);
+            let double_pressed = action_state.double_pressed(Action::Jump, window);
+            action_state.tick(start + Duration::from_millis(millis + 50));
+            action_state.release(Action::Jump);
+            double_pressed
+        };
+
+        // A lone press is followed by a separate double press
+        assert!(!tap(&mut action_state, 0));
+        assert!(!tap(&mut action_state, 1000));
+        assert!(tap(&mut action_state, 1200));
+
+        // Mashing fires on every second press, long after the recent presses have started being forgotten
+        let n_presses = 4 * RECENT_PRESS_CAPACITY as u64;
+        let double_presses: Vec<bool> = (0..n_presses)
+            .map(|i| tap(&mut action_state, 5000 + i * 100))
+            .collect();
+        let expected: Vec<bool> = (0..n_presses).map(|i| i % 2 == 1).collect();
+        assert_eq!(double_presses, expected);
+
+        // Wider windows can pair presses differently
+        assert!(!tap(&mut action_state, 20_000));
+        action_state.tick(start + Duration::from_millis(20_600));
+        action_state.press(Action::Jump);
+        assert!(!action_state.double_pressed(Action::Jump, window));
+        assert!(action_state.double_pressed(Action::Jump, Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn repeated_presses_keep_timing() {
+        use crate::action_state::ActionState;
+        use bevy_utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let t0 = Instant::now();
+        let t1 = t0 + Duration::from_millis(100);
+
+        action_state.press(Action::Run);
+        action_state.tick(t0);
+
+        // Pressing an action that is already pressed should not restart its timer
+        action_state.press(Action::Run);
+        action_state.tick(t1);
+        assert_eq!(action_state.instant_started(Action::Run), Some(t0));
+        assert_eq!(action_state.current_duration(Action::Run), t1 - t0);
+
+        // The same is true for releasing
+        action_state.release(Action::Jump);
+        assert_eq!(action_state.instant_started(Action::Jump), Some(t0));
+    }
+
+    #[test]
+    fn chord_breaking() {
+        use crate::action_state::ActionState;
+        use crate::clashing_inputs::ClashStrategy;
+        use crate::input_map::InputMap;
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
+        use bevy_utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        let mut input_map = InputMap::default();
+        input_map.insert_chord(Action::Hide, [KeyCode::Key1, KeyCode::Key2]);
+
+        let mut keyboard_input_stream = Input::<KeyCode>::default();
+        let update = |keyboard: &Input<KeyCode>, action_state: &mut ActionState<Action>| {
+            action_state.tick(Instant::now());
+            let input_streams = InputStreams::from_keyboard(keyboard);
+            action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        };
+
+        // Partially pressing a chord does not break it
+        keyboard_input_stream.press(KeyCode::Key1);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(!action_state.pressed(Action::Hide));
+        assert!(!action_state.chord_broken(Action::Hide));
+
+        // Completing the chord
+        keyboard_input_stream.press(KeyCode::Key2);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(action_state.pressed(Action::Hide));
+        assert!(!action_state.chord_broken(Action::Hide));
+
+        // Releasing one key breaks the chord
+        keyboard_input_stream.release(KeyCode::Key1);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(action_state.just_released(Action::Hide));
+        assert!(action_state.chord_broken(Action::Hide));
+
+        // But only for a single tick
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(!action_state.chord_broken(Action::Hide));
+
+        // Releasing the rest of the chord afterwards does not break it again
+        keyboard_input_stream.release(KeyCode::Key2);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(!action_state.chord_broken(Action::Hide));
+
+        // Releasing every key at once is a normal release
+        keyboard_input_stream.press(KeyCode::Key1);
+        keyboard_input_stream.press(KeyCode::Key2);
+        update(&keyboard_input_stream, &mut action_state);
+        keyboard_input_stream.release(KeyCode::Key1);
+        keyboard_input_stream.release(KeyCode::Key2);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(action_state.just_released(Action::Hide));
+        assert!(!action_state.chord_broken(Action::Hide));
+    }
+
+    #[test]
+    fn diff_against_analog_values() {
+        use crate::action_state::{ActionDiff, ActionState};
+        use crate::axislike::DualAxisData;
+        use bevy::math::Vec2;
+
+        let mut previous = ActionState::<Action>::default();
+        previous.press(Action::Run);
+        previous.set_axis_value(Action::Run, 0.5);
+
+        // Changes smaller than the epsilon are ignored
+        let mut current = previous.clone();
+        current.set_axis_value(Action::Run, 0.505);
+        assert!(current.diff_against(&previous).is_empty());
+
+        current.set_axis_value(Action::Run, 0.8);
+        current.press(Action::Jump);
+        current.set_axis_pair(Action::Jump, DualAxisData::new(Vec2::new(0.0, 1.0)));
+        assert_eq!(
+            current.diff_against(&previous),
+            vec![
+                ActionDiff::Pressed {
+                    action: Action::Jump,
+                    id: ()
+                },
+                ActionDiff::ValueChanged {
+                    action: Action::Run,
+                    id: (),
+                    value: 0.8
+                },
+                ActionDiff::AxisPairChanged {
+                    action: Action::Jump,
+                    id: (),
+                    axis_pair: Vec2::new(0.0, 1.0)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_serialized_inputs() {
+        use crate::action_state::ActionState;
+        use crate::errors::SerializedInputsLength;
+        use bevy_utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(Action::Run);
+        action_state.tick(Instant::now());
+
+        // Changes between frames produce the usual transitions
+        action_state.apply_serialized_inputs(&[0b010]).unwrap();
+        assert!(action_state.just_released(Action::Run));
+        assert!(action_state.just_pressed(Action::Jump));
+        assert!(action_state.released(Action::Hide));
+
+        assert_eq!(
+            action_state.apply_serialized_inputs(&[0, 0]),
+            Err(SerializedInputsLength {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert!(action_state.pressed(Action::Jump));
+    }
+
+    #[test]
+    fn chord_completion() {
+        use crate::action_state::ActionState;
+        use crate::clashing_inputs::ClashStrategy;
+        use crate::input_map::InputMap;
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
+        use bevy_utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        let mut input_map = InputMap::default();
+        input_map.insert_chord(Action::Hide, [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3]);
+        input_map.insert(Action::Hide, KeyCode::H);
+
+        let mut keyboard_input_stream = Input::<KeyCode>::default();
+        let update = |keyboard: &Input<KeyCode>, action_state: &mut ActionState<Action>| {
+            action_state.tick(Instant::now());
+            let input_streams = InputStreams::from_keyboard(keyboard);
+            action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        };
+
+        // Pressing the chord one key per tick only presses the action once it is complete
+        keyboard_input_stream.press(KeyCode::Key1);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(!action_state.pressed(Action::Hide));
+        assert!(!action_state.chord_just_completed(Action::Hide));
+
+        keyboard_input_stream.press(KeyCode::Key2);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(!action_state.pressed(Action::Hide));
+        assert!(!action_state.chord_just_completed(Action::Hide));
+
+        keyboard_input_stream.press(KeyCode::Key3);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(action_state.just_pressed(Action::Hide));
+        assert!(action_state.chord_just_completed(Action::Hide));
+
+        // Holding the chord does not complete it again
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(action_state.pressed(Action::Hide));
+        assert!(!action_state.just_pressed(Action::Hide));
+        assert!(!action_state.chord_just_completed(Action::Hide));
+
+        // Re-pressing the final key completes it again
+        keyboard_input_stream.release(KeyCode::Key3);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(action_state.released(Action::Hide));
+        keyboard_input_stream.press(KeyCode::Key3);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(action_state.chord_just_completed(Action::Hide));
+
+        // Presses from bindings that are not chords are not completions
+        keyboard_input_stream.release(KeyCode::Key1);
+        keyboard_input_stream.release(KeyCode::Key2);
+        keyboard_input_stream.release(KeyCode::Key3);
+        update(&keyboard_input_stream, &mut action_state);
+        keyboard_input_stream.press(KeyCode::H);
+        update(&keyboard_input_stream, &mut action_state);
+        assert!(action_state.just_pressed(Action::Hide));
+        assert!(!action_state.chord_just_completed(Action::Hide));
+    }
+
+    #[test]
+    fn reconcile_stuck_actions() {
+        use crate::action_state::ActionState;
+        use crate::clashing_inputs::ClashStrategy;
+        use crate::input_map::InputMap;
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
+        use bevy_utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        let mut input_map = InputMap::default();
+        input_map.insert(Action::Run, KeyCode::R);
+        input_map.insert(Action::Jump, KeyCode::Space);
+
+        let mut keyboard = Input::<KeyCode>::default();
+        keyboard.press(KeyCode::R);
+        keyboard.press(KeyCode::Space);
+        let input_streams = InputStreams::from_keyboard(&keyboard);
+        action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+
+        // Space was released, but the update that would notice it was skipped
+        keyboard.release(KeyCode::Space);
+        let input_streams = InputStreams::from_keyboard(&keyboard);
+        let action_data = input_map.which_pressed(&input_streams, ClashStrategy::PressAll);
+
+        // Presses made by code are intentional, even once they are carried over
+        action_state.press(Action::Hide);
+        action_state.tick(Instant::now());
+
+        // Presses made by inputs that are no longer held are orphaned
+        assert_eq!(action_state.reconcile(&action_data), vec![Action::Jump]);
+        assert!(action_state.pressed(Action::Run));
+        assert!(action_state.pressed(Action::Hide));
+        assert!(action_state.just_released(Action::Jump));
+        #[cfg(feature = "action_source")]
+        assert_eq!(
+            action_state.source(Action::Jump),
+            crate::action_state::ActionSource::Input
+        );
+    }
+
+    #[test]
+    fn exclusive_groups() {
+        use crate::action_state::ActionState;
+        use bevy_utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        let group = [Action::Run, Action::Jump, Action::Hide];
+
+        // Actions pressed on the same frame are resolved by group order
+        action_state.press(Action::Hide);
+        action_state.press(Action::Jump);
+        action_state.enforce_exclusive(&group);
+        assert_eq!(action_state.get_pressed(), vec![Action::Jump]);
+
+        // Held actions are not affected until another action is pressed
+        action_state.tick(Instant::now());
+        action_state.enforce_exclusive(&group);
+        assert_eq!(action_state.get_pressed(), vec![Action::Jump]);
+
+        // The most recent press wins
+        action_state.press(Action::Run);
+        action_state.enforce_exclusive(&group);
+        assert_eq!(action_state.get_pressed(), vec![Action::Run]);
+        assert!(action_state.action_data(Action::Jump).consumed);
+    }
+
+    #[test]
+    fn consume_across_updates() {
+        use crate::action_state::{ActionData, ActionState};
+        use crate::buttonlike::ButtonState;
+        use bevy_utils::Instant;
+
+        let held = |pressed: bool| {
+            let mut action_data = vec![ActionData::default(); Action::N_VARIANTS];
+            if pressed {
+                action_data[Action::Jump.index()].state = ButtonState::JustPressed;
+            }
+            action_data
+        };
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.update(held(true));
+        assert!(action_state.just_pressed(Action::Jump));
+
+        action_state.consume(Action::Jump);
+        assert!(!action_state.pressed(Action::Jump));
+        assert!(!action_state.just_pressed(Action::Jump));
+
+        // Continued inputs do not re-press the action on the next tick
+        action_state.tick(Instant::now());
+        action_state.update(held(true));
+        assert!(action_state.released(Action::Jump));
+        assert!(!action_state.just_pressed(Action::Jump));
+
+        // Once the inputs are released, the action responds to them again
+        action_state.tick(Instant::now());
+        action_state.update(held(false));
+        action_state.tick(Instant::now());
+        action_state.update(held(true));
+        assert!(action_state.just_pressed(Action::Jump));
+    }
+
+    #[test]
+    fn press_counts() {
+        use crate::action_state::ActionState;
+        use bevy_utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let t0 = Instant::now();
+
+        for i in 0..5 {
+            action_state.tick(t0 + Duration::from_secs(i));
+            action_state.press(Action::Jump);
+            // Holding the action does not count as another press
+            action_state.press(Action::Jump);
+            action_state.release(Action::Jump);
+        }
+
+        assert_eq!(action_state.press_count(Action::Jump), 5);
+        assert_eq!(action_state.release_count(Action::Jump), 5);
+        assert_eq!(action_state.press_count(Action::Run), 0);
+
+        // The most recent press occurred during the most recent tick
+        assert_eq!(action_state.presses_within(Action::Jump, Duration::ZERO), 1);
+        assert_eq!(
+            action_state.presses_within(Action::Jump, Duration::from_secs(2)),
+            3
+        );
+
+        // Earlier presses were counted during earlier ticks
+        assert_eq!(action_state.presses_this_tick(Action::Jump), 1);
+        action_state.press(Action::Jump);
+        assert_eq!(action_state.presses_this_tick(Action::Jump), 2);
+        action_state.tick(t0 + Duration::from_secs(5));
+        assert_eq!(action_state.presses_this_tick(Action::Jump), 0);
+        action_state.release(Action::Jump);
+
+        action_state.reset_press_counts(Action::Jump);
+        assert_eq!(action_state.press_count(Action::Jump), 0);
+        assert_eq!(action_state.release_count(Action::Jump), 0);
+        assert_eq!(
+            action_state.presses_within(Action::Jump, Duration::from_secs(10)),
+            0
+        );
+    }
+
+    #[test]
+    fn mashing() {
+        use crate::action_state::ActionState;
+        use bevy_utils::{Duration, Instant};
+
+        let mut action_state = ActionState::<Action>::default();
+        let t0 = Instant::now();
+        assert_eq!(action_state.mash_rate(Action::Jump), 0.0);
+
+        // Mash at 10 presses per second for two seconds
+        let mut reached = 0;
+        for i in 0..20 {
+            action_state.tick(t0 + Duration::from_millis(100 * i));
+            action_state.press(Action::Jump);
+            action_state.release(Action::Jump);
+
+            if action_state.just_reached_mash(Action::Jump, 8.0) {
+                reached += 1;
+            }
+        }
+
+        let rate = action_state.mash_rate(Action::Jump);
+        assert!((9.0..=11.0).contains(&rate), "{rate}");
+        assert_eq!(reached, 1);
+
+        // Once mashing stops, the rate decays smoothly
+        action_state.tick(t0 + Duration::from_millis(2000));
+        let decayed_rate = action_state.mash_rate(Action::Jump);
+        assert!(decayed_rate < rate && decayed_rate > rate / 2.0);
+
+        action_state.tick(t0 + Duration::from_millis(5000));
+        assert!(action_state.mash_rate(Action::Jump) < 0.1);
+    }
+
+    #[test]
+    fn input_interop() {
+        use crate::action_state::ActionState;
+        use bevy_utils::Instant;
+
+        let mut action_state = ActionState::<Action>::default();
+        action_state.press(Action::Run);
+        action_state.tick(Instant::now());
+        action_state.press(Action::Jump);
+
+        let mut input = action_state.to_input();
+        assert!(input.pressed(Action::Run));
+        assert!(!input.just_pressed(Action::Run));
+        assert!(input.just_pressed(Action::Jump));
+        assert_eq!(input.get_pressed().count(), 2);
+
+        action_state.tick(Instant::now());
+        action_state.release(Action::Run);
+        action_state.write_to_input(&mut input);
+        assert!(input.just_released(Action::Run));
+        assert!(!input.pressed(Action::Run));
+        assert!(!input.just_pressed(Action::Jump));
+        assert!(input.pressed(Action::Jump));
+        assert_eq!(input.get_just_released().count(), 1);
+
+        // Edges are cleared by the next tick, just like in `bevy_input`
+        action_state.tick(Instant::now());
+        action_state.write_to_input(&mut input);
+        assert_eq!(input.get_just_released().count(), 0);
+    }
+
+    #[test]
+    fn hold_levels_on_crossing() {
+        use crate::action_state::{ActionState, HoldLevels, HoldTrigger};
+        use bevy_utils::{Duration, Instant};
+
+        let hold_levels = HoldLevels::new(
+            [
+                Duration::from_millis(300),
+                Duration::ZERO,
+                Duration::from_millis(100),
+            ],
+            HoldTrigger::OnCrossing,
+        );
+
+        let mut action_state = ActionState::<Action>::default();
+        let t0 = Instant::now();
+        action_state.tick(t0);
+        action_state.press(Action::Hide);
+
+        // A zero threshold fires on the press itself
+        assert_eq!(action_state.hold_level(Action::Hide, &hold_levels), Some(0));
+
+        let mut fired = Vec::new();
+        for millis in (0..=400).step_by(50) {
+            action_state.tick(t0 + Duration::from_millis(millis));
+            if let Some(level) = action_state.hold_level(Action::Hide, &hold_levels) {
+                fired.push((millis, level));
+            }
+        }
+        assert_eq!(fired, vec![(100, 1), (300, 2)]);
+
+        // Releasing does not fire anything
+        action_state.release(Action::Hide);
+        assert_eq!(action_state.hold_level(Action::Hide, &hold_levels), None);
+        assert_eq!(
+            action_state.current_hold_level(Action::Hide, &hold_levels),
+            None
+        );
+    }
 }
@@ -2,6 +2,235 @@
 
 use crate::orientation::{Direction, Rotation};
 use bevy_math::Vec2;
+use bevy_utils::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+
+/// The analog value of an action, stored alongside its [`ButtonState`](crate::buttonlike::ButtonState)
+/// in each [`ActionData`](crate::action_state::ActionData)
+///
+/// Read this using [`ActionState::axis_value`](crate::action_state::ActionState::axis_value).
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AxisData {
+    /// The value of the action, ranging from -1 to 1, inclusive
+    ///
+    /// Buttons report `1.0` when pressed and `0.0` when released.
+    pub value: f32,
+    /// The total distance scrolled this frame by the [`UserInput::MouseWheel`](crate::user_input::UserInput::MouseWheel)
+    /// inputs that pressed this action, measured in lines
+    ///
+    /// Read this using [`ActionState::mouse_wheel_value`](crate::action_state::ActionState::mouse_wheel_value).
+    pub mouse_wheel: f32,
+    /// The total distance moved this frame by the mouse in the directions of the [`UserInput::MouseMotion`](crate::user_input::UserInput::MouseMotion)
+    /// inputs that pressed this action
+    ///
+    /// Read this using [`ActionState::mouse_motion_value`](crate::action_state::ActionState::mouse_motion_value).
+    pub mouse_motion: f32,
+    /// The values of the [`UserInput::DualAxis`](crate::user_input::UserInput::DualAxis) input that pressed this action, if any
+    ///
+    /// Read this using [`ActionState::axis_pair`](crate::action_state::ActionState::axis_pair).
+    pub axis_pair: Option<DualAxisData>,
+}
+
+impl AxisData {
+    /// Stores the `axis_pair`, unless one with a larger magnitude is already stored
+    pub(crate) fn merge_axis_pair(&mut self, axis_pair: DualAxisData) {
+        match self.axis_pair {
+            Some(previous) if previous.magnitude() >= axis_pair.magnitude() => (),
+            _ => self.axis_pair = Some(axis_pair),
+        }
+    }
+}
+
+/// The combined values of a pair of analog axes, such as a joystick
+///
+/// When constructed, the magnitude is capped at 1, but direction is preserved.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DualAxisData {
+    xy: Vec2,
+}
+
+impl DualAxisData {
+    /// Creates a new [`DualAxisData`] from the provided (x,y) values
+    #[must_use]
+    pub fn new(xy: Vec2) -> DualAxisData {
+        DualAxisData {
+            xy: xy.clamp_length_max(1.0),
+        }
+    }
+
+    /// The value along the x-axis, ranging from -1 to 1
+    #[must_use]
+    #[inline]
+    pub fn x(&self) -> f32 {
+        self.xy.x
+    }
+
+    /// The value along the y-axis, ranging from -1 to 1
+    #[must_use]
+    #[inline]
+    pub fn y(&self) -> f32 {
+        self.xy.y
+    }
+
+    /// The (x, y) values, with a magnitude of at most 1
+    #[must_use]
+    #[inline]
+    pub fn xy(&self) -> Vec2 {
+        self.xy
+    }
+
+    /// How far from the origin the axes are, ranging from 0 to 1
+    #[must_use]
+    #[inline]
+    pub fn magnitude(&self) -> f32 {
+        self.xy.length()
+    }
+
+    /// The [`Direction`] that the axes are pointing towards
+    ///
+    /// If the axes are neutral (x,y) = (0,0), this will be `None`
+    #[must_use]
+    #[inline]
+    pub fn direction(&self) -> Option<Direction> {
+        if self.xy == Vec2::ZERO {
+            None
+        } else {
+            Some(Direction::new(self.xy))
+        }
+    }
+}
+
+/// A dead zone for analog inputs, used to ignore small movements caused by stick drift
+///
+/// Values inside of the dead zone are treated as zero.
+/// The remaining range is rescaled to cover `[0, 1]`, so there is no sudden jump in value at the edge of the dead zone.
+/// Dead zones are applied to each action using [`InputMap::set_deadzone`](crate::input_map::InputMap::set_deadzone),
+/// on top of any configured in [`GamepadSettings`](bevy_input::gamepad::GamepadSettings).
+///
+/// # Example
+/// ```rust
+/// use bevy_math::Vec2;
+/// use leafwing_input_manager::axislike::DeadZone;
+///
+/// let deadzone = DeadZone::Radial { radius: 0.5 };
+/// assert_eq!(deadzone.apply(0.1), 0.0);
+/// assert_eq!(deadzone.apply(-0.75), -0.5);
+/// assert_eq!(deadzone.apply_pair(Vec2::new(0.3, 0.3)), Vec2::ZERO);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DeadZone {
+    /// Each axis is treated independently, ignoring values whose magnitude is at most `threshold`
+    ///
+    /// This produces a square dead zone for a pair of axes, which makes it easier to move in a straight line.
+    Axial {
+        /// The magnitude at or below which each axis is considered to be at rest
+        threshold: f32,
+    },
+    /// Pairs of axes are treated together, ignoring inputs whose combined magnitude is at most `radius`
+    ///
+    /// This produces a circular dead zone, which preserves the direction of the input.
+    /// For a single axis, this behaves identically to [`DeadZone::Axial`].
+    Radial {
+        /// The magnitude at or below which the axes are considered to be at rest
+        radius: f32,
+    },
+}
+
+impl Default for DeadZone {
+    fn default() -> Self {
+        DeadZone::Axial { threshold: 0.0 }
+    }
+}
+
+impl DeadZone {
+    /// Applies the dead zone to the `value` of a single axis
+    #[must_use]
+    pub fn apply(self, value: f32) -> f32 {
+        let threshold = match self {
+            DeadZone::Axial { threshold } => threshold,
+            DeadZone::Radial { radius } => radius,
+        };
+
+        value.signum() * Self::rescale(value.abs(), threshold)
+    }
+
+    /// Applies the dead zone to the values of a pair of axes, such as a joystick
+    #[must_use]
+    pub fn apply_pair(self, xy: Vec2) -> Vec2 {
+        match self {
+            DeadZone::Axial { .. } => Vec2::new(self.apply(xy.x), self.apply(xy.y)),
+            DeadZone::Radial { radius } => {
+                let magnitude = xy.length();
+                if magnitude == 0.0 {
+                    Vec2::ZERO
+                } else {
+                    xy / magnitude * Self::rescale(magnitude, radius)
+                }
+            }
+        }
+    }
+
+    /// Rescales a non-negative `magnitude` from `[threshold, 1]` to `[0, 1]`
+    fn rescale(magnitude: f32, threshold: f32) -> f32 {
+        if magnitude <= threshold {
+            0.0
+        } else if threshold <= 0.0 {
+            magnitude
+        } else {
+            ((magnitude - threshold) / (1.0 - threshold)).min(1.0)
+        }
+    }
+}
+
+/// The magnitudes at which the analog value of an action presses and releases it
+///
+/// By default, analog inputs press their action whenever they leave the [`DeadZone`].
+/// Thresholds are applied to each action using [`InputMap::set_axis_thresholds`](crate::input_map::InputMap::set_axis_thresholds),
+/// which is useful when treating a trigger as a button.
+/// They apply to the value of the action as a whole, rather than to each of its bindings separately.
+/// Setting `release` below `press` adds hysteresis, so that inputs held near the press point do not rapidly press and release the action.
+///
+/// # Example
+/// ```rust
+/// use leafwing_input_manager::axislike::AxisThresholds;
+///
+/// let thresholds = AxisThresholds::new(0.5, 0.3);
+/// assert!(!thresholds.pressed(0.4, false));
+/// assert!(thresholds.pressed(0.5, false));
+/// // Once pressed, the action stays pressed until the value falls to the release threshold
+/// assert!(thresholds.pressed(0.4, true));
+/// assert!(!thresholds.pressed(0.3, true));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AxisThresholds {
+    /// The magnitude at or above which a released action is pressed
+    pub press: f32,
+    /// The magnitude at or below which a pressed action is released
+    pub release: f32,
+}
+
+impl AxisThresholds {
+    /// Creates a new [`AxisThresholds`]
+    ///
+    /// The `release` threshold is capped at the `press` threshold.
+    #[must_use]
+    pub fn new(press: f32, release: f32) -> Self {
+        AxisThresholds {
+            press,
+            release: release.min(press),
+        }
+    }
+
+    /// Should an action with a value of this `magnitude` be pressed, given whether it `was_pressed` before?
+    #[must_use]
+    pub fn pressed(&self, magnitude: f32, was_pressed: bool) -> bool {
+        if was_pressed {
+            magnitude > self.release
+        } else {
+            magnitude >= self.press
+        }
+    }
+}
 
 /// A high-level abstract user input that varies from -1 to 1, inclusive, along two axes
 ///
@@ -96,3 +325,190 @@ impl AxisPair {
         self.xy.length_squared()
     }
 }
+
+/// The completion of a stick flick, as detected by [`FlickStick`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StickFlick {
+    /// The [`Rotation`] (measured clockwise from midnight) that the stick was flicked towards
+    pub rotation: Rotation,
+    /// The [`Direction`] that the stick was flicked towards
+    pub direction: Direction,
+}
+
+/// Detects quick flicks of an analog stick, as used by "flick stick" aiming schemes
+///
+/// A flick occurs when the stick moves from near the center (within `center_radius`)
+/// to near the edge (beyond `edge_threshold`) in at most `max_flick_duration`.
+/// Slower motions are treated as normal aiming, and do not produce a flick.
+///
+/// Feed the processed stick position to [`FlickStick::update`] each frame.
+/// While the stick is held at the edge after a flick, [`FlickStick::flick_rotation`] tracks the stick,
+/// allowing the rotation target to be adjusted until the stick returns to the center.
+///
+/// # Example
+/// ```rust
+/// use bevy_math::Vec2;
+/// use bevy_utils::{Duration, Instant};
+/// use leafwing_input_manager::axislike::{AxisPair, FlickStick};
+///
+/// let mut flick_stick = FlickStick::default();
+/// let start = Instant::now();
+///
+/// flick_stick.update(&AxisPair::new(Vec2::ZERO), start);
+/// let flick = flick_stick.update(&AxisPair::new(Vec2::X), start + Duration::from_millis(30));
+///
+/// assert_eq!(flick.unwrap().direction.unit_vector(), Vec2::X);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlickStick {
+    /// The magnitude below which the stick is considered to be centered
+    pub center_radius: f32,
+    /// The magnitude above which the stick is considered to be at the edge
+    pub edge_threshold: f32,
+    /// The longest time that the stick can take to travel from the center to the edge and still count as a flick
+    pub max_flick_duration: Duration,
+    state: FlickState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FlickState {
+    /// The stick is near the center
+    Centered,
+    /// The stick has left the center, and may be flicking
+    Leaving { since: Instant },
+    /// The stick was flicked, and is being held at the edge
+    Flicked { rotation: Rotation },
+    /// The stick is being moved normally, and cannot flick until it returns to the center
+    Aiming,
+}
+
+impl Default for FlickStick {
+    fn default() -> Self {
+        FlickStick {
+            center_radius: 0.25,
+            edge_threshold: 0.9,
+            max_flick_duration: Duration::from_millis(100),
+            state: FlickState::Aiming,
+        }
+    }
+}
+
+impl FlickStick {
+    /// Updates the detector with the current position of the stick, returning a [`StickFlick`] if one was just completed
+    ///
+    /// The detector starts out treating the stick as aiming,
+    /// so it must be centered before the first flick can be detected.
+    pub fn update(&mut self, axis_pair: &AxisPair, current_time: Instant) -> Option<StickFlick> {
+        let magnitude = axis_pair.magnitude();
+
+        if magnitude < self.center_radius {
+            self.state = FlickState::Centered;
+            return None;
+        }
+
+        let at_edge = magnitude >= self.edge_threshold;
+
+        match self.state {
+            FlickState::Centered => {
+                if at_edge {
+                    // Reaching the edge within a single update is always fast enough
+                    self.complete_flick(axis_pair)
+                } else {
+                    self.state = FlickState::Leaving {
+                        since: current_time,
+                    };
+                    None
+                }
+            }
+            FlickState::Leaving { since } => {
+                if current_time - since > self.max_flick_duration {
+                    self.state = FlickState::Aiming;
+                    None
+                } else if at_edge {
+                    self.complete_flick(axis_pair)
+                } else {
+                    None
+                }
+            }
+            FlickState::Flicked { .. } => {
+                if let Some(rotation) = axis_pair.rotation() {
+                    self.state = FlickState::Flicked { rotation };
+                }
+                None
+            }
+            FlickState::Aiming => None,
+        }
+    }
+
+    fn complete_flick(&mut self, axis_pair: &AxisPair) -> Option<StickFlick> {
+        let rotation = axis_pair.rotation()?;
+        self.state = FlickState::Flicked { rotation };
+
+        Some(StickFlick {
+            rotation,
+            direction: axis_pair.direction(),
+        })
+    }
+
+    /// The current flick target, if the stick was flicked and has not yet returned to the center
+    #[must_use]
+    pub fn flick_rotation(&self) -> Option<Rotation> {
+        match self.state {
+            FlickState::Flicked { rotation } => Some(rotation),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AxisPair, FlickStick};
+    use crate::orientation::Rotation;
+    use bevy_math::Vec2;
+    use bevy_utils::{Duration, Instant};
+
+    #[test]
+    fn flicks_are_fast() {
+        let mut flick_stick = FlickStick::default();
+        let t0 = Instant::now();
+        let ms = Duration::from_millis;
+        // Conversions from vectors may be off by a tenth of a degree
+        let approx_eq = |a: Rotation, b: Rotation| {
+            (i32::from(a.deci_degrees()) - i32::from(b.deci_degrees())).abs() <= 1
+        };
+
+        // The stick must start centered
+        assert_eq!(flick_stick.update(&AxisPair::new(Vec2::Y), t0), None);
+        flick_stick.update(&AxisPair::new(Vec2::ZERO), t0);
+
+        // A quick flick, completed over several updates
+        flick_stick.update(&AxisPair::new(Vec2::new(0.0, -0.5)), t0 + ms(10));
+        let flick = flick_stick.update(&AxisPair::new(Vec2::new(0.0, -1.0)), t0 + ms(50));
+        assert!(approx_eq(flick.unwrap().rotation, Rotation::SOUTH));
+        assert!(approx_eq(
+            flick_stick.flick_rotation().unwrap(),
+            Rotation::SOUTH
+        ));
+
+        // Holding the stick at the edge adjusts the flick target, without flicking again
+        assert_eq!(
+            flick_stick.update(&AxisPair::new(Vec2::X), t0 + ms(60)),
+            None
+        );
+        assert!(approx_eq(
+            flick_stick.flick_rotation().unwrap(),
+            Rotation::EAST
+        ));
+
+        // Slowly pushing the stick to the edge is normal aiming
+        flick_stick.update(&AxisPair::new(Vec2::ZERO), t0 + ms(100));
+        assert_eq!(flick_stick.flick_rotation(), None);
+        flick_stick.update(&AxisPair::new(Vec2::new(0.5, 0.0)), t0 + ms(110));
+        flick_stick.update(&AxisPair::new(Vec2::new(0.7, 0.0)), t0 + ms(200));
+        assert_eq!(
+            flick_stick.update(&AxisPair::new(Vec2::X), t0 + ms(300)),
+            None
+        );
+        assert_eq!(flick_stick.flick_rotation(), None);
+    }
+}
@@ -1,14 +1,16 @@
 //! Handles clashing inputs into a [`InputMap`](crate::input_map::InputMap) in a configurable fashion.
 
-use crate::action_state::ActionData;
+use crate::action_state::{ActionData, ActionState};
 use crate::input_map::InputMap;
 use crate::user_input::{InputButton, InputStreams, UserInput};
 use crate::Actionlike;
 
+use bevy_ecs::component::Component;
 use itertools::Itertools;
 use petitset::PetitSet;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
 
 /// How should clashing inputs by handled by an [`InputMap`]?
@@ -24,6 +26,7 @@ use std::marker::PhantomData;
 ///
 /// This strategy is only used when assessing the actions and input holistically,
 /// in [`InputMap::which_pressed`], using [`InputMap::handle_clashes`].
+/// For per-action priorities, use [`ActionPriority`]; for other behavior, implement a [`ClashResolver`] instead.
 #[non_exhaustive]
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum ClashStrategy {
@@ -35,7 +38,8 @@ pub enum ClashStrategy {
     PrioritizeLongest,
     /// Use the order in which actions are defined in the enum to resolve clashing inputs
     ///
-    /// Uses the iteration order returned by [`Actionlike::variants()`],
+    /// Uses the priority returned by [`Actionlike::ordering()`], where lower values win.
+    /// By default, this matches the iteration order returned by [`Actionlike::variants()`],
     /// which is generated in order of the enum items by the `#[derive(Actionlike)]` macro.
     UseActionOrder,
 }
@@ -46,6 +50,229 @@ impl Default for ClashStrategy {
     }
 }
 
+impl ClashStrategy {
+    /// Iterates over every available [`ClashStrategy`]
+    pub fn variants() -> impl Iterator<Item = ClashStrategy> {
+        [
+            ClashStrategy::PressAll,
+            ClashStrategy::PrioritizeLongest,
+            ClashStrategy::UseActionOrder,
+        ]
+        .into_iter()
+    }
+}
+
+/// Custom logic for resolving clashing inputs, used in place of a [`ClashStrategy`]
+///
+/// Insert a [`CustomClashResolver`] resource to use it with the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin),
+/// or pass it to [`InputMap::which_pressed_with`] directly.
+/// Every [`ClashStrategy`] is a resolver, as is any `Fn(&[(A, usize)]) -> Option<A>` closure.
+///
+/// # Example
+/// ```rust
+/// use bevy_input::{keyboard::KeyCode, Input};
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::InputStreams;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Copy,
+///     Stop,
+/// }
+///
+/// impl Action {
+///     fn priority(&self) -> u8 {
+///         match self {
+///             Action::Copy => 0,
+///             Action::Stop => 1,
+///         }
+///     }
+/// }
+///
+/// let prefer_priority = |candidates: &[(Action, usize)]| {
+///     candidates
+///         .iter()
+///         .map(|(action, _)| *action)
+///         .max_by_key(Action::priority)
+/// };
+///
+/// let mut input_map = InputMap::default();
+/// input_map
+///     .insert_chord(Action::Copy, [KeyCode::LControl, KeyCode::C])
+///     .insert(Action::Stop, KeyCode::C);
+///
+/// let mut keyboard = Input::<KeyCode>::default();
+/// keyboard.press(KeyCode::LControl);
+/// keyboard.press(KeyCode::C);
+///
+/// let action_data = input_map.which_pressed_with(&InputStreams::from_keyboard(&keyboard), &prefer_priority);
+/// assert!(action_data[Action::Copy.index()].state.released());
+/// assert!(action_data[Action::Stop.index()].state.pressed());
+/// ```
+pub trait ClashResolver<A: Actionlike>: Send + Sync + 'static {
+    /// Chooses which of the clashing `candidates` remains pressed, releasing the others
+    ///
+    /// Each candidate action is paired with the length of its longest pressed input that is involved in the clash.
+    /// Clashes are resolved pairwise, so there are always two candidates.
+    /// Returning [`None`] keeps every candidate pressed.
+    fn resolve(&self, candidates: &[(A, usize)]) -> Option<A>;
+}
+
+impl<A: Actionlike> ClashResolver<A> for ClashStrategy {
+    fn resolve(&self, candidates: &[(A, usize)]) -> Option<A> {
+        match candidates {
+            [(action_a, longest_a), (action_b, longest_b)] => {
+                let culled: Option<A> = resolve_by_strategy(
+                    (action_a.index(), *longest_a),
+                    (action_b.index(), *longest_b),
+                    *self,
+                );
+
+                match culled {
+                    Some(culled) if culled.index() == action_a.index() => Some(action_b.clone()),
+                    Some(_) => Some(action_a.clone()),
+                    None => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<A: Actionlike, F> ClashResolver<A> for F
+where
+    F: Fn(&[(A, usize)]) -> Option<A> + Send + Sync + 'static,
+{
+    fn resolve(&self, candidates: &[(A, usize)]) -> Option<A> {
+        self(candidates)
+    }
+}
+
+/// A resource that resolves clashing inputs for actions of type `A` using a [`ClashResolver`], overriding the [`ClashStrategy`] resource
+///
+/// Used by [`update_action_state`](crate::systems::update_action_state) and the other systems that resolve inputs.
+pub struct CustomClashResolver<A: Actionlike> {
+    resolver: Box<dyn ClashResolver<A>>,
+}
+
+impl<A: Actionlike> CustomClashResolver<A> {
+    /// Creates a new [`CustomClashResolver`] from the provided `resolver`
+    #[must_use]
+    pub fn new(resolver: impl ClashResolver<A>) -> Self {
+        Self {
+            resolver: Box::new(resolver),
+        }
+    }
+
+    /// The [`ClashResolver`] stored in this resource
+    #[must_use]
+    pub fn resolver(&self) -> &dyn ClashResolver<A> {
+        &*self.resolver
+    }
+}
+
+/// A [`ClashResolver`] that uses explicit per-action priorities, falling back to [`ClashStrategy::PrioritizeLongest`]
+///
+/// When two actions clash, the action with the higher priority wins, even if its input is shorter.
+/// Actions have a priority of 0 unless set otherwise, and clashes between actions with equal priorities
+/// are resolved by pressing the action with the longer input.
+///
+/// Add this as a component to an entity with an [`InputMap`] to use it for that entity alone,
+/// which keeps the priorities of each player separate in split-screen games.
+/// To use it for every [`InputMap`] of this action type, wrap it in a [`CustomClashResolver`] resource instead.
+///
+/// Clashes are resolved using the first of these that applies:
+/// 1. the [`ClashStrategy`] set on the [`InputMap`] itself, using [`InputMap::set_clash_strategy`]
+/// 2. the [`ActionPriority`] component of the entity
+/// 3. the [`CustomClashResolver`] resource for this action type
+/// 4. the global [`ClashStrategy`] resource
+///
+/// [`Actionlike::ordering`] is only used by [`ClashStrategy::UseActionOrder`], and so never breaks ties between priorities.
+///
+/// # Example
+/// ```rust
+/// use bevy_input::{keyboard::KeyCode, Input};
+/// use leafwing_input_manager::clashing_inputs::ActionPriority;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::user_input::InputStreams;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Copy,
+///     Stop,
+/// }
+///
+/// let mut input_map = InputMap::default();
+/// input_map
+///     .insert_chord(Action::Copy, [KeyCode::LControl, KeyCode::C])
+///     .insert(Action::Stop, KeyCode::C);
+///
+/// let mut keyboard = Input::<KeyCode>::default();
+/// keyboard.press(KeyCode::LControl);
+/// keyboard.press(KeyCode::C);
+/// let input_streams = InputStreams::from_keyboard(&keyboard);
+///
+/// // With equal priorities, the longer chord wins
+/// let mut action_priority = ActionPriority::default();
+/// let action_data = input_map.which_pressed_with(&input_streams, &action_priority);
+/// assert!(action_data[Action::Copy.index()].state.pressed());
+/// assert!(action_data[Action::Stop.index()].state.released());
+///
+/// action_priority.set(Action::Stop, 1);
+/// let action_data = input_map.which_pressed_with(&input_streams, &action_priority);
+/// assert!(action_data[Action::Copy.index()].state.released());
+/// assert!(action_data[Action::Stop.index()].state.pressed());
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct ActionPriority<A: Actionlike> {
+    priorities: Vec<i32>,
+    _phantom: PhantomData<A>,
+}
+
+// Implement manually to not require [`Default`] for `A`
+impl<A: Actionlike> Default for ActionPriority<A> {
+    fn default() -> Self {
+        Self {
+            priorities: vec![0; A::N_VARIANTS],
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> ActionPriority<A> {
+    /// Sets the `priority` of the `action`, where higher values win clashes
+    pub fn set(&mut self, action: A, priority: i32) -> &mut Self {
+        self.priorities[action.index()] = priority;
+        self
+    }
+
+    /// The priority of the `action`
+    #[must_use]
+    pub fn get(&self, action: A) -> i32 {
+        self.priorities[action.index()]
+    }
+}
+
+impl<A: Actionlike> ClashResolver<A> for ActionPriority<A> {
+    fn resolve(&self, candidates: &[(A, usize)]) -> Option<A> {
+        match candidates {
+            [(action_a, longest_a), (action_b, longest_b)] => {
+                let ordering = self
+                    .get(action_a.clone())
+                    .cmp(&self.get(action_b.clone()))
+                    .then_with(|| longest_a.cmp(longest_b));
+
+                match ordering {
+                    Ordering::Greater => Some(action_a.clone()),
+                    Ordering::Less => Some(action_b.clone()),
+                    Ordering::Equal => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
 impl UserInput {
     /// Does `self` clash with `other`?
     #[must_use]
@@ -54,13 +281,29 @@ impl UserInput {
 
         match self {
             Single(self_button) => match other {
-                Single(_) => false,
+                Single(_)
+                | SingleAxis(_)
+                | HalfAxis { .. }
+                | MouseMotion { .. }
+                | MouseWheel { .. }
+                | DualAxis { .. } => false,
                 Chord(other_set) => button_chord_clash(self_button, other_set),
             },
             Chord(self_set) => match other {
                 Single(other_button) => button_chord_clash(other_button, self_set),
                 Chord(other_set) => chord_chord_clash(self_set, other_set),
+                SingleAxis(_)
+                | HalfAxis { .. }
+                | MouseMotion { .. }
+                | MouseWheel { .. }
+                | DualAxis { .. } => false,
             },
+            // Axes, mouse motion and mouse wheels do not contain any buttons, and so can never clash
+            SingleAxis(_)
+            | HalfAxis { .. }
+            | MouseMotion { .. }
+            | MouseWheel { .. }
+            | DualAxis { .. } => false,
         }
     }
 }
@@ -74,13 +317,190 @@ impl<A: Actionlike> InputMap<A> {
         action_data: &mut [ActionData],
         input_streams: &InputStreams,
         clash_strategy: ClashStrategy,
+    ) {
+        self.handle_clashes_with(action_data, input_streams, &clash_strategy);
+    }
+
+    /// Resolve clashing inputs using a custom [`ClashResolver`], removing action presses that have been overruled
+    ///
+    /// The `usize` stored in `pressed_actions` corresponds to `Actionlike::index`
+    pub fn handle_clashes_with(
+        &self,
+        action_data: &mut [ActionData],
+        input_streams: &InputStreams,
+        clash_resolver: &dyn ClashResolver<A>,
     ) {
         for clash in self.get_clashes(action_data, input_streams) {
             // Remove the action in the pair that was overruled, if any
-            if let Some(culled_action) = resolve_clash(&clash, clash_strategy, input_streams) {
+            if let Some(culled_action) = resolve_clash(&clash, clash_resolver, input_streams) {
+                action_data[culled_action.index()] = ActionData::default();
+            }
+        }
+    }
+
+    /// Reports every pair of bindings that could ever clash, and how each [`ClashStrategy`] would resolve them
+    ///
+    /// This does not depend on any inputs, and is intended as a design-time tool for auditing an [`InputMap`]
+    /// and choosing an appropriate [`ClashStrategy`].
+    /// Only enabled bindings are considered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_input::keyboard::KeyCode;
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::clashing_inputs::ClashOutcome;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Copy,
+    ///     Stop,
+    ///     Jump,
+    /// }
+    ///
+    /// let mut input_map = InputMap::default();
+    /// input_map
+    ///     .insert_chord(Action::Copy, [KeyCode::LControl, KeyCode::C])
+    ///     .insert(Action::Stop, KeyCode::C)
+    ///     .insert(Action::Jump, KeyCode::Space);
+    ///
+    /// let clash_report = input_map.clash_report();
+    /// assert_eq!(clash_report.clashes.len(), 1);
+    ///
+    /// let clash = &clash_report.clashes[0];
+    /// assert_eq!(clash.outcome(ClashStrategy::PressAll), ClashOutcome::PressBoth);
+    /// assert_eq!(
+    ///     clash.outcome(ClashStrategy::PrioritizeLongest),
+    ///     ClashOutcome::Press(Action::Copy)
+    /// );
+    /// assert!(clash.strategy_dependent());
+    /// ```
+    #[must_use]
+    pub fn clash_report(&self) -> ClashAnalysis<A> {
+        let mut clashes = Vec::default();
+
+        for clash in self.possible_clashes() {
+            let action_a = A::get_at(clash.index_a).unwrap();
+            let action_b = A::get_at(clash.index_b).unwrap();
+
+            for (input_a, input_b) in clash.inputs_a.iter().zip(clash.inputs_b.iter()) {
+                clashes.push(PotentialClash {
+                    action_a: action_a.clone(),
+                    action_b: action_b.clone(),
+                    input_a: input_a.clone(),
+                    input_b: input_b.clone(),
+                });
+            }
+        }
+
+        ClashAnalysis { clashes }
+    }
+
+    /// Returns the actions that are currently pressed, along with every action that was suppressed by a clash and why
+    ///
+    /// The resulting [`ClashReport::action_data`] is identical to that of [`InputMap::which_pressed`].
+    /// This is a diagnostic tool for understanding surprising clash resolutions, and does extra work to record them:
+    /// prefer [`InputMap::which_pressed`] outside of debugging.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_input::{keyboard::KeyCode, Input};
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::user_input::InputStreams;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Copy,
+    ///     Stop,
+    /// }
+    ///
+    /// let mut input_map = InputMap::default();
+    /// input_map
+    ///     .insert_chord(Action::Copy, [KeyCode::LControl, KeyCode::C])
+    ///     .insert(Action::Stop, KeyCode::C);
+    ///
+    /// let mut keyboard = Input::<KeyCode>::default();
+    /// keyboard.press(KeyCode::LControl);
+    /// keyboard.press(KeyCode::C);
+    ///
+    /// let report = input_map.which_pressed_verbose(
+    ///     &InputStreams::from_keyboard(&keyboard),
+    ///     ClashStrategy::PrioritizeLongest,
+    /// );
+    ///
+    /// assert_eq!(report.suppressed.len(), 1);
+    /// assert_eq!(report.suppressed[0].action, Action::Stop);
+    /// assert_eq!(report.suppressed[0].overruled_by, Action::Copy);
+    /// ```
+    #[must_use]
+    pub fn which_pressed_verbose(
+        &self,
+        input_streams: &InputStreams,
+        clash_strategy: ClashStrategy,
+    ) -> ClashReport<A> {
+        let mut action_data = self.which_pressed_unresolved(input_streams);
+        let mut suppressed = Vec::default();
+
+        for clash in self.get_clashes(&action_data, input_streams) {
+            if let Some(culled_action) = resolve_clash(&clash, &clash_strategy, input_streams) {
+                let (inputs, overruling_inputs, overruled_by) =
+                    if culled_action.index() == clash.index_a {
+                        (&clash.inputs_a, &clash.inputs_b, clash.index_b)
+                    } else {
+                        (&clash.inputs_b, &clash.inputs_a, clash.index_a)
+                    };
+
+                suppressed.push(SuppressedAction {
+                    action: culled_action.clone(),
+                    overruled_by: A::get_at(overruled_by).unwrap(),
+                    inputs: pressed_inputs(inputs, input_streams),
+                    overruling_inputs: pressed_inputs(overruling_inputs, input_streams),
+                });
                 action_data[culled_action.index()] = ActionData::default();
             }
         }
+
+        ClashReport {
+            action_data,
+            suppressed,
+        }
+    }
+
+    /// Returns every action that is already bound to exactly the same `input`
+    ///
+    /// Unlike [`InputMap::clash_report`], this only detects identical bindings:
+    /// an input that is merely part of a longer chord does not conflict with it.
+    /// Disabled bindings are included, as they may be re-enabled later.
+    #[must_use]
+    pub fn conflicts(&self, input: &UserInput) -> Vec<A> {
+        A::variants()
+            .filter(|action| self.get(action.clone()).contains(input))
+            .collect()
+    }
+
+    /// Inserts a mapping between `action` and `input`, unless `input` is already bound to another action
+    ///
+    /// Re-inserting an existing binding of the same `action` is not a conflict, and does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map is full and `input` is not a duplicate.
+    pub fn try_insert(
+        &mut self,
+        action: A,
+        input: impl Into<UserInput>,
+    ) -> Result<&mut Self, BindingConflict<A>> {
+        let input = input.into();
+        let actions: Vec<A> = self
+            .conflicts(&input)
+            .into_iter()
+            .filter(|conflicting| conflicting.index() != action.index())
+            .collect();
+
+        if actions.is_empty() {
+            Ok(self.insert(action, input))
+        } else {
+            Err(BindingConflict { input, actions })
+        }
     }
 
     /// Updates the cache of possible input clashes
@@ -131,8 +551,8 @@ impl<A: Actionlike> InputMap<A> {
     fn possible_clash(&self, action_a: A, action_b: A) -> Option<Clash<A>> {
         let mut clash = Clash::new(action_a.clone(), action_b.clone());
 
-        for input_a in self.get(action_a).iter() {
-            for input_b in self.get(action_b.clone()).iter() {
+        for input_a in self.enabled_inputs(action_a) {
+            for input_b in self.enabled_inputs(action_b.clone()) {
                 if input_a.clashes(input_b) {
                     clash.inputs_a.push(input_a.clone());
                     clash.inputs_b.push(input_b.clone());
@@ -148,6 +568,130 @@ impl<A: Actionlike> InputMap<A> {
     }
 }
 
+/// An offline report of every binding clash in an [`InputMap`], created by [`InputMap::clash_report`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClashAnalysis<A: Actionlike> {
+    /// Each pair of clashing bindings, ordered by the [`Actionlike::index`] of their actions
+    pub clashes: Vec<PotentialClash<A>>,
+}
+
+impl<A: Actionlike> ClashAnalysis<A> {
+    /// Are there no clashing bindings at all?
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.clashes.is_empty()
+    }
+
+    /// Iterates over the clashes whose outcome depends on the [`ClashStrategy`] chosen
+    pub fn strategy_dependent(&self) -> impl Iterator<Item = &PotentialClash<A>> {
+        self.clashes
+            .iter()
+            .filter(|clash| clash.strategy_dependent())
+    }
+
+    /// Iterates over the clashes that involve the `action`
+    pub fn involving(&self, action: A) -> impl Iterator<Item = &PotentialClash<A>> {
+        self.clashes.iter().filter(move |clash| {
+            clash.action_a.index() == action.index() || clash.action_b.index() == action.index()
+        })
+    }
+}
+
+/// A pair of bindings for different actions which clash whenever `input_a` and `input_b` are both pressed
+#[derive(Debug, Clone, PartialEq)]
+pub struct PotentialClash<A: Actionlike> {
+    /// The action bound to `input_a`
+    pub action_a: A,
+    /// The action bound to `input_b`
+    pub action_b: A,
+    /// The binding of `action_a`
+    pub input_a: UserInput,
+    /// The binding of `action_b`
+    pub input_b: UserInput,
+}
+
+impl<A: Actionlike> PotentialClash<A> {
+    /// Which actions are pressed when this clash is resolved using the `clash_strategy`?
+    #[must_use]
+    pub fn outcome(&self, clash_strategy: ClashStrategy) -> ClashOutcome<A> {
+        let culled: Option<A> = resolve_by_strategy(
+            (self.action_a.index(), self.input_a.len()),
+            (self.action_b.index(), self.input_b.len()),
+            clash_strategy,
+        );
+
+        match culled {
+            None => ClashOutcome::PressBoth,
+            Some(culled) if culled.index() == self.action_a.index() => {
+                ClashOutcome::Press(self.action_b.clone())
+            }
+            Some(_) => ClashOutcome::Press(self.action_a.clone()),
+        }
+    }
+
+    /// Does the outcome of this clash differ between any of the available [`ClashStrategy`] variants?
+    #[must_use]
+    pub fn strategy_dependent(&self) -> bool {
+        ClashStrategy::variants()
+            .map(|clash_strategy| match self.outcome(clash_strategy) {
+                ClashOutcome::PressBoth => None,
+                ClashOutcome::Press(action) => Some(action.index()),
+            })
+            .dedup()
+            .count()
+            > 1
+    }
+}
+
+/// The result of resolving a [`PotentialClash`] with a particular [`ClashStrategy`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClashOutcome<A: Actionlike> {
+    /// Both actions are pressed
+    PressBoth,
+    /// Only this action is pressed, overruling the other
+    Press(A),
+}
+
+/// The actions pressed by an [`InputMap`], along with those that were suppressed by clashes, created by [`InputMap::which_pressed_verbose`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClashReport<A: Actionlike> {
+    /// The pressed actions, as returned by [`InputMap::which_pressed`]
+    pub action_data: Vec<ActionData>,
+    /// Each action that was pressed but then released to resolve a clash, in the order that the clashes were resolved
+    pub suppressed: Vec<SuppressedAction<A>>,
+}
+
+/// An action that was released to resolve a clash, as recorded in a [`ClashReport`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuppressedAction<A: Actionlike> {
+    /// The action that was released
+    pub action: A,
+    /// The clashing action that remained pressed
+    pub overruled_by: A,
+    /// The pressed inputs of `action` that caused the clash
+    pub inputs: Vec<UserInput>,
+    /// The pressed inputs of `overruled_by` that caused the clash
+    pub overruling_inputs: Vec<UserInput>,
+}
+
+/// The binding could not be inserted by [`InputMap::try_insert`], as its input is already bound to other actions
+#[derive(Debug, Clone, PartialEq)]
+pub struct BindingConflict<A: Actionlike> {
+    /// The input that was not inserted
+    pub input: UserInput,
+    /// The actions that `input` is already bound to
+    pub actions: Vec<A>,
+}
+
+impl<A: Actionlike + Debug> Display for BindingConflict<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is already bound to {:?}", self.input, self.actions)
+    }
+}
+
+impl<A: Actionlike + Debug> std::error::Error for BindingConflict<A> {}
+
 /// A user-input clash, which stores the actions that are being clashed on,
 /// as well as the corresponding user inputs
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -248,11 +792,20 @@ fn check_clash<A: Actionlike>(clash: &Clash<A>, input_streams: &InputStreams) ->
     }
 }
 
+/// The subset of `inputs` that are currently pressed
+fn pressed_inputs(inputs: &[UserInput], input_streams: &InputStreams) -> Vec<UserInput> {
+    inputs
+        .iter()
+        .filter(|&input| input_streams.input_pressed(input))
+        .cloned()
+        .collect()
+}
+
 /// Which (if any) of the actions in the [`Clash`] should be discarded?
 #[must_use]
 fn resolve_clash<A: Actionlike>(
     clash: &Clash<A>,
-    clash_strategy: ClashStrategy,
+    clash_resolver: &dyn ClashResolver<A>,
     input_streams: &InputStreams,
 ) -> Option<A> {
     // Figure out why the actions are pressed
@@ -280,34 +833,164 @@ fn resolve_clash<A: Actionlike>(
     }
 
     // There's a real clash; resolve it according to the `clash_strategy`
+    let longest_a: usize = reasons_a_is_pressed
+        .iter()
+        .map(|input| input.len())
+        .reduce(|a, b| a.max(b))
+        .unwrap_or_default();
+
+    let longest_b: usize = reasons_b_is_pressed
+        .iter()
+        .map(|input| input.len())
+        .reduce(|a, b| a.max(b))
+        .unwrap_or_default();
+
+    let action_a = A::get_at(clash.index_a).unwrap();
+    let action_b = A::get_at(clash.index_b).unwrap();
+
+    match clash_resolver.resolve(&[(action_a.clone(), longest_a), (action_b.clone(), longest_b)]) {
+        Some(winner) if winner.index() == clash.index_a => Some(action_b),
+        Some(winner) if winner.index() == clash.index_b => Some(action_a),
+        _ => None,
+    }
+}
+
+/// Which (if any) of the two actions should be discarded under the `clash_strategy`?
+///
+/// Each action is described by its `Actionlike::index` and the length of the longest input that pressed it.
+#[must_use]
+fn resolve_by_strategy<A: Actionlike>(
+    (index_a, longest_a): (usize, usize),
+    (index_b, longest_b): (usize, usize),
+    clash_strategy: ClashStrategy,
+) -> Option<A> {
     match clash_strategy {
         // Do nothing
         ClashStrategy::PressAll => None,
         // Remove the clashing action with the shorter chord
-        ClashStrategy::PrioritizeLongest => {
-            let longest_a: usize = reasons_a_is_pressed
-                .iter()
-                .map(|input| input.len())
-                .reduce(|a, b| a.max(b))
-                .unwrap_or_default();
+        ClashStrategy::PrioritizeLongest => match longest_a.cmp(&longest_b) {
+            Ordering::Greater => Some(A::get_at(index_b).unwrap()),
+            Ordering::Less => Some(A::get_at(index_a).unwrap()),
+            Ordering::Equal => None,
+        },
+        // Remove the clashing action that comes later in the action ordering
+        ClashStrategy::UseActionOrder => {
+            let action_a = A::get_at(index_a).unwrap();
+            let action_b = A::get_at(index_b).unwrap();
+            match action_a.ordering().cmp(&action_b.ordering()) {
+                Ordering::Greater => Some(action_a),
+                Ordering::Less => Some(action_b),
+                Ordering::Equal => None,
+            }
+        }
+    }
+}
 
-            let longest_b: usize = reasons_b_is_pressed
-                .iter()
-                .map(|input| input.len())
-                .reduce(|a, b| a.max(b))
-                .unwrap_or_default();
+/// How should simultaneous opposing directional inputs (such as left and right) be resolved?
+///
+/// This is known as "SOCD cleaning" (Simultaneous Opposing Cardinal Directions) in competitive games.
+/// Configure it for pairs of actions using the [`SocdCleaning`] resource.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum SocdPolicy {
+    /// When both actions are pressed, neither is
+    Neutral,
+    /// When both actions are pressed, the one that was pressed most recently wins
+    ///
+    /// Releasing the winning action returns control to the other action, if it is still held.
+    /// If both actions are pressed during the same frame, neither is pressed until one of them is released.
+    LastInputPriority,
+    /// When both actions are pressed, the positive action (such as right or up) wins
+    PositivePriority,
+}
 
-            match longest_a.cmp(&longest_b) {
-                Ordering::Greater => Some(A::get_at(clash.index_b).unwrap()),
-                Ordering::Less => Some(A::get_at(clash.index_a).unwrap()),
-                Ordering::Equal => None,
+/// A resource that configures [`SocdPolicy`]s for pairs of opposing actions of type `A`
+///
+/// These are applied by [`update_action_state`](crate::systems::update_action_state)
+/// after inputs are resolved by the [`InputMap`], but before the [`ActionState`] is updated,
+/// so the suppressed action is never pressed at all.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::clashing_inputs::{SocdCleaning, SocdPolicy};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Left,
+///     Right,
+///     Up,
+///     Down,
+/// }
+///
+/// let mut socd_cleaning = SocdCleaning::<Action>::default();
+/// socd_cleaning
+///     .add_pair(Action::Left, Action::Right, SocdPolicy::LastInputPriority)
+///     .add_pair(Action::Down, Action::Up, SocdPolicy::PositivePriority);
+///
+/// App::new()
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .insert_resource(socd_cleaning);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SocdCleaning<A: Actionlike> {
+    pairs: Vec<(A, A, SocdPolicy)>,
+}
+
+// Implement manually to not require [`Default`] for `A`
+impl<A: Actionlike> Default for SocdCleaning<A> {
+    fn default() -> Self {
+        Self { pairs: Vec::new() }
+    }
+}
+
+impl<A: Actionlike> SocdCleaning<A> {
+    /// Resolves simultaneous presses of the `negative` and `positive` actions using the provided `policy`
+    pub fn add_pair(&mut self, negative: A, positive: A, policy: SocdPolicy) -> &mut Self {
+        self.pairs.push((negative, positive, policy));
+        self
+    }
+
+    /// Applies each configured [`SocdPolicy`] to the freshly resolved `action_data`
+    ///
+    /// The `previous` [`ActionState`] is used to determine which action was pressed most recently.
+    /// Suppressed actions are released, and marked as [`suppressed`](ActionData::suppressed).
+    pub fn clean(&self, action_data: &mut [ActionData], previous: &ActionState<A>) {
+        for (negative, positive, policy) in self.pairs.iter() {
+            let (negative, positive) = (negative.index(), positive.index());
+            if !(action_data[negative].state.pressed() && action_data[positive].state.pressed()) {
+                continue;
             }
-        } // Remove the clashing action that comes later in the action enum
-        ClashStrategy::UseActionOrder => match clash.index_a.cmp(&clash.index_b) {
-            Ordering::Greater => Some(A::get_at(clash.index_a).unwrap()),
-            Ordering::Less => Some(A::get_at(clash.index_b).unwrap()),
-            Ordering::Equal => None,
-        },
+
+            let previous_negative = &previous.action_data[negative];
+            let previous_positive = &previous.action_data[positive];
+
+            let losers: &[usize] = match policy {
+                SocdPolicy::Neutral => &[negative, positive],
+                SocdPolicy::PositivePriority => &[negative],
+                SocdPolicy::LastInputPriority => {
+                    match (
+                        previous_negative.state.pressed(),
+                        previous_positive.state.pressed(),
+                    ) {
+                        // The positive action was already being suppressed, or was just pressed
+                        (true, false) if previous_positive.suppressed => &[positive],
+                        (true, false) => &[negative],
+                        (false, true) if previous_negative.suppressed => &[negative],
+                        (false, true) => &[positive],
+                        // Pressed during the same frame, so neither has priority
+                        _ => &[negative, positive],
+                    }
+                }
+            };
+
+            for &loser in losers {
+                action_data[loser] = ActionData {
+                    suppressed: true,
+                    ..Default::default()
+                };
+            }
+        }
     }
 }
 
@@ -450,7 +1133,7 @@ mod tests {
             assert_eq!(
                 resolve_clash(
                     &simple_clash,
-                    ClashStrategy::PrioritizeLongest,
+                    &ClashStrategy::PrioritizeLongest,
                     &input_streams,
                 ),
                 Some(One)
@@ -460,7 +1143,7 @@ mod tests {
             assert_eq!(
                 resolve_clash(
                     &reversed_clash,
-                    ClashStrategy::PrioritizeLongest,
+                    &ClashStrategy::PrioritizeLongest,
                     &input_streams,
                 ),
                 Some(One)
@@ -476,7 +1159,7 @@ mod tests {
             assert_eq!(
                 resolve_clash(
                     &chord_clash,
-                    ClashStrategy::PrioritizeLongest,
+                    &ClashStrategy::PrioritizeLongest,
                     &input_streams,
                 ),
                 Some(OneAndTwo)
@@ -498,20 +1181,110 @@ mod tests {
             let input_streams = InputStreams::from_keyboard(&keyboard);
 
             assert_eq!(
-                resolve_clash(&simple_clash, ClashStrategy::UseActionOrder, &input_streams,),
+                resolve_clash(
+                    &simple_clash,
+                    &ClashStrategy::UseActionOrder,
+                    &input_streams,
+                ),
                 Some(CtrlOne)
             );
 
             assert_eq!(
                 resolve_clash(
                     &reversed_clash,
-                    ClashStrategy::UseActionOrder,
+                    &ClashStrategy::UseActionOrder,
                     &input_streams,
                 ),
                 Some(CtrlOne)
             );
         }
 
+        #[test]
+        fn resolve_use_action_order_with_custom_ordering() {
+            use bevy::prelude::*;
+
+            #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+            enum EditorAction {
+                Save,
+                SaveAs,
+            }
+
+            impl Actionlike for EditorAction {
+                const N_VARIANTS: usize = 2;
+
+                fn get_at(index: usize) -> Option<Self> {
+                    [EditorAction::Save, EditorAction::SaveAs]
+                        .get(index)
+                        .copied()
+                }
+
+                fn index(&self) -> usize {
+                    *self as usize
+                }
+
+                // `SaveAs` wins clashes, even though it is declared second
+                fn ordering(&self) -> usize {
+                    match self {
+                        EditorAction::Save => 1,
+                        EditorAction::SaveAs => 0,
+                    }
+                }
+            }
+
+            let mut input_map = InputMap::default();
+            input_map.insert(EditorAction::Save, S);
+            input_map.insert_chord(EditorAction::SaveAs, [LControl, S]);
+            let clash = input_map
+                .possible_clash(EditorAction::Save, EditorAction::SaveAs)
+                .unwrap();
+            let mut keyboard: Input<KeyCode> = Default::default();
+            keyboard.press(S);
+            keyboard.press(LControl);
+
+            let input_streams = InputStreams::from_keyboard(&keyboard);
+
+            assert_eq!(
+                resolve_clash(&clash, &ClashStrategy::UseActionOrder, &input_streams),
+                Some(EditorAction::Save)
+            );
+        }
+
+        #[test]
+        fn custom_resolver() {
+            use bevy::prelude::*;
+            use Action::*;
+
+            // Prefer the action with the shortest chord, and never resolve clashes involving `Two`
+            let prefer_shortest = |candidates: &[(Action, usize)]| {
+                if candidates.iter().any(|(action, _)| *action == Two) {
+                    None
+                } else {
+                    candidates
+                        .iter()
+                        .min_by_key(|(_, length)| *length)
+                        .map(|(action, _)| *action)
+                }
+            };
+
+            let input_map = test_input_map();
+            let mut keyboard: Input<KeyCode> = Default::default();
+            keyboard.press(Key1);
+            keyboard.press(Key2);
+
+            let input_streams = InputStreams::from_keyboard(&keyboard);
+            let one_clash = input_map.possible_clash(One, OneAndTwo).unwrap();
+            assert_eq!(
+                resolve_clash(&one_clash, &prefer_shortest, &input_streams),
+                Some(OneAndTwo)
+            );
+
+            let two_clash = input_map.possible_clash(Two, OneAndTwo).unwrap();
+            assert_eq!(
+                resolve_clash(&two_clash, &prefer_shortest, &input_streams),
+                None
+            );
+        }
+
         #[test]
         fn handle_clashes() {
             use crate::buttonlike::ButtonState;
@@ -541,6 +1314,64 @@ mod tests {
             assert_eq!(action_data, expected);
         }
 
+        #[test]
+        fn clash_report() {
+            use Action::*;
+
+            let clash_report = test_input_map().clash_report();
+            assert!(!clash_report.is_empty());
+            assert_eq!(clash_report.involving(Two).count(), 3);
+            assert!(clash_report
+                .involving(One)
+                .all(|clash| clash.action_a == One));
+
+            // The longer chord comes later in the action enum, so these strategies disagree
+            let ctrl_alt = clash_report
+                .clashes
+                .iter()
+                .find(|clash| clash.action_a == CtrlOne && clash.action_b == CtrlAltOne)
+                .unwrap();
+            assert_eq!(
+                ctrl_alt.outcome(ClashStrategy::PrioritizeLongest),
+                ClashOutcome::Press(CtrlAltOne)
+            );
+            assert_eq!(
+                ctrl_alt.outcome(ClashStrategy::UseActionOrder),
+                ClashOutcome::Press(CtrlOne)
+            );
+
+            // Every clash in this map is affected by the strategy
+            assert_eq!(
+                clash_report.strategy_dependent().count(),
+                clash_report.clashes.len()
+            );
+        }
+
+        #[test]
+        fn binding_conflicts() {
+            use crate::user_input::UserInput;
+            use Action::*;
+
+            let mut input_map = test_input_map();
+            assert_eq!(input_map.conflicts(&Key1.into()), vec![One]);
+            assert_eq!(
+                input_map.conflicts(&UserInput::chord([Key2, Key1])),
+                vec![OneAndTwo]
+            );
+            assert!(input_map.conflicts(&Key3.into()).is_empty());
+
+            assert_eq!(
+                input_map.try_insert(Two, Key1).unwrap_err(),
+                BindingConflict {
+                    input: Key1.into(),
+                    actions: vec![One]
+                }
+            );
+            assert!(input_map.try_insert(One, Key1).is_ok());
+            assert!(input_map.try_insert(Two, Key3).is_ok());
+            assert_eq!(input_map.conflicts(&Key3.into()), vec![Two]);
+        }
+
         #[test]
         fn which_pressed() {
             use bevy::prelude::*;
@@ -567,4 +1398,76 @@ mod tests {
             }
         }
     }
+
+    mod socd_cleaning {
+        use super::*;
+        use crate::action_state::ActionState;
+        use crate::buttonlike::ButtonState;
+
+        fn pressed(actions: &[Action]) -> Vec<ActionData> {
+            let mut action_data = vec![ActionData::default(); Action::N_VARIANTS];
+            for action in actions {
+                action_data[action.index()].state = ButtonState::JustPressed;
+            }
+            action_data
+        }
+
+        fn step(
+            socd_cleaning: &SocdCleaning<Action>,
+            action_state: &mut ActionState<Action>,
+            actions: &[Action],
+        ) {
+            let mut action_data = pressed(actions);
+            socd_cleaning.clean(&mut action_data, action_state);
+            action_state.tick(bevy_utils::Instant::now());
+            action_state.update(action_data);
+        }
+
+        #[test]
+        fn neutral_and_positive_priority() {
+            use Action::*;
+
+            let mut socd_cleaning = SocdCleaning::default();
+            socd_cleaning.add_pair(One, Two, SocdPolicy::Neutral);
+            let mut action_state = ActionState::<Action>::default();
+            step(&socd_cleaning, &mut action_state, &[One, Two]);
+            assert!(action_state.released(One));
+            assert!(action_state.released(Two));
+            assert!(action_state.action_data(One).suppressed);
+
+            let mut socd_cleaning = SocdCleaning::default();
+            socd_cleaning.add_pair(One, Two, SocdPolicy::PositivePriority);
+            let mut action_state = ActionState::<Action>::default();
+            step(&socd_cleaning, &mut action_state, &[One, Two]);
+            assert!(action_state.released(One));
+            assert!(action_state.pressed(Two));
+        }
+
+        #[test]
+        fn last_input_priority() {
+            use Action::*;
+
+            let mut socd_cleaning = SocdCleaning::default();
+            socd_cleaning.add_pair(One, Two, SocdPolicy::LastInputPriority);
+            let mut action_state = ActionState::<Action>::default();
+
+            step(&socd_cleaning, &mut action_state, &[One]);
+            assert!(action_state.just_pressed(One));
+
+            // The newer press wins
+            step(&socd_cleaning, &mut action_state, &[One, Two]);
+            assert!(action_state.just_released(One));
+            assert!(action_state.just_pressed(Two));
+
+            // And keeps winning while both are held
+            step(&socd_cleaning, &mut action_state, &[One, Two]);
+            assert!(action_state.released(One));
+            assert!(action_state.pressed(Two));
+
+            // Releasing the winner returns control to the held action
+            step(&socd_cleaning, &mut action_state, &[One]);
+            assert!(action_state.just_pressed(One));
+            assert!(action_state.just_released(Two));
+        }
+    }
 }
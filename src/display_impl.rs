@@ -17,6 +17,22 @@ impl Display for UserInput {
                 }
                 write!(f, "{string}")
             }
+            // The representation of the axis
+            UserInput::SingleAxis(axis_type) => write!(f, "{axis_type:?}"),
+            // The representation of the axis, followed by the sign of its half
+            UserInput::HalfAxis {
+                axis_type,
+                inverted,
+            } => {
+                let sign = if *inverted { '-' } else { '+' };
+                write!(f, "{axis_type:?}{sign}")
+            }
+            // Both axes, seperated by "/"
+            UserInput::DualAxis { x, y } => write!(f, "{x:?}/{y:?}"),
+            // The direction of mouse movement
+            UserInput::MouseMotion { direction } => write!(f, "Mouse{direction:?}"),
+            // The direction of scrolling
+            UserInput::MouseWheel { direction } => write!(f, "Wheel{direction:?}"),
         }
     }
 }
@@ -27,6 +43,8 @@ impl Display for InputButton {
             InputButton::Gamepad(button) => write!(f, "{button:?}"),
             InputButton::Mouse(button) => write!(f, "{button:?}"),
             InputButton::Keyboard(button) => write!(f, "{button:?}"),
+            InputButton::Touchpad(button) => write!(f, "Touchpad{button:?}"),
+            InputButton::Modifier(modifier) => write!(f, "{modifier:?}"),
         }
     }
 }
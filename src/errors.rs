@@ -1,4 +1,4 @@
-//! Errors that may occur when working with 2D coordinates
+//! Errors that may occur when working with 2D coordinates or serialized inputs
 
 use derive_more::{Display, Error};
 
@@ -11,3 +11,20 @@ use derive_more::{Display, Error};
 /// In almost all cases, the correct way to handle this error is to simply not change the rotation.
 #[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
 pub struct NearlySingularConversion;
+
+/// The serialized inputs passed to [`ActionState::from_serialized_inputs`](crate::action_state::ActionState::from_serialized_inputs)
+/// did not have one bit for each action
+///
+/// This usually means that the inputs were serialized with a different [`Actionlike`](crate::Actionlike) type.
+#[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
+#[display(
+    fmt = "expected {} bytes of serialized inputs, but found {}",
+    expected,
+    found
+)]
+pub struct SerializedInputsLength {
+    /// The number of bytes needed to store one bit for each action
+    pub expected: usize,
+    /// The number of bytes provided
+    pub found: usize,
+}
@@ -0,0 +1,173 @@
+//! Stacked input contexts, where modal layers like menus and dialogs hide input from the layers beneath them
+
+use crate::Actionlike;
+use bevy_utils::HashSet;
+use std::any::{type_name, TypeId};
+
+/// A resource storing a stack of [`InputLayer`]s, which controls which action types respond to input
+///
+/// An action type `A` is suppressed if any blocking layer sits above the topmost layer that contains `A`.
+/// Action types that are not in any layer are suppressed by every blocking layer.
+/// With an empty stack, every action type is active.
+///
+/// The [`ActionState`](crate::action_state::ActionState)s of suppressed action types ignore user inputs, and are released.
+/// Actions whose inputs are held while suppressed are [consumed](crate::action_state::ActionState::consume),
+/// like with [`ReenablePolicy::WaitForRelease`](crate::plugin::ReenablePolicy::WaitForRelease),
+/// so uncovering their layer does not press them again until their inputs are released and pressed again.
+/// Touches, virtual joysticks and [`ActionStateDriver`](crate::action_state::ActionStateDriver) buttons are ignored as well.
+///
+/// This is a more structured alternative to [`ToggleActions`](crate::plugin::ToggleActions),
+/// for games whose menus and dialogs open on top of each other.
+///
+/// # Example
+/// ```rust
+/// use leafwing_input_manager::input_context::{InputContext, InputLayer};
+/// use leafwing_input_manager::prelude::*;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum GameplayAction {
+///     Jump,
+/// }
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum MenuAction {
+///     Confirm,
+/// }
+///
+/// let mut input_context = InputContext::default();
+/// input_context.push(InputLayer::new::<GameplayAction>());
+/// assert!(input_context.is_active::<GameplayAction>());
+///
+/// // Opening the menu hides input from gameplay
+/// input_context.push(InputLayer::new::<MenuAction>());
+/// assert!(input_context.is_active::<MenuAction>());
+/// assert!(!input_context.is_active::<GameplayAction>());
+///
+/// // Closing it restores control
+/// input_context.pop();
+/// assert!(input_context.is_active::<GameplayAction>());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputContext {
+    layers: Vec<InputLayer>,
+}
+
+impl InputContext {
+    /// Pushes a new `layer` on top of the stack
+    pub fn push(&mut self, layer: InputLayer) {
+        self.layers.push(layer);
+    }
+
+    /// Removes and returns the topmost layer, if any
+    pub fn pop(&mut self) -> Option<InputLayer> {
+        self.layers.pop()
+    }
+
+    /// The topmost layer, if any
+    #[must_use]
+    pub fn top(&self) -> Option<&InputLayer> {
+        self.layers.last()
+    }
+
+    /// The layers of the stack, from bottom to top
+    pub fn layers(&self) -> impl Iterator<Item = &InputLayer> {
+        self.layers.iter()
+    }
+
+    /// The number of layers in the stack
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Is the stack empty?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Removes every layer from the stack
+    pub fn clear(&mut self) {
+        self.layers.clear();
+    }
+
+    /// Does the action type `A` currently respond to input?
+    #[must_use]
+    pub fn is_active<A: Actionlike>(&self) -> bool {
+        let type_id = TypeId::of::<A>();
+
+        for layer in self.layers.iter().rev() {
+            if layer.contains_type_id(type_id) {
+                return true;
+            }
+
+            if layer.blocks_lower {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// One layer of an [`InputContext`], such as gameplay, a menu or a dialog
+///
+/// Each layer contains some set of action types. By default, layers block all layers beneath them;
+/// use [`InputLayer::passthrough`] for overlays that should not.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputLayer {
+    /// The name of this layer, for debugging
+    pub name: &'static str,
+    /// Are the action types of the layers beneath this one suppressed?
+    pub blocks_lower: bool,
+    action_types: HashSet<TypeId>,
+}
+
+impl InputLayer {
+    /// Creates a new blocking [`InputLayer`] containing the action type `A`, named after `A`
+    #[must_use]
+    pub fn new<A: Actionlike>() -> Self {
+        InputLayer {
+            name: type_name::<A>(),
+            blocks_lower: true,
+            action_types: HashSet::default(),
+        }
+        .with_actions::<A>()
+    }
+
+    /// Creates a new blocking [`InputLayer`] with the provided `name` and no action types
+    ///
+    /// This suppresses every lower layer, without activating any action types of its own.
+    #[must_use]
+    pub fn named(name: &'static str) -> Self {
+        InputLayer {
+            name,
+            blocks_lower: true,
+            action_types: HashSet::default(),
+        }
+    }
+
+    /// Adds the action type `A` to this layer
+    #[must_use]
+    pub fn with_actions<A: Actionlike>(mut self) -> Self {
+        self.action_types.insert(TypeId::of::<A>());
+        self
+    }
+
+    /// Allows the layers beneath this one to continue responding to input
+    #[must_use]
+    pub fn passthrough(mut self) -> Self {
+        self.blocks_lower = false;
+        self
+    }
+
+    /// Does this layer contain the action type `A`?
+    #[must_use]
+    pub fn contains<A: Actionlike>(&self) -> bool {
+        self.contains_type_id(TypeId::of::<A>())
+    }
+
+    fn contains_type_id(&self, type_id: TypeId) -> bool {
+        self.action_types.contains(&type_id)
+    }
+}
@@ -1,17 +1,21 @@
 //! This module contains [`InputMap`] and its supporting methods and impls.
 
-use crate::action_state::ActionData;
+use crate::action_state::{ActionData, ActionState};
+use crate::axislike::{AxisData, AxisThresholds, DeadZone, DualAxisData};
 use crate::buttonlike::ButtonState;
-use crate::clashing_inputs::ClashStrategy;
-use crate::user_input::{InputButton, InputStreams, UserInput};
+use crate::clashing_inputs::{ClashResolver, ClashStrategy};
+use crate::user_input::{ControllerLayout, InputButton, InputStreams, ModifierKey, UserInput};
 use crate::Actionlike;
 
-use bevy_ecs::component::Component;
-use bevy_input::gamepad::Gamepad;
+use bevy_ecs::{component::Component, entity::Entity};
+use bevy_input::gamepad::{Gamepad, GamepadAxisType};
+use bevy_math::Vec2;
+use bevy_utils::Duration;
 
 use core::fmt::Debug;
 use petitset::PetitSet;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::marker::PhantomData;
 
 /// Maps from raw inputs to an input-method agnostic representation
@@ -34,6 +38,10 @@ use std::marker::PhantomData;
 /// Set the [`ClashStrategy`](crate::clashing_inputs::ClashStrategy) resource
 /// to configure this behavior.
 ///
+/// [`InputMap`] implements [`Serialize`] and [`Deserialize`], so rebindable controls can be saved to disk.
+/// The associated gamepad, controller layout and [`BindingOptions`] are stored alongside the bindings themselves;
+/// `A` does not need to be serializable.
+///
 /// # Example
 /// ```rust
 /// use bevy::prelude::*;
@@ -76,7 +84,21 @@ pub struct InputMap<A: Actionlike> {
     /// The raw vector of [PetitSet]s used to store the input mapping,
     /// indexed by the `Actionlike::id` of `A`
     map: Vec<PetitSet<UserInput, 16>>,
+    /// The [`BindingOptions`] of the bindings in `map` that do not use the default options, indexed in the same way
+    binding_options: Vec<Vec<(UserInput, BindingOptions)>>,
+    /// The dead zone applied to the analog inputs of each action, indexed in the same way
+    deadzones: Vec<DeadZone>,
+    /// The axes whose values are negated before they are used by any binding
+    inverted_axes: Vec<GamepadAxisType>,
+    /// The [`AxisThresholds`] applied to the analog value of each action, indexed in the same way as `map`
+    axis_thresholds: Vec<Option<AxisThresholds>>,
+    /// The length of the [`Cooldown`](crate::action_state::Cooldown) of each action, if any, indexed in the same way
+    cooldowns: Vec<Option<Duration>>,
     associated_gamepad: Option<Gamepad>,
+    controller_layout: ControllerLayout,
+    mouse_motion_threshold: f32,
+    /// The [`ClashStrategy`] used for this map instead of the global resource, if any
+    clash_strategy: Option<ClashStrategy>,
     #[serde(skip)]
     marker: PhantomData<A>,
 }
@@ -85,12 +107,45 @@ impl<A: Actionlike> Default for InputMap<A> {
     fn default() -> Self {
         InputMap {
             map: A::variants().map(|_| PetitSet::default()).collect(),
+            binding_options: A::variants().map(|_| Vec::new()).collect(),
+            deadzones: A::variants().map(|_| DeadZone::default()).collect(),
+            inverted_axes: Vec::new(),
+            axis_thresholds: vec![None; A::N_VARIANTS],
+            cooldowns: vec![None; A::N_VARIANTS],
             associated_gamepad: None,
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            clash_strategy: None,
             marker: PhantomData,
         }
     }
 }
 
+/// The settings of a single binding in an [`InputMap`]
+///
+/// Read these using [`InputMap::binding_options`].
+/// Each setting can also be changed individually, such as by [`InputMap::set_binding_enabled`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BindingOptions {
+    /// Is this binding ignored when checking which actions are pressed?
+    ///
+    /// Set using [`InputMap::set_binding_enabled`].
+    pub disabled: bool,
+    /// Is this binding ignored while a [`ModifierKey`] that is not part of it is held?
+    ///
+    /// Set using [`InputMap::set_exclude_modifiers`].
+    pub exclude_modifiers: bool,
+    /// Must the buttons of this chord that are not [`ModifierKey`]s be pressed after its modifiers?
+    ///
+    /// Set using [`InputMap::set_shortcut`].
+    pub shortcut: bool,
+    /// The window within which every button of this chord must be pressed for it to count, if any
+    ///
+    /// Set using [`InputMap::set_chord_window`].
+    pub chord_window: Option<Duration>,
+}
+
 // Constructors
 impl<A: Actionlike> InputMap<A> {
     /// Creates a new [`InputMap`] from an iterator of `(action, user_input)` pairs
@@ -188,7 +243,8 @@ impl<A: Actionlike> InputMap<A> {
 
     /// Insert a mapping between `action` and the provided `inputs`
     ///
-    /// This method creates multiple distinct bindings.
+    /// This method creates multiple distinct bindings, each of which may be for a different action.
+    /// To bind several inputs to a single action, use [`insert_one_to_many`](Self::insert_one_to_many).
     /// If you want to require multiple buttons to be pressed at once, use [`insert_chord`](Self::insert_chord).
     /// Any iterator that can be converted into a [`UserInput`] can be supplied.
     ///
@@ -206,10 +262,76 @@ impl<A: Actionlike> InputMap<A> {
         self
     }
 
+    /// Inserts a mapping between the single `action` and each of the provided `inputs`
+    ///
+    /// This is equivalent to calling [`InputMap::insert`] once for each input,
+    /// and is convenient for giving an action a binding on each kind of device.
+    /// To insert `(action, input)` pairs for several actions at once, use [`InputMap::insert_multiple`].
+    /// Existing bindings for the `action` are kept; use [`InputMap::replace_bindings`] to discard them.
+    ///
+    /// The bindings of each action are stored in a [`PetitSet`] with a fixed capacity of 16,
+    /// so inserting never reallocates, and there is no need to reserve capacity up front.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_input::{gamepad::GamepadButtonType, keyboard::KeyCode};
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::user_input::InputButton;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let mut input_map = InputMap::<Action>::default();
+    /// input_map.insert_one_to_many(
+    ///     Action::Jump,
+    ///     [InputButton::from(KeyCode::Space), GamepadButtonType::South.into()],
+    /// );
+    /// assert_eq!(input_map.get(Action::Jump).len(), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `action` already has 16 bindings and any of `inputs` is not a duplicate.
+    pub fn insert_one_to_many(
+        &mut self,
+        action: A,
+        inputs: impl IntoIterator<Item = impl Into<UserInput>>,
+    ) -> &mut Self {
+        for input in inputs {
+            self.insert(action.clone(), input);
+        }
+
+        self
+    }
+
+    /// Replaces all of the inputs bound to `action` with the provided `inputs`
+    ///
+    /// Any existing bindings for the `action`, including disabled ones, are removed first.
+    /// This is useful for overlays, such as a vehicle control scheme, that should override rather than add to a base map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than 16 distinct `inputs` are provided.
+    pub fn replace_bindings(
+        &mut self,
+        action: A,
+        inputs: impl IntoIterator<Item = impl Into<UserInput>>,
+    ) -> &mut Self {
+        self.clear_action(action.clone());
+        for input in inputs {
+            self.insert(action.clone(), input);
+        }
+
+        self
+    }
+
     /// Insert a mapping between `action` and the simultaneous combination of `buttons` provided
     ///
     /// Any iterator that can be converted into a [`Button`] can be supplied, but will be converted into a [`PetitSet`] for storage and use.
     /// Chords can also be added with the [insert](Self::insert) method, if the [`UserInput::Chord`] variant is constructed explicitly.
+    /// Buttons from different devices, such as `LControl` and a mouse click, can be combined by converting each of them into an [`InputButton`].
     ///
     /// # Panics
     ///
@@ -223,6 +345,112 @@ impl<A: Actionlike> InputMap<A> {
         self
     }
 
+    /// Insert a mapping between `action` and the chord of `buttons`, which only counts if every one of its buttons was pressed within `within` of each other
+    ///
+    /// This distinguishes a deliberate press of Ctrl+S from tapping S while Ctrl is held for some other reason.
+    /// Once the chord is pressed in time, it remains pressed for as long as its buttons are held.
+    /// Press times are read from the [`ButtonPressTimes`](crate::user_input::ButtonPressTimes) resource,
+    /// which is maintained by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin);
+    /// if it is missing, the window is ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_input::keyboard::KeyCode;
+    /// use bevy_utils::Duration;
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::user_input::UserInput;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Save,
+    /// }
+    ///
+    /// let mut input_map = InputMap::default();
+    /// input_map.insert_chord_within(Action::Save, [KeyCode::LControl, KeyCode::S], Duration::from_millis(200));
+    ///
+    /// assert_eq!(
+    ///     input_map.chord_window(Action::Save, UserInput::chord([KeyCode::LControl, KeyCode::S])),
+    ///     Some(Duration::from_millis(200))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map is full and `buttons` is not a duplicate.
+    pub fn insert_chord_within(
+        &mut self,
+        action: A,
+        buttons: impl IntoIterator<Item = impl Into<InputButton>>,
+        within: Duration,
+    ) -> &mut Self {
+        let chord = UserInput::chord(buttons);
+        self.insert(action.clone(), chord.clone());
+        self.set_chord_window(action, chord, Some(within));
+        self
+    }
+
+    /// The window within which the buttons of the `input` bound to `action` must be pressed, if any
+    ///
+    /// See [`InputMap::insert_chord_within`].
+    #[must_use]
+    pub fn chord_window(&self, action: A, input: impl Into<UserInput>) -> Option<Duration> {
+        self.binding_options(action, input).chord_window
+    }
+
+    /// Sets the window within which the buttons of the `input` bound to `action` must be pressed, or removes it if `within` is `None`
+    ///
+    /// This only affects [`UserInput::Chord`]s, and is ignored if the `input` is not bound to the `action`.
+    /// See [`InputMap::insert_chord_within`].
+    pub fn set_chord_window(
+        &mut self,
+        action: A,
+        input: impl Into<UserInput>,
+        within: Option<Duration>,
+    ) -> &mut Self {
+        let input = input.into();
+        let mut options = self.binding_options(action.clone(), input.clone());
+        options.chord_window = within;
+        self.set_binding_options(action, input, options);
+        self
+    }
+
+    /// Begins binding a chord of `button` and some [`ModifierKey`]s to the `action`
+    ///
+    /// Add modifiers using the methods on the returned [`ChordBuilder`], then call [`ChordBuilder::insert`] to bind the chord.
+    /// Each modifier is bound as an [`InputButton::Modifier`], so either its left or its right key is accepted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    /// use bevy_input::keyboard::KeyCode;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Hash)]
+    /// enum Action {
+    ///     Save,
+    ///     SaveAs,
+    /// }
+    ///
+    /// let mut input_map = InputMap::default();
+    /// input_map.bind_chord(Action::Save, KeyCode::S).with_ctrl().insert();
+    /// input_map
+    ///     .bind_chord(Action::SaveAs, KeyCode::S)
+    ///     .with_ctrl()
+    ///     .with_shift()
+    ///     .insert();
+    ///
+    /// // Each binding is a single chord, which accepts either LControl or RControl
+    /// assert_eq!(input_map.get(Action::Save).len(), 1);
+    /// assert_eq!(input_map.get(Action::SaveAs).len(), 1);
+    /// ```
+    pub fn bind_chord(&mut self, action: A, button: impl Into<InputButton>) -> ChordBuilder<'_, A> {
+        ChordBuilder {
+            input_map: self,
+            action,
+            button: button.into(),
+            modifiers: Vec::default(),
+        }
+    }
+
     /// Merges the provided [`InputMap`] into the [`InputMap`] this method was called on
     ///
     /// This adds both of their bindings to the resulting [`InputMap`].
@@ -238,6 +466,13 @@ impl<A: Actionlike> InputMap<A> {
 
         let mut new_map = InputMap {
             associated_gamepad,
+            controller_layout: self.controller_layout,
+            mouse_motion_threshold: self.mouse_motion_threshold,
+            clash_strategy: self.clash_strategy,
+            deadzones: self.deadzones.clone(),
+            inverted_axes: self.inverted_axes.clone(),
+            axis_thresholds: self.axis_thresholds.clone(),
+            cooldowns: self.cooldowns.clone(),
             ..Default::default()
         };
 
@@ -249,6 +484,30 @@ impl<A: Actionlike> InputMap<A> {
             for input in other.get(action.clone()).iter() {
                 new_map.insert(action.clone(), input.clone());
             }
+
+            // Cooldowns from `self` take precedence over those from `other`
+            if new_map.cooldowns[action.index()].is_none() {
+                new_map.cooldowns[action.index()] = other.cooldowns[action.index()];
+            }
+
+            // Options that were set in either map remain set,
+            // with the chord windows from `self` taking precedence
+            for (input, options) in self.binding_options[action.index()]
+                .iter()
+                .chain(other.binding_options[action.index()].iter())
+            {
+                let merged = new_map.binding_options(action.clone(), input.clone());
+                new_map.set_binding_options(
+                    action.clone(),
+                    input.clone(),
+                    BindingOptions {
+                        disabled: merged.disabled || options.disabled,
+                        exclude_modifiers: merged.exclude_modifiers || options.exclude_modifiers,
+                        shortcut: merged.shortcut || options.shortcut,
+                        chord_window: merged.chord_window.or(options.chord_window),
+                    },
+                );
+            }
         }
 
         *self = new_map;
@@ -256,6 +515,102 @@ impl<A: Actionlike> InputMap<A> {
     }
 }
 
+/// A component that allows the [`InputMap`] on the same entity to drive the [`ActionState`](crate::action_state::ActionState) of other entities
+///
+/// Inputs are resolved once using the [`InputMap`], and the result is copied to each of the `targets`.
+/// This is useful when several entities should all respond to the same controls,
+/// such as a group of selected units or characters in tethered co-op.
+///
+/// Used in [`update_shared_action_states`](crate::systems::update_shared_action_states).
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct SharedInputMap<A: Actionlike> {
+    /// The entities whose [`ActionState`](crate::action_state::ActionState) should be updated
+    pub targets: Vec<Entity>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> SharedInputMap<A> {
+    /// Creates a new [`SharedInputMap`] that controls the provided `targets`
+    #[must_use]
+    pub fn new(targets: impl IntoIterator<Item = Entity>) -> Self {
+        SharedInputMap {
+            targets: targets.into_iter().collect(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A component that requests a connected gamepad for the [`InputMap`] on the same entity
+///
+/// Each [`InputMap`] without an associated gamepad is assigned the connected gamepad with the lowest id that is not already in use,
+/// with lower `slot`s chosen first.
+/// When the assigned gamepad disconnects, the association is cleared, so another gamepad can take its place.
+///
+/// Used in [`assign_gamepads`](crate::systems::assign_gamepads).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GamepadAssignment {
+    /// The player slot that this entity occupies, such as `0` for player one
+    pub slot: usize,
+}
+
+/// Builds a chord of a button and any number of [`ModifierKey`]s, to be inserted into an [`InputMap`]
+///
+/// Created by [`InputMap::bind_chord`].
+/// Nothing is bound until [`ChordBuilder::insert`] is called.
+#[derive(Debug)]
+#[must_use = "chords are only bound once `ChordBuilder::insert` is called"]
+pub struct ChordBuilder<'a, A: Actionlike> {
+    input_map: &'a mut InputMap<A>,
+    action: A,
+    button: InputButton,
+    modifiers: Vec<ModifierKey>,
+}
+
+impl<'a, A: Actionlike> ChordBuilder<'a, A> {
+    /// Requires the provided `modifier` to be held as part of the chord
+    pub fn with_modifier(mut self, modifier: ModifierKey) -> Self {
+        if !self.modifiers.contains(&modifier) {
+            self.modifiers.push(modifier);
+        }
+        self
+    }
+
+    /// Requires either Alt key to be held as part of the chord
+    pub fn with_alt(self) -> Self {
+        self.with_modifier(ModifierKey::Alt)
+    }
+
+    /// Requires either Control key to be held as part of the chord
+    pub fn with_ctrl(self) -> Self {
+        self.with_modifier(ModifierKey::Control)
+    }
+
+    /// Requires either Shift key to be held as part of the chord
+    pub fn with_shift(self) -> Self {
+        self.with_modifier(ModifierKey::Shift)
+    }
+
+    /// Requires either Super key to be held as part of the chord
+    pub fn with_super(self) -> Self {
+        self.with_modifier(ModifierKey::Super)
+    }
+
+    /// Inserts the chord into the [`InputMap`], returning the map so that further bindings can be chained
+    ///
+    /// If no modifiers were added, the button is bound on its own.
+    pub fn insert(self) -> &'a mut InputMap<A> {
+        let mut buttons: Vec<InputButton> = self
+            .modifiers
+            .into_iter()
+            .map(InputButton::Modifier)
+            .collect();
+        buttons.push(self.button);
+
+        self.input_map
+            .insert(self.action, UserInput::chord(buttons))
+    }
+}
+
 // Configuration
 impl<A: Actionlike> InputMap<A> {
     /// Fetches the [Gamepad] associated with the entity controlled by this entity map
@@ -275,6 +630,202 @@ impl<A: Actionlike> InputMap<A> {
         self.associated_gamepad = None;
         self
     }
+
+    /// Fetches the [`ControllerLayout`] used to resolve gamepad bindings
+    #[must_use]
+    pub fn controller_layout(&self) -> ControllerLayout {
+        self.controller_layout
+    }
+
+    /// Sets the [`ControllerLayout`] used to resolve gamepad bindings
+    ///
+    /// The default layout, [`ControllerLayout::Standard`], does not remap any buttons.
+    pub fn set_controller_layout(&mut self, controller_layout: ControllerLayout) -> &mut Self {
+        self.controller_layout = controller_layout;
+        self
+    }
+
+    /// Fetches the distance that the mouse must move in a single frame to press a [`UserInput::MouseMotion`]
+    #[must_use]
+    pub fn mouse_motion_threshold(&self) -> f32 {
+        self.mouse_motion_threshold
+    }
+
+    /// Sets the distance that the mouse must move in a single frame to press a [`UserInput::MouseMotion`]
+    ///
+    /// This is measured in the same units as [`MouseMotion`](bevy_input::mouse::MouseMotion) events.
+    /// The default threshold of `0.0` presses these inputs whenever the mouse moves at all in their direction.
+    pub fn set_mouse_motion_threshold(&mut self, threshold: f32) -> &mut Self {
+        self.mouse_motion_threshold = threshold;
+        self
+    }
+
+    /// Fetches the [`ClashStrategy`] used for this map instead of the global [`ClashStrategy`] resource, if any
+    #[must_use]
+    pub fn clash_strategy(&self) -> Option<ClashStrategy> {
+        self.clash_strategy
+    }
+
+    /// Sets the [`ClashStrategy`] used to resolve the clashing inputs of this map, overriding the global [`ClashStrategy`] resource
+    ///
+    /// This lets different entities or action types resolve clashes differently in the same app:
+    /// movement might use [`ClashStrategy::UseActionOrder`], while shortcuts use [`ClashStrategy::PrioritizeLongest`].
+    /// The override also takes precedence over the [`ActionPriority`](crate::clashing_inputs::ActionPriority) component of the entity,
+    /// and over any [`CustomClashResolver`](crate::clashing_inputs::CustomClashResolver).
+    /// [`Actionlike::ordering`] only decides the winner when the strategy is [`ClashStrategy::UseActionOrder`].
+    /// It is applied by [`update_action_state`](crate::systems::update_action_state);
+    /// methods such as [`InputMap::which_pressed`] use the strategy they are passed.
+    pub fn set_clash_strategy(&mut self, clash_strategy: ClashStrategy) -> &mut Self {
+        self.clash_strategy = Some(clash_strategy);
+        self
+    }
+
+    /// Removes the [`ClashStrategy`] override of this map, so that the global resource is used again
+    pub fn clear_clash_strategy(&mut self) -> &mut Self {
+        self.clash_strategy = None;
+        self
+    }
+
+    /// The [`ClashStrategy`] override of this map, or the `default` resolver if there is none
+    pub(crate) fn clash_resolver<'a>(
+        &'a self,
+        default: &'a dyn ClashResolver<A>,
+    ) -> &'a dyn ClashResolver<A> {
+        match &self.clash_strategy {
+            Some(clash_strategy) => clash_strategy,
+            None => default,
+        }
+    }
+
+    /// Fetches the [`DeadZone`] applied to the analog inputs of the `action`
+    #[must_use]
+    pub fn deadzone(&self, action: A) -> DeadZone {
+        self.deadzones[action.index()]
+    }
+
+    /// Sets the [`DeadZone`] applied to the analog inputs of the `action`
+    ///
+    /// Analog inputs are only pressed once they leave the dead zone.
+    /// By default, no dead zone is applied beyond the one configured in
+    /// [`GamepadSettings`](bevy_input::gamepad::GamepadSettings).
+    pub fn set_deadzone(&mut self, action: A, deadzone: DeadZone) -> &mut Self {
+        self.deadzones[action.index()] = deadzone;
+        self
+    }
+
+    /// Fetches the [`AxisThresholds`] of the `action`, if any have been set
+    #[must_use]
+    pub fn axis_thresholds(&self, action: A) -> Option<AxisThresholds> {
+        self.axis_thresholds[action.index()]
+    }
+
+    /// Sets the magnitudes at which the analog value of the `action` presses and releases it
+    ///
+    /// The `action` is pressed once its value reaches `press`, and stays pressed until it falls to `release`,
+    /// which is capped at `press`.
+    /// This is applied by [`update_action_state`](crate::systems::update_action_state),
+    /// since it depends on whether the action was already pressed.
+    /// Use [`InputMap::clear_axis_thresholds`] to restore the default behavior,
+    /// where any value outside of the [`DeadZone`] presses the action.
+    ///
+    /// Thresholds apply to the combined value of the `action`, and so are shared by all of its analog bindings.
+    /// There is no default threshold of 0.5: actions without thresholds keep the behavior described above,
+    /// so that existing analog bindings are unaffected.
+    pub fn set_axis_thresholds(&mut self, action: A, press: f32, release: f32) -> &mut Self {
+        self.axis_thresholds[action.index()] = Some(AxisThresholds::new(press, release));
+        self
+    }
+
+    /// Removes the [`AxisThresholds`] of the `action`
+    pub fn clear_axis_thresholds(&mut self, action: A) -> &mut Self {
+        self.axis_thresholds[action.index()] = None;
+        self
+    }
+
+    /// Releases the actions in `action_data` whose values do not satisfy their [`AxisThresholds`]
+    ///
+    /// Whether each action was previously pressed is read from the `action_state`.
+    pub(crate) fn apply_axis_thresholds(
+        &self,
+        action_data: &mut [ActionData],
+        action_state: &ActionState<A>,
+    ) {
+        for action in A::variants() {
+            let thresholds = match self.axis_thresholds[action.index()] {
+                Some(thresholds) => thresholds,
+                None => continue,
+            };
+
+            let action_data = &mut action_data[action.index()];
+            let magnitude = action_data.axis_data.value.abs();
+            if action_data.state.pressed()
+                && !thresholds.pressed(magnitude, action_state.pressed(action))
+            {
+                action_data.state = ButtonState::Released;
+                action_data.reasons_pressed.clear();
+                action_data.axis_data = AxisData::default();
+            }
+        }
+    }
+
+    /// Fetches the length of the cooldown of the `action`, if one has been set
+    #[must_use]
+    pub fn cooldown(&self, action: A) -> Option<Duration> {
+        self.cooldowns[action.index()]
+    }
+
+    /// Sets the length of the [`Cooldown`](crate::action_state::Cooldown) of the `action`
+    ///
+    /// This is copied to each [`ActionState`] driven by this map by [`update_action_state`](crate::systems::update_action_state),
+    /// where [`ActionState::trigger`] starts the cooldown and [`ActionState::ready`] reports whether it has finished.
+    /// As the map is the source of truth for this `action`, cooldowns set directly on those [`ActionState`]s are replaced.
+    /// Cooldowns that [`ActionState::set_cooldown`] sets for actions without a cooldown in this map are kept.
+    pub fn set_cooldown(&mut self, action: A, duration: Duration) -> &mut Self {
+        self.cooldowns[action.index()] = Some(duration);
+        self
+    }
+
+    /// Removes the cooldown of the `action`
+    ///
+    /// The [`Cooldown`](crate::action_state::Cooldown) copied from this map is also removed from each [`ActionState`]
+    /// driven by it the next time it is updated.
+    pub fn clear_cooldown(&mut self, action: A) -> &mut Self {
+        self.cooldowns[action.index()] = None;
+        self
+    }
+
+    /// Copies the cooldowns set on this map to the `action_state`,
+    /// removing any cooldowns that were copied from this map but have since been cleared
+    pub(crate) fn apply_cooldowns(&self, action_state: &mut ActionState<A>) {
+        for action in A::variants() {
+            let duration = self.cooldowns[action.index()];
+            action_state.set_map_cooldown(action, duration);
+        }
+    }
+
+    /// Is the `axis_type` inverted, so that its value is negated before it is used by any binding?
+    #[must_use]
+    pub fn axis_inverted(&self, axis_type: GamepadAxisType) -> bool {
+        self.inverted_axes.contains(&axis_type)
+    }
+
+    /// Sets whether the `axis_type` is inverted, negating its value for every [`UserInput::SingleAxis`],
+    /// [`UserInput::HalfAxis`] and [`UserInput::DualAxis`] in this map
+    ///
+    /// This is intended for the "invert look" toggles found in options menus:
+    /// inverting [`GamepadAxisType::RightStickY`] flips vertical camera control,
+    /// without changing any bindings.
+    pub fn set_axis_inverted(&mut self, axis_type: GamepadAxisType, inverted: bool) -> &mut Self {
+        if inverted {
+            if !self.axis_inverted(axis_type) {
+                self.inverted_axes.push(axis_type);
+            }
+        } else {
+            self.inverted_axes
+                .retain(|&inverted_axis| inverted_axis != axis_type);
+        }
+        self
+    }
 }
 
 // Check whether buttons are pressed
@@ -296,6 +847,7 @@ impl<A: Actionlike> InputMap<A> {
 
     /// Returns the actions that are currently pressed, and the responsible [`UserInput`] for each action
     ///
+    /// The analog value of each action is taken from whichever of its pressed inputs has the largest magnitude.
     /// Accounts for clashing inputs according to the [`ClashStrategy`].
     /// The position in each vector corresponds to `Actionlike::index()`.
     #[must_use]
@@ -304,18 +856,87 @@ impl<A: Actionlike> InputMap<A> {
         input_streams: &InputStreams,
         clash_strategy: ClashStrategy,
     ) -> Vec<ActionData> {
+        self.which_pressed_with(input_streams, &clash_strategy)
+    }
+
+    /// Returns the actions that are currently pressed, resolving clashing inputs using a custom [`ClashResolver`]
+    ///
+    /// Otherwise identical to [`InputMap::which_pressed`].
+    #[must_use]
+    pub fn which_pressed_with(
+        &self,
+        input_streams: &InputStreams,
+        clash_resolver: &dyn ClashResolver<A>,
+    ) -> Vec<ActionData> {
+        let mut action_data = self.which_pressed_unresolved(input_streams);
+
+        // Handle clashing inputs, possibly removing some pressed actions from the list
+        self.handle_clashes_with(&mut action_data, input_streams, clash_resolver);
+
+        action_data
+    }
+
+    /// Returns the actions that are currently pressed, before any clashing inputs are resolved
+    pub(crate) fn which_pressed_unresolved(&self, input_streams: &InputStreams) -> Vec<ActionData> {
         let mut action_data = vec![ActionData::default(); A::N_VARIANTS];
 
         // Generate the raw action presses
         for action in A::variants() {
             let mut inputs = Vec::new();
 
-            for input in self.get(action.clone()).iter() {
-                if input_streams.input_pressed(input) {
+            for input in self.enabled_inputs(action.clone()) {
+                let options = self.binding_options(action.clone(), input.clone());
+                // Inputs are pressed exactly when they have a value
+                let mut value = self.input_value(action.clone(), input, input_streams);
+
+                // Chords with a window only count if they were pressed quickly enough
+                if let UserInput::Chord(buttons) = input {
+                    if let Some(within) = options.chord_window {
+                        if !input_streams.pressed_within(buttons, within) {
+                            value = 0.0;
+                        }
+                    }
+
+                    // And shortcuts only count if their modifiers were held first
+                    if options.shortcut && !input_streams.modifiers_pressed_first(buttons) {
+                        value = 0.0;
+                    }
+                }
+
+                // So that shortcuts like Ctrl + S do not also trigger S
+                if options.exclude_modifiers && input_streams.other_modifier_pressed(input) {
+                    value = 0.0;
+                }
+
+                if value != 0.0 {
                     inputs.push(input.clone());
                     action_data[action.index()]
                         .reasons_pressed
                         .push(input.clone());
+
+                    // The input with the largest magnitude determines the value of the action
+                    let axis_data = &mut action_data[action.index()].axis_data;
+                    if value.abs() > axis_data.value.abs() {
+                        axis_data.value = value;
+                    }
+
+                    if let UserInput::MouseWheel { direction } = input {
+                        axis_data.mouse_wheel += input_streams.mouse_wheel_distance(*direction);
+                    }
+
+                    if let UserInput::MouseMotion { direction } = input {
+                        axis_data.mouse_motion +=
+                            direction.distance(input_streams.mouse_motion()).max(0.0);
+                    }
+
+                    if let UserInput::DualAxis { x, y } = input {
+                        let axis_pair = self.axis_pair(action.clone(), *x, *y, input_streams);
+                        axis_data.merge_axis_pair(axis_pair);
+                    }
+                } else if input_streams.input_partially_pressed(input) {
+                    action_data[action.index()]
+                        .partial_chords
+                        .push(input.clone());
                 }
             }
 
@@ -324,32 +945,221 @@ impl<A: Actionlike> InputMap<A> {
             }
         }
 
-        // Handle clashing inputs, possibly removing some pressed actions from the list
-        self.handle_clashes(&mut action_data, input_streams, clash_strategy);
-
         action_data
     }
-}
-
-// Utilities
-impl<A: Actionlike> InputMap<A> {
-    /// Returns an iterator over actions with their inputs
-    pub fn iter(&self) -> impl Iterator<Item = (A, &PetitSet<UserInput, 16>)> {
-        self.map
-            .iter()
-            .enumerate()
-            .map(|(action_index, inputs)| (A::get_at(action_index).unwrap(), inputs))
-    }
 
-    /// Returns an iterator over all mapped inputs
-    pub fn iter_inputs(&self) -> impl Iterator<Item = &PetitSet<UserInput, 16>> {
-        self.map.iter()
+    /// The value of the `input` bound to `action`, after applying the [`DeadZone`] of the `action` to analog inputs
+    ///
+    /// A [`UserInput::DualAxis`] reports its magnitude.
+    fn input_value(&self, action: A, input: &UserInput, input_streams: &InputStreams) -> f32 {
+        match input {
+            UserInput::SingleAxis(axis_type) => {
+                self.deadzones[action.index()].apply(self.axis_value(*axis_type, input_streams))
+            }
+            UserInput::HalfAxis {
+                axis_type,
+                inverted,
+            } => {
+                let value = self.deadzones[action.index()]
+                    .apply(self.axis_value(*axis_type, input_streams));
+                let value = if *inverted { -value } else { value };
+                value.max(0.0)
+            }
+            UserInput::DualAxis { x, y } => {
+                self.axis_pair(action, *x, *y, input_streams).magnitude()
+            }
+            _ => input_streams.input_value(input),
+        }
     }
 
-    /// Returns the `action` mappings
-    #[must_use]
-    pub fn get(&self, action: A) -> &PetitSet<UserInput, 16> {
-        &self.map[action.index()]
+    /// The value of the `axis_type`, negated if it is [inverted](InputMap::set_axis_inverted)
+    fn axis_value(&self, axis_type: GamepadAxisType, input_streams: &InputStreams) -> f32 {
+        let value = input_streams.axis_value(axis_type);
+        if self.axis_inverted(axis_type) {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// The values of the `x` and `y` axes bound to `action`, after applying the [`DeadZone`] of the `action`
+    fn axis_pair(
+        &self,
+        action: A,
+        x: GamepadAxisType,
+        y: GamepadAxisType,
+        input_streams: &InputStreams,
+    ) -> DualAxisData {
+        let xy = Vec2::new(
+            self.axis_value(x, input_streams),
+            self.axis_value(y, input_streams),
+        );
+        DualAxisData::new(self.deadzones[action.index()].apply_pair(xy))
+    }
+
+    /// Returns the actions that are currently pressed in any of the `input_streams`, aggregating several devices into one
+    ///
+    /// Each of the `input_streams` is resolved independently by [`InputMap::which_pressed`],
+    /// so chords and clashes are only considered within a single device,
+    /// and the results are then combined:
+    /// - an action is pressed if it is pressed by any of the `input_streams`
+    /// - the analog value of each action is taken from the stream where its magnitude is largest,
+    ///   as are the values of its dual-axis inputs and the distance scrolled by its mouse wheel inputs
+    /// - the `reasons_pressed` and `partial_chords` of each action are the union of those from each stream,
+    ///   where chords that are fully pressed by one device are not reported as partially pressed
+    ///
+    /// This is useful for arcade cabinets and other setups that wire several physical devices to a single player.
+    /// The [`associated_gamepad`](InputStreams::associated_gamepad) of each stream is respected,
+    /// so several gamepads can be aggregated by passing one stream for each of them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_input::{gamepad::{Gamepad, GamepadButton, GamepadButtonType}, Input};
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::user_input::InputStreams;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    /// }
+    ///
+    /// let input_map = InputMap::new([(Action::Jump, GamepadButtonType::South)]);
+    ///
+    /// let mut gamepad_buttons = Input::<GamepadButton>::default();
+    /// gamepad_buttons.press(GamepadButton(Gamepad(1), GamepadButtonType::South));
+    ///
+    /// let input_streams = [
+    ///     InputStreams::from_gamepad(&gamepad_buttons, Gamepad(0)),
+    ///     InputStreams::from_gamepad(&gamepad_buttons, Gamepad(1)),
+    /// ];
+    ///
+    /// let action_data = input_map.which_pressed_any(&input_streams, ClashStrategy::PrioritizeLongest);
+    /// assert!(action_data[Action::Jump.index()].state.pressed());
+    /// ```
+    #[must_use]
+    pub fn which_pressed_any(
+        &self,
+        input_streams: &[InputStreams],
+        clash_strategy: ClashStrategy,
+    ) -> Vec<ActionData> {
+        let mut action_data = vec![ActionData::default(); A::N_VARIANTS];
+
+        for input_streams in input_streams {
+            let stream_data = self.which_pressed(input_streams, clash_strategy);
+
+            for (combined, stream) in action_data.iter_mut().zip(stream_data) {
+                if stream.state.pressed() {
+                    combined.state = ButtonState::JustPressed;
+                }
+
+                if stream.axis_data.value.abs() > combined.axis_data.value.abs() {
+                    combined.axis_data.value = stream.axis_data.value;
+                }
+
+                if let Some(axis_pair) = stream.axis_data.axis_pair {
+                    combined.axis_data.merge_axis_pair(axis_pair);
+                }
+
+                combined.axis_data.mouse_wheel = combined
+                    .axis_data
+                    .mouse_wheel
+                    .max(stream.axis_data.mouse_wheel);
+                combined.axis_data.mouse_motion = combined
+                    .axis_data
+                    .mouse_motion
+                    .max(stream.axis_data.mouse_motion);
+
+                for reason in stream.reasons_pressed {
+                    if !combined.reasons_pressed.contains(&reason) {
+                        combined.reasons_pressed.push(reason);
+                    }
+                }
+
+                for chord in stream.partial_chords {
+                    if !combined.partial_chords.contains(&chord) {
+                        combined.partial_chords.push(chord);
+                    }
+                }
+            }
+        }
+
+        for action_data in action_data.iter_mut() {
+            let reasons_pressed = &action_data.reasons_pressed;
+            action_data
+                .partial_chords
+                .retain(|chord| !reasons_pressed.contains(chord));
+        }
+
+        action_data
+    }
+}
+
+// Utilities
+impl<A: Actionlike> InputMap<A> {
+    /// Returns an iterator over actions with their inputs
+    pub fn iter(&self) -> impl Iterator<Item = (A, &PetitSet<UserInput, 16>)> {
+        self.map
+            .iter()
+            .enumerate()
+            .map(|(action_index, inputs)| (A::get_at(action_index).unwrap(), inputs))
+    }
+
+    /// Returns an iterator over every binding, as pairs of an action and one of its inputs
+    ///
+    /// Actions are yielded in the order of [`Actionlike::variants`], and each chord is yielded as a single [`UserInput::Chord`].
+    /// Disabled bindings are included: check them with [`InputMap::binding_enabled`].
+    /// This is useful for displaying a full list of controls.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::prelude::*;
+    /// use bevy_input::keyboard::KeyCode;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Jump,
+    ///     Save,
+    /// }
+    ///
+    /// let mut input_map = InputMap::new([(Action::Jump, KeyCode::Space), (Action::Jump, KeyCode::W)]);
+    /// input_map.insert_chord(Action::Save, [KeyCode::LControl, KeyCode::S]);
+    ///
+    /// let bindings: Vec<(Action, String)> = input_map
+    ///     .iter_bindings()
+    ///     .map(|(action, input)| (action, input.to_string()))
+    ///     .collect();
+    ///
+    /// assert_eq!(bindings.len(), 3);
+    /// assert_eq!(bindings[2].0, Action::Save);
+    /// ```
+    pub fn iter_bindings(&self) -> impl Iterator<Item = (A, &UserInput)> {
+        self.iter()
+            .flat_map(|(action, inputs)| inputs.iter().map(move |input| (action.clone(), input)))
+    }
+
+    /// Returns an iterator over all mapped inputs
+    pub fn iter_inputs(&self) -> impl Iterator<Item = &PetitSet<UserInput, 16>> {
+        self.map.iter()
+    }
+
+    /// Returns the `action` mappings
+    ///
+    /// Chords are stored as a single [`UserInput::Chord`] entry.
+    #[must_use]
+    pub fn get(&self, action: A) -> &PetitSet<UserInput, 16> {
+        &self.map[action.index()]
+    }
+
+    /// Returns an iterator over the `action` mappings that are currently enabled
+    ///
+    /// Bindings can be disabled using [`InputMap::set_binding_enabled`].
+    pub fn enabled_inputs(&self, action: A) -> impl Iterator<Item = &UserInput> {
+        let binding_options = &self.binding_options[action.index()];
+        self.map[action.index()].iter().filter(move |input| {
+            !binding_options
+                .iter()
+                .any(|(bound, options)| bound == *input && options.disabled)
+        })
     }
 
     /// How many input bindings are registered total?
@@ -375,23 +1185,389 @@ impl<A: Actionlike> InputMap<A> {
     /// Clears all inputs registered for the `action`
     pub fn clear_action(&mut self, action: A) {
         self.map[action.index()].clear();
+        self.binding_options[action.index()].clear();
     }
 
     /// Removes the input for the `action` at the provided index
     ///
     /// Returns `true` if an element was found.
     pub fn remove_at(&mut self, action: A, index: usize) -> bool {
-        self.map[action.index()].remove_at(index)
+        match self.map[action.index()].take_at(index) {
+            Some(input) => {
+                self.binding_options[action.index()].retain(|(bound, _)| *bound != input);
+                true
+            }
+            None => false,
+        }
     }
 
     /// Removes the input for the `action`, if it exists
     ///
     /// Returns [`Some`] with index if the input was found, or [`None`] if no matching input was found.
+    /// Other actions bound to the same input are unaffected.
     pub fn remove(&mut self, action: A, input: impl Into<UserInput>) -> Option<usize> {
-        self.map[action.index()].remove(&input.into())
+        let input = input.into();
+        self.binding_options[action.index()].retain(|(bound, _)| *bound != input);
+        self.map[action.index()].remove(&input)
     }
 }
 
+// Binding options
+impl<A: Actionlike> InputMap<A> {
+    /// The [`BindingOptions`] of the `input` binding for the `action`
+    ///
+    /// Returns the default options if the `input` is not bound to the `action`.
+    #[must_use]
+    pub fn binding_options(&self, action: A, input: impl Into<UserInput>) -> BindingOptions {
+        let input = input.into();
+        self.binding_options[action.index()]
+            .iter()
+            .find(|(bound, _)| *bound == input)
+            .map(|(_, options)| *options)
+            .unwrap_or_default()
+    }
+
+    /// Replaces the [`BindingOptions`] of the `input` binding for the `action`
+    ///
+    /// Returns `false` if the `input` is not bound to the `action`.
+    pub fn set_binding_options(
+        &mut self,
+        action: A,
+        input: impl Into<UserInput>,
+        options: BindingOptions,
+    ) -> bool {
+        let input = input.into();
+        if !self.map[action.index()].contains(&input) {
+            return false;
+        }
+
+        let binding_options = &mut self.binding_options[action.index()];
+        binding_options.retain(|(bound, _)| *bound != input);
+        // Only bindings with non-default options are stored
+        if options != BindingOptions::default() {
+            binding_options.push((input, options));
+        }
+        true
+    }
+
+    /// Enables or disables the `input` binding for the `action`, without removing it
+    ///
+    /// Disabled bindings are ignored when checking which actions are pressed, and cannot cause clashes.
+    /// This is useful for accessibility presets, which can disable a set of bindings and later restore them.
+    ///
+    /// Returns `false` if the `input` is not bound to the `action`.
+    pub fn set_binding_enabled(
+        &mut self,
+        action: A,
+        input: impl Into<UserInput>,
+        enabled: bool,
+    ) -> bool {
+        let input = input.into();
+        let mut options = self.binding_options(action.clone(), input.clone());
+        options.disabled = !enabled;
+        self.set_binding_options(action, input, options)
+    }
+
+    /// Is the `input` binding for the `action` enabled?
+    ///
+    /// Returns `false` if the `input` is not bound to the `action`.
+    #[must_use]
+    pub fn binding_enabled(&self, action: A, input: impl Into<UserInput>) -> bool {
+        let input = input.into();
+        self.map[action.index()].contains(&input) && !self.binding_options(action, input).disabled
+    }
+
+    /// Re-enables every disabled binding
+    pub fn enable_all_bindings(&mut self) {
+        for binding_options in self.binding_options.iter_mut() {
+            binding_options
+                .iter_mut()
+                .for_each(|(_, options)| options.disabled = false);
+            binding_options.retain(|(_, options)| *options != BindingOptions::default());
+        }
+    }
+}
+
+// Excluding modifiers
+impl<A: Actionlike> InputMap<A> {
+    /// Inserts a binding between `action` and `input` that is ignored while any [`ModifierKey`] is held,
+    /// other than those that are part of the `input` itself
+    ///
+    /// This stops plain keys from firing as part of a shortcut: with `S` bound this way,
+    /// pressing Ctrl + S does not press the action.
+    /// Unlike the [`ClashStrategy`], this applies even when no shortcut is bound to Ctrl + S.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_input::{keyboard::KeyCode, Input};
+    /// use leafwing_input_manager::prelude::*;
+    /// use leafwing_input_manager::user_input::InputStreams;
+    ///
+    /// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     MoveDown,
+    /// }
+    ///
+    /// let mut input_map = InputMap::<Action>::default();
+    /// input_map.insert_excluding_modifiers(Action::MoveDown, KeyCode::S);
+    ///
+    /// let mut keyboard = Input::<KeyCode>::default();
+    /// keyboard.press(KeyCode::S);
+    /// let input_streams = InputStreams::from_keyboard(&keyboard);
+    /// assert!(input_map.pressed(Action::MoveDown, &input_streams, ClashStrategy::PressAll));
+    ///
+    /// keyboard.press(KeyCode::LControl);
+    /// let input_streams = InputStreams::from_keyboard(&keyboard);
+    /// assert!(!input_map.pressed(Action::MoveDown, &input_streams, ClashStrategy::PressAll));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map is full and `input` is not a duplicate.
+    pub fn insert_excluding_modifiers(
+        &mut self,
+        action: A,
+        input: impl Into<UserInput>,
+    ) -> &mut Self {
+        let input = input.into();
+        self.insert(action.clone(), input.clone());
+        self.set_exclude_modifiers(action, input, true);
+        self
+    }
+
+    /// Sets whether the `input` binding for the `action` is ignored while an unrelated [`ModifierKey`] is held
+    ///
+    /// See [`InputMap::insert_excluding_modifiers`].
+    /// Returns `false` if the `input` is not bound to the `action`.
+    pub fn set_exclude_modifiers(
+        &mut self,
+        action: A,
+        input: impl Into<UserInput>,
+        exclude_modifiers: bool,
+    ) -> bool {
+        let input = input.into();
+        let mut options = self.binding_options(action.clone(), input.clone());
+        options.exclude_modifiers = exclude_modifiers;
+        self.set_binding_options(action, input, options)
+    }
+
+    /// Is the `input` binding for the `action` ignored while an unrelated [`ModifierKey`] is held?
+    ///
+    /// Returns `false` if the `input` is not bound to the `action`.
+    #[must_use]
+    pub fn excludes_modifiers(&self, action: A, input: impl Into<UserInput>) -> bool {
+        self.binding_options(action, input).exclude_modifiers
+    }
+
+    /// Inserts a chord of `buttons` for the `action` that behaves like a typical keyboard shortcut
+    ///
+    /// The buttons of the chord that are not [`ModifierKey`]s must be pressed after (or at the same time as) its modifiers.
+    /// Holding `Ctrl` and tapping `S` presses Ctrl + S on every tap, but holding `S` and then pressing `Ctrl` does not.
+    /// This relies on the [`ButtonPressTimes`](crate::user_input::ButtonPressTimes) resource,
+    /// and the order is ignored if it does not exist.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map is full and the chord is not a duplicate.
+    pub fn insert_shortcut(
+        &mut self,
+        action: A,
+        buttons: impl IntoIterator<Item = impl Into<InputButton>>,
+    ) -> &mut Self {
+        let input = UserInput::chord(buttons);
+        self.insert(action.clone(), input.clone());
+        self.set_shortcut(action, input, true);
+        self
+    }
+
+    /// Sets whether the non-modifier buttons of the chord `input` bound to the `action` must be pressed after its modifiers
+    ///
+    /// See [`InputMap::insert_shortcut`].
+    /// Returns `false` if the `input` is not a chord bound to the `action`.
+    pub fn set_shortcut(&mut self, action: A, input: impl Into<UserInput>, shortcut: bool) -> bool {
+        let input = input.into();
+        if !matches!(input, UserInput::Chord(_)) {
+            return false;
+        }
+
+        let mut options = self.binding_options(action.clone(), input.clone());
+        options.shortcut = shortcut;
+        self.set_binding_options(action, input, options)
+    }
+
+    /// Must the non-modifier buttons of the `input` binding for the `action` be pressed after its modifiers?
+    ///
+    /// Returns `false` if the `input` is not bound to the `action`.
+    #[must_use]
+    pub fn is_shortcut(&self, action: A, input: impl Into<UserInput>) -> bool {
+        self.binding_options(action, input).shortcut
+    }
+}
+
+// Overrides
+impl<A: Actionlike> InputMap<A> {
+    /// Collects the settings of this [`InputMap`] that differ from the provided `defaults`
+    ///
+    /// The returned [`InputMapOverrides`] can be serialized in place of the full [`InputMap`],
+    /// producing compact save data.
+    /// Actions that were never customized (as reported by [`InputMap::changed_actions`]) are left out,
+    /// so they will pick up any changes made to the default bindings when loaded with [`InputMap::apply_overrides`].
+    ///
+    /// Each customized action stores all of its settings: its bindings and their [`BindingOptions`],
+    /// its [`DeadZone`], [`AxisThresholds`] and cooldown.
+    /// The inverted axes are stored if they differ from the `defaults`.
+    /// The associated gamepad, controller layout, mouse motion threshold and [`ClashStrategy`] are not stored.
+    #[must_use]
+    pub fn serialize_overrides(&self, defaults: &InputMap<A>) -> InputMapOverrides<A> {
+        let overrides = self
+            .changed_actions(defaults)
+            .into_iter()
+            .map(|action| {
+                let i = action.index();
+                let action_overrides = ActionOverrides {
+                    bindings: self.map[i].clone(),
+                    binding_options: self.binding_options[i].clone(),
+                    deadzone: self.deadzones[i],
+                    axis_thresholds: self.axis_thresholds[i],
+                    cooldown: self.cooldowns[i],
+                };
+                (i, action_overrides)
+            })
+            .collect();
+
+        let inverted_axes_changed = self.inverted_axes.len() != defaults.inverted_axes.len()
+            || self
+                .inverted_axes
+                .iter()
+                .any(|axis_type| !defaults.axis_inverted(*axis_type));
+
+        InputMapOverrides {
+            overrides,
+            inverted_axes: inverted_axes_changed.then(|| self.inverted_axes.clone()),
+            marker: PhantomData,
+        }
+    }
+
+    /// Replaces the settings of each action customized in `overrides` with the stored settings
+    ///
+    /// Call this on a fresh copy of the default bindings after loading saved [`InputMapOverrides`].
+    /// Overrides stored for actions that no longer exist (because the action enum has fewer variants than it used to)
+    /// are ignored.
+    pub fn apply_overrides(&mut self, overrides: &InputMapOverrides<A>) -> &mut Self {
+        for (&index, action_overrides) in overrides.overrides.iter() {
+            if let Some(action) = A::get_at(index) {
+                let i = action.index();
+                self.map[i] = action_overrides.bindings.clone();
+                self.binding_options[i] = action_overrides.binding_options.clone();
+                self.deadzones[i] = action_overrides.deadzone;
+                self.axis_thresholds[i] = action_overrides.axis_thresholds;
+                self.cooldowns[i] = action_overrides.cooldown;
+            }
+        }
+
+        if let Some(inverted_axes) = &overrides.inverted_axes {
+            self.inverted_axes = inverted_axes.clone();
+        }
+
+        self
+    }
+}
+
+// Change detection
+impl<A: Actionlike> InputMap<A> {
+    /// Returns the actions whose configuration differs between `self` and `previous`, in order of their [index](Actionlike::index)
+    ///
+    /// An action has changed if its bindings, their [`BindingOptions`], its [`DeadZone`],
+    /// its [`AxisThresholds`] or its cooldown differ.
+    /// Settings that apply to every action, such as the associated gamepad, are not attributed to any action.
+    #[must_use]
+    pub fn changed_actions(&self, previous: &InputMap<A>) -> Vec<A> {
+        A::variants()
+            .filter(|action| {
+                let i = action.index();
+                let bindings =
+                    |input_map: &InputMap<A>| input_map.map[i].iter().cloned().collect::<Vec<_>>();
+                // The order in which options were set does not matter
+                let binding_options_eq = self.binding_options[i].len()
+                    == previous.binding_options[i].len()
+                    && self.binding_options[i]
+                        .iter()
+                        .all(|entry| previous.binding_options[i].contains(entry));
+
+                bindings(self) != bindings(previous)
+                    || !binding_options_eq
+                    || self.deadzones[i] != previous.deadzones[i]
+                    || self.axis_thresholds[i] != previous.axis_thresholds[i]
+                    || self.cooldowns[i] != previous.cooldowns[i]
+            })
+            .collect()
+    }
+
+    /// Do `self` and `other` have the same settings for every action, such as the associated gamepad?
+    ///
+    /// Unlike [`PartialEq`], this does not require `A: PartialEq`.
+    pub(crate) fn shared_settings_eq(&self, other: &InputMap<A>) -> bool {
+        self.inverted_axes == other.inverted_axes
+            && self.associated_gamepad == other.associated_gamepad
+            && self.controller_layout == other.controller_layout
+            && self.mouse_motion_threshold == other.mouse_motion_threshold
+            && self.clash_strategy == other.clash_strategy
+    }
+}
+
+/// An event sent whenever an [`InputMap`] is modified, for UI that mirrors the current controls
+///
+/// Sent by the [`detect_input_map_changes`](crate::systems::detect_input_map_changes) system,
+/// which compares each changed [`InputMap`] to its state when it was last checked.
+/// Newly added maps are compared to an empty [`InputMap`], so one event is sent for each map when it is first seen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputMapChanged<A: Actionlike> {
+    /// The entity with the [`InputMap`] component that changed, or `None` if the [`InputMap`] resource changed
+    pub entity: Option<Entity>,
+    /// The actions whose configuration changed, as determined by [`InputMap::changed_actions`]
+    ///
+    /// This is empty if only settings that apply to every action changed, such as the associated gamepad.
+    pub actions: Vec<A>,
+}
+
+/// The settings of an [`InputMap`] that differ from a set of default settings
+///
+/// Created by [`InputMap::serialize_overrides`], and loaded using [`InputMap::apply_overrides`].
+/// Actions are stored by their [index](Actionlike::index),
+/// so variants should only ever be appended to the end of the action enum if old overrides are to remain valid.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputMapOverrides<A: Actionlike> {
+    overrides: BTreeMap<usize, ActionOverrides>,
+    /// The inverted axes, if they differ from the defaults
+    inverted_axes: Option<Vec<GamepadAxisType>>,
+    #[serde(skip)]
+    marker: PhantomData<A>,
+}
+
+impl<A: Actionlike> InputMapOverrides<A> {
+    /// The number of customized actions
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.overrides.len()
+    }
+
+    /// Are there no customized actions or inverted axes?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty() && self.inverted_axes.is_none()
+    }
+}
+
+/// The complete settings of a single customized action, stored in [`InputMapOverrides`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ActionOverrides {
+    bindings: PetitSet<UserInput, 16>,
+    binding_options: Vec<(UserInput, BindingOptions)>,
+    deadzone: DeadZone,
+    axis_thresholds: Option<AxisThresholds>,
+    cooldown: Option<Duration>,
+}
+
 mod tests {
     use crate as leafwing_input_manager;
     use crate::prelude::*;
@@ -447,6 +1623,44 @@ mod tests {
         assert_eq!(input_map_1, input_map_2);
     }
 
+    #[test]
+    fn one_to_many_insertion() {
+        use bevy_input::keyboard::KeyCode;
+        use KeyCode::*;
+
+        let keys = [
+            Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9, Key0, A, B, C, D, E, F,
+        ];
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert_one_to_many(Action::Run, keys);
+        assert_eq!(input_map.get(Action::Run).len(), 16);
+        assert_eq!(input_map.len(), 16);
+
+        // Duplicates can still be inserted into a full action
+        input_map.insert_one_to_many(Action::Run, [Key1, F]);
+        assert_eq!(input_map.get(Action::Run).len(), 16);
+
+        // And each action has its own capacity
+        input_map.insert_one_to_many(Action::Jump, keys);
+        assert_eq!(input_map.get(Action::Jump).len(), 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn one_to_many_insertion_into_full_action() {
+        use bevy_input::keyboard::KeyCode;
+        use KeyCode::*;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert_one_to_many(
+            Action::Run,
+            [
+                Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9, Key0, A, B, C, D, E, F,
+            ],
+        );
+        input_map.insert_one_to_many(Action::Run, [G]);
+    }
+
     #[test]
     fn chord_singleton_coercion() {
         use crate::input_map::UserInput;
@@ -459,74 +1673,306 @@ mod tests {
         let mut input_map_2 = InputMap::<Action>::default();
         input_map_2.insert(Action::Run, UserInput::chord([KeyCode::Space]));
 
-        assert_eq!(input_map_1, input_map_2);
+        assert_eq!(input_map_1, input_map_2);
+    }
+
+    #[test]
+    fn input_clearing() {
+        use bevy_input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert(Action::Run, KeyCode::Space);
+
+        // Clearing action
+        input_map.clear_action(Action::Run);
+        assert_eq!(input_map, InputMap::default());
+
+        // Remove input at existing index
+        input_map.insert(Action::Run, KeyCode::Space);
+        input_map.insert(Action::Run, KeyCode::LShift);
+        assert!(input_map.remove_at(Action::Run, 1));
+        assert!(
+            !input_map.remove_at(Action::Run, 1),
+            "Should return false on second removal at the same index"
+        );
+        assert!(input_map.remove_at(Action::Run, 0));
+        assert!(
+            !input_map.remove_at(Action::Run, 0),
+            "Should return false on second removal at the same index"
+        );
+    }
+
+    #[test]
+    fn removing_shared_input() {
+        use crate::user_input::UserInput;
+        use bevy_input::keyboard::KeyCode;
+        use petitset::PetitSet;
+
+        let mut input_map = InputMap::<Action>::new([
+            (Action::Run, KeyCode::Space),
+            (Action::Run, KeyCode::LShift),
+            (Action::Jump, KeyCode::Space),
+        ]);
+
+        assert_eq!(input_map.remove(Action::Run, KeyCode::Space), Some(0));
+        assert_eq!(input_map.remove(Action::Run, KeyCode::Space), None);
+        assert_eq!(
+            *input_map.get(Action::Run),
+            PetitSet::<UserInput, 16>::from_iter([KeyCode::LShift.into()])
+        );
+        assert_eq!(
+            *input_map.get(Action::Jump),
+            PetitSet::<UserInput, 16>::from_iter([KeyCode::Space.into()])
+        );
+    }
+
+    #[test]
+    fn merging() {
+        use crate::user_input::UserInput;
+        use bevy_input::{gamepad::GamepadButtonType, keyboard::KeyCode};
+        use petitset::PetitSet;
+
+        let mut input_map = InputMap::default();
+        let mut default_keyboard_map = InputMap::default();
+        default_keyboard_map.insert(Action::Run, KeyCode::LShift);
+        default_keyboard_map.insert_chord(Action::Hide, [KeyCode::LControl, KeyCode::H]);
+        let mut default_gamepad_map = InputMap::default();
+        default_gamepad_map.insert(Action::Run, GamepadButtonType::South);
+        default_gamepad_map.insert(Action::Hide, GamepadButtonType::East);
+
+        // Merging works
+        input_map.merge(&default_keyboard_map);
+        assert_eq!(input_map, default_keyboard_map);
+
+        // Merging is idempotent
+        input_map.merge(&default_keyboard_map);
+        assert_eq!(input_map, default_keyboard_map);
+
+        // Bindings are unioned per action
+        let mut vehicle_map = InputMap::default();
+        vehicle_map.insert(Action::Run, KeyCode::LShift);
+        vehicle_map.insert(Action::Jump, KeyCode::Space);
+        input_map.merge(&default_gamepad_map).merge(&vehicle_map);
+
+        assert_eq!(
+            *input_map.get(Action::Run),
+            PetitSet::<UserInput, 16>::from_iter([
+                KeyCode::LShift.into(),
+                GamepadButtonType::South.into()
+            ])
+        );
+        assert_eq!(
+            *input_map.get(Action::Hide),
+            PetitSet::<UserInput, 16>::from_iter([
+                UserInput::chord([KeyCode::LControl, KeyCode::H]),
+                GamepadButtonType::East.into()
+            ])
+        );
+        assert_eq!(
+            *input_map.get(Action::Jump),
+            PetitSet::<UserInput, 16>::from_iter([KeyCode::Space.into()])
+        );
+    }
+
+    #[test]
+    fn replacing_bindings() {
+        use bevy_input::keyboard::KeyCode;
+
+        let mut input_map = InputMap::<Action>::new([
+            (Action::Run, KeyCode::LShift),
+            (Action::Run, KeyCode::RShift),
+            (Action::Jump, KeyCode::Space),
+        ]);
+        input_map.set_binding_enabled(Action::Run, KeyCode::RShift, false);
+        input_map.replace_bindings(Action::Run, [KeyCode::W, KeyCode::W]);
+
+        assert_eq!(
+            input_map,
+            InputMap::new([(Action::Run, KeyCode::W), (Action::Jump, KeyCode::Space)])
+        );
+    }
+
+    #[test]
+    fn gamepad_swapping() {
+        use bevy_input::gamepad::Gamepad;
+
+        let mut input_map = InputMap::<Action>::default();
+        assert_eq!(input_map.gamepad(), None);
+
+        input_map.set_gamepad(Gamepad(0));
+        assert_eq!(input_map.gamepad(), Some(Gamepad(0)));
+
+        input_map.clear_gamepad();
+        assert_eq!(input_map.gamepad(), None);
+    }
+
+    #[test]
+    fn modifier_chords() {
+        use crate::user_input::{InputButton, InputStreams, ModifierKey};
+        use bevy::prelude::*;
+        use petitset::PetitSet;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map
+            .bind_chord(Action::Run, KeyCode::S)
+            .with_ctrl()
+            .insert();
+        input_map
+            .bind_chord(Action::Hide, KeyCode::S)
+            .with_ctrl()
+            .with_shift()
+            .with_ctrl()
+            .insert();
+
+        assert_eq!(
+            *input_map.get(Action::Run),
+            PetitSet::<UserInput, 16>::from_iter([UserInput::chord([
+                InputButton::Modifier(ModifierKey::Control),
+                KeyCode::S.into(),
+            ])])
+        );
+        // Duplicate modifiers are ignored
+        assert_eq!(
+            *input_map.get(Action::Hide),
+            PetitSet::<UserInput, 16>::from_iter([UserInput::chord([
+                InputButton::Modifier(ModifierKey::Control),
+                InputButton::Modifier(ModifierKey::Shift),
+                KeyCode::S.into(),
+            ])])
+        );
+
+        let mut keyboard_input_stream = Input::<KeyCode>::default();
+        keyboard_input_stream.press(KeyCode::RControl);
+        keyboard_input_stream.press(KeyCode::LShift);
+        keyboard_input_stream.press(KeyCode::S);
+        let input_streams = InputStreams::from_keyboard(&keyboard_input_stream);
+
+        assert!(input_map.pressed(Action::Run, &input_streams, ClashStrategy::PressAll));
+        assert!(input_map.pressed(Action::Hide, &input_streams, ClashStrategy::PressAll));
+    }
+
+    #[test]
+    fn modifier_keys() {
+        use crate::user_input::{InputButton, InputStreams, ModifierKey};
+        use bevy::prelude::*;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert_chord(
+            Action::Run,
+            [
+                InputButton::Modifier(ModifierKey::Control),
+                KeyCode::S.into(),
+            ],
+        );
+        input_map.insert(Action::Jump, ModifierKey::Shift);
+        input_map.insert(Action::Hide, KeyCode::S);
+
+        let mut keyboard_input_stream = Input::<KeyCode>::default();
+        keyboard_input_stream.press(KeyCode::RControl);
+        keyboard_input_stream.press(KeyCode::S);
+        let input_streams = InputStreams::from_keyboard(&keyboard_input_stream);
+
+        assert!(input_map.pressed(
+            Action::Run,
+            &input_streams,
+            ClashStrategy::PrioritizeLongest
+        ));
+        assert!(!input_map.pressed(
+            Action::Jump,
+            &input_streams,
+            ClashStrategy::PrioritizeLongest
+        ));
+        // The modifier chord clashes with its non-modifier key, like any other chord
+        assert!(!input_map.pressed(
+            Action::Hide,
+            &input_streams,
+            ClashStrategy::PrioritizeLongest
+        ));
+
+        keyboard_input_stream.press(KeyCode::LShift);
+        let input_streams = InputStreams::from_keyboard(&keyboard_input_stream);
+        assert!(input_map.pressed(
+            Action::Jump,
+            &input_streams,
+            ClashStrategy::PrioritizeLongest
+        ));
     }
 
     #[test]
-    fn input_clearing() {
-        use bevy_input::keyboard::KeyCode;
+    fn exclude_modifiers() {
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
 
         let mut input_map = InputMap::<Action>::default();
-        input_map.insert(Action::Run, KeyCode::Space);
+        input_map.insert_excluding_modifiers(Action::Run, KeyCode::S);
+        input_map.insert_chord(Action::Hide, [KeyCode::LControl, KeyCode::S]);
+        input_map.insert_excluding_modifiers(
+            Action::Jump,
+            UserInput::chord([KeyCode::LShift, KeyCode::S]),
+        );
 
-        // Clearing action
-        input_map.clear_action(Action::Run);
-        assert_eq!(input_map, InputMap::default());
+        let pressed = |keys: &[KeyCode]| -> Vec<Action> {
+            let mut keyboard_input_stream = Input::<KeyCode>::default();
+            for &key in keys {
+                keyboard_input_stream.press(key);
+            }
+            let input_streams = InputStreams::from_keyboard(&keyboard_input_stream);
+
+            input_map
+                .which_pressed(&input_streams, ClashStrategy::PressAll)
+                .iter()
+                .zip(Action::variants())
+                .filter(|(action_data, _)| action_data.state.pressed())
+                .map(|(_, action)| action)
+                .collect()
+        };
 
-        // Remove input at existing index
-        input_map.insert(Action::Run, KeyCode::Space);
-        input_map.insert(Action::Run, KeyCode::LShift);
-        assert!(input_map.remove_at(Action::Run, 1));
-        assert!(
-            !input_map.remove_at(Action::Run, 1),
-            "Should return false on second removal at the same index"
+        assert_eq!(pressed(&[KeyCode::S]), vec![Action::Run]);
+        // The chord wins cleanly, even though the clash strategy would allow both
+        assert_eq!(pressed(&[KeyCode::RControl, KeyCode::S]), vec![]);
+        assert_eq!(
+            pressed(&[KeyCode::LControl, KeyCode::S]),
+            vec![Action::Hide]
         );
-        assert!(input_map.remove_at(Action::Run, 0));
-        assert!(
-            !input_map.remove_at(Action::Run, 0),
-            "Should return false on second removal at the same index"
+        // Modifiers that are part of the binding do not exclude it
+        assert_eq!(pressed(&[KeyCode::LShift, KeyCode::S]), vec![Action::Jump]);
+        assert_eq!(
+            pressed(&[KeyCode::LShift, KeyCode::LAlt, KeyCode::S]),
+            vec![]
         );
-    }
 
-    #[test]
-    fn merging() {
-        use bevy_input::{gamepad::GamepadButtonType, keyboard::KeyCode};
-
-        let mut input_map = InputMap::default();
-        let mut default_keyboard_map = InputMap::default();
-        default_keyboard_map.insert(Action::Run, KeyCode::LShift);
-        default_keyboard_map.insert_chord(Action::Hide, [KeyCode::LControl, KeyCode::H]);
-        let mut default_gamepad_map = InputMap::default();
-        default_gamepad_map.insert(Action::Run, GamepadButtonType::South);
-        default_gamepad_map.insert(Action::Hide, GamepadButtonType::East);
-
-        // Merging works
-        input_map.merge(&default_keyboard_map);
-        assert_eq!(input_map, default_keyboard_map);
-
-        // Merging is idempotent
-        input_map.merge(&default_keyboard_map);
-        assert_eq!(input_map, default_keyboard_map);
+        assert!(input_map.excludes_modifiers(Action::Run, KeyCode::S));
+        assert!(input_map.set_exclude_modifiers(Action::Run, KeyCode::S, false));
+        assert!(!input_map.excludes_modifiers(Action::Run, KeyCode::S));
+        assert!(!input_map.set_exclude_modifiers(Action::Hide, KeyCode::S, true));
     }
 
     #[test]
-    fn gamepad_swapping() {
-        use bevy_input::gamepad::Gamepad;
+    fn controller_layout() {
+        use crate::user_input::{ControllerLayout, InputStreams};
+        use bevy::prelude::*;
 
         let mut input_map = InputMap::<Action>::default();
-        assert_eq!(input_map.gamepad(), None);
-
         input_map.set_gamepad(Gamepad(0));
-        assert_eq!(input_map.gamepad(), Some(Gamepad(0)));
+        input_map.insert(Action::Jump, GamepadButtonType::South);
+        assert_eq!(input_map.controller_layout(), ControllerLayout::Standard);
 
-        input_map.clear_gamepad();
-        assert_eq!(input_map.gamepad(), None);
+        // The physical "A" button on a Nintendo controller is on the east
+        let mut gamepad_input_stream = Input::<GamepadButton>::default();
+        gamepad_input_stream.press(GamepadButton(Gamepad(0), GamepadButtonType::East));
+
+        let mut input_streams = InputStreams::from_gamepad(&gamepad_input_stream, Gamepad(0));
+        assert!(!input_map.pressed(Action::Jump, &input_streams, ClashStrategy::PressAll));
+
+        input_map.set_controller_layout(ControllerLayout::Nintendo);
+        input_streams.controller_layout = input_map.controller_layout();
+        assert!(input_map.pressed(Action::Jump, &input_streams, ClashStrategy::PressAll));
     }
 
     #[test]
     fn mock_inputs() {
         use crate::input_map::InputButton;
-        use crate::user_input::InputStreams;
+        use crate::user_input::{ControllerLayout, InputStreams};
         use bevy::prelude::*;
 
         // Setting up the input map
@@ -566,7 +2012,15 @@ mod tests {
             gamepad: Some(&gamepad_input_stream),
             keyboard: Some(&keyboard_input_stream),
             mouse: Some(&mouse_input_stream),
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: Some(Gamepad(42)),
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         };
 
         // With no inputs, nothing should be detected
@@ -581,7 +2035,15 @@ mod tests {
             gamepad: Some(&gamepad_input_stream),
             keyboard: Some(&keyboard_input_stream),
             mouse: Some(&mouse_input_stream),
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: Some(Gamepad(42)),
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         };
         for action in Action::variants() {
             assert!(!input_map.pressed(action, &input_streams, ClashStrategy::PressAll));
@@ -594,7 +2056,15 @@ mod tests {
             gamepad: Some(&gamepad_input_stream),
             keyboard: Some(&keyboard_input_stream),
             mouse: Some(&mouse_input_stream),
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: Some(Gamepad(42)),
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         };
 
         assert!(input_map.pressed(Action::Run, &input_streams, ClashStrategy::PressAll));
@@ -608,7 +2078,15 @@ mod tests {
             gamepad: Some(&gamepad_input_stream),
             keyboard: Some(&keyboard_input_stream),
             mouse: Some(&mouse_input_stream),
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: Some(Gamepad(42)),
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         };
 
         assert!(input_map.pressed(Action::Run, &input_streams, ClashStrategy::PressAll));
@@ -620,7 +2098,15 @@ mod tests {
             gamepad: Some(&gamepad_input_stream),
             keyboard: Some(&keyboard_input_stream),
             mouse: Some(&mouse_input_stream),
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: Some(Gamepad(42)),
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         };
 
         for action in Action::variants() {
@@ -634,7 +2120,15 @@ mod tests {
             gamepad: Some(&gamepad_input_stream),
             keyboard: Some(&keyboard_input_stream),
             mouse: Some(&mouse_input_stream),
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: Some(Gamepad(42)),
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         };
 
         assert!(input_map.pressed(Action::Run, &input_streams, ClashStrategy::PressAll));
@@ -650,7 +2144,15 @@ mod tests {
             gamepad: Some(&gamepad_input_stream),
             keyboard: Some(&keyboard_input_stream),
             mouse: Some(&mouse_input_stream),
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: Some(Gamepad(42)),
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         };
 
         assert!(input_map.pressed(Action::Run, &input_streams, ClashStrategy::PressAll));
@@ -666,9 +2168,721 @@ mod tests {
             gamepad: Some(&gamepad_input_stream),
             keyboard: Some(&keyboard_input_stream),
             mouse: Some(&mouse_input_stream),
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: Some(Gamepad(42)),
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         };
 
         assert!(input_map.pressed(Action::Hide, &input_streams, ClashStrategy::PressAll));
     }
+
+    #[test]
+    fn overrides() {
+        use bevy_input::keyboard::KeyCode;
+        use petitset::PetitSet;
+
+        let mut defaults = InputMap::<Action>::default();
+        defaults.insert(Action::Run, KeyCode::LShift);
+        defaults.insert(Action::Jump, KeyCode::Space);
+
+        let mut customized = defaults.clone();
+        assert!(customized.serialize_overrides(&defaults).is_empty());
+
+        customized.clear_action(Action::Run);
+        customized.insert(Action::Run, KeyCode::R);
+        customized.clear_action(Action::Jump);
+        let overrides = customized.serialize_overrides(&defaults);
+        assert_eq!(overrides.len(), 2);
+
+        // New default bindings are picked up by actions that were never customized
+        let mut new_defaults = defaults.clone();
+        new_defaults.insert(Action::Hide, KeyCode::H);
+        let mut loaded = new_defaults.clone();
+        loaded.apply_overrides(&overrides);
+
+        let mut expected = new_defaults;
+        expected.clear_action(Action::Run);
+        expected.insert(Action::Run, KeyCode::R);
+        expected.clear_action(Action::Jump);
+        assert_eq!(loaded, expected);
+
+        // Overrides for actions that no longer exist are skipped
+        let mut stale_overrides = overrides.clone();
+        let mut stale_action = stale_overrides.overrides[&0].clone();
+        stale_action.bindings = PetitSet::default();
+        stale_action
+            .bindings
+            .insert(UserInput::Single(KeyCode::X.into()));
+        stale_overrides.overrides.insert(42, stale_action);
+        let mut loaded = defaults.clone();
+        loaded.apply_overrides(&stale_overrides);
+        assert_eq!(loaded, customized);
+    }
+
+    #[test]
+    fn overrides_store_settings() {
+        use crate::axislike::DeadZone;
+        use bevy_input::gamepad::GamepadAxisType;
+        use bevy_input::keyboard::KeyCode;
+        use bevy_utils::Duration;
+
+        let mut defaults = InputMap::<Action>::default();
+        defaults.insert(Action::Run, KeyCode::LShift);
+        defaults.insert_chord(Action::Jump, [KeyCode::LControl, KeyCode::Space]);
+
+        // Settings that are not bindings are saved too
+        let chord = UserInput::chord([KeyCode::LControl, KeyCode::Space]);
+        let mut customized = defaults.clone();
+        customized.set_binding_enabled(Action::Run, KeyCode::LShift, false);
+        customized.set_shortcut(Action::Jump, chord.clone(), true);
+        customized.set_chord_window(Action::Jump, chord, Some(Duration::from_millis(100)));
+        customized.set_deadzone(Action::Hide, DeadZone::Axial { threshold: 0.2 });
+        customized.set_cooldown(Action::Hide, Duration::from_secs(1));
+        customized.set_axis_inverted(GamepadAxisType::RightStickY, true);
+
+        let overrides = customized.serialize_overrides(&defaults);
+        assert_eq!(overrides.len(), 3);
+
+        let mut loaded = defaults.clone();
+        loaded.apply_overrides(&overrides);
+        assert_eq!(loaded, customized);
+
+        // Inverting axes alone still counts as a customization
+        let mut inverted = defaults.clone();
+        inverted.set_axis_inverted(GamepadAxisType::RightStickY, true);
+        assert!(!inverted.serialize_overrides(&defaults).is_empty());
+    }
+
+    #[test]
+    fn binding_options() {
+        use crate::input_map::BindingOptions;
+        use bevy::prelude::*;
+
+        let chord = UserInput::chord([KeyCode::LControl, KeyCode::S]);
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert_shortcut(Action::Run, [KeyCode::LControl, KeyCode::S]);
+        input_map.set_binding_enabled(Action::Run, chord.clone(), false);
+        assert_eq!(
+            input_map.binding_options(Action::Run, chord.clone()),
+            BindingOptions {
+                disabled: true,
+                exclude_modifiers: false,
+                shortcut: true,
+                chord_window: None,
+            }
+        );
+
+        // Options are only stored for bound inputs
+        assert!(!input_map.set_binding_options(
+            Action::Jump,
+            chord.clone(),
+            BindingOptions::default()
+        ));
+
+        // Options are merged from both maps
+        let mut other = InputMap::<Action>::default();
+        other.insert_excluding_modifiers(Action::Run, chord.clone());
+        input_map.merge(&other);
+        assert!(
+            input_map
+                .binding_options(Action::Run, chord.clone())
+                .exclude_modifiers
+        );
+        assert!(input_map.is_shortcut(Action::Run, chord.clone()));
+
+        // And removed along with their binding
+        input_map.remove(Action::Run, chord.clone());
+        input_map.insert(Action::Run, chord.clone());
+        assert_eq!(
+            input_map.binding_options(Action::Run, chord),
+            BindingOptions::default()
+        );
+    }
+
+    #[test]
+    fn disabled_bindings() {
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert(Action::Run, KeyCode::LShift);
+        input_map.insert(Action::Run, KeyCode::R);
+        input_map.insert_chord(Action::Jump, [KeyCode::LShift, KeyCode::Space]);
+
+        let mut keyboard = Input::<KeyCode>::default();
+        keyboard.press(KeyCode::LShift);
+        keyboard.press(KeyCode::Space);
+        let input_streams = InputStreams::from_keyboard(&keyboard);
+
+        // The chord clashes with and overrules the single key
+        assert!(!input_map.pressed(
+            Action::Run,
+            &input_streams,
+            ClashStrategy::PrioritizeLongest
+        ));
+        assert!(input_map.pressed(
+            Action::Jump,
+            &input_streams,
+            ClashStrategy::PrioritizeLongest
+        ));
+
+        // Disabled bindings are not pressed, and do not participate in clashes
+        let chord = UserInput::chord([KeyCode::LShift, KeyCode::Space]);
+        assert!(input_map.set_binding_enabled(Action::Jump, chord.clone(), false));
+        assert!(!input_map.binding_enabled(Action::Jump, chord));
+        assert!(input_map.pressed(
+            Action::Run,
+            &input_streams,
+            ClashStrategy::PrioritizeLongest
+        ));
+        assert!(!input_map.pressed(
+            Action::Jump,
+            &input_streams,
+            ClashStrategy::PrioritizeLongest
+        ));
+
+        // Other bindings for the same action are unaffected
+        assert!(input_map.set_binding_enabled(Action::Run, KeyCode::LShift, false));
+        assert!(input_map.binding_enabled(Action::Run, KeyCode::R));
+        assert!(!input_map.pressed(Action::Run, &input_streams, ClashStrategy::PressAll));
+
+        // Only bound inputs can be toggled
+        assert!(!input_map.set_binding_enabled(Action::Hide, KeyCode::H, false));
+        assert!(!input_map.binding_enabled(Action::Hide, KeyCode::H));
+
+        // Disabled bindings are restored
+        input_map.enable_all_bindings();
+        assert!(input_map.binding_enabled(Action::Run, KeyCode::LShift));
+        assert!(input_map.pressed(
+            Action::Jump,
+            &input_streams,
+            ClashStrategy::PrioritizeLongest
+        ));
+    }
+
+    #[test]
+    fn analog_axes() {
+        use crate::action_state::ActionData;
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
+
+        let gamepad = Gamepad(0);
+        let mut input_map = InputMap::<Action>::default();
+        input_map.set_gamepad(gamepad);
+        input_map.insert(Action::Run, GamepadAxisType::RightZ);
+        input_map.insert(Action::Run, GamepadButtonType::RightTrigger2);
+
+        let run = |buttons: &Input<GamepadButton>, value: f32| -> ActionData {
+            let mut axes = Axis::<GamepadAxis>::default();
+            axes.set(GamepadAxis(gamepad, GamepadAxisType::RightZ), value);
+            let mut input_streams = InputStreams::from_gamepad(buttons, gamepad);
+            input_streams.gamepad_axes = Some(&axes);
+            input_map.which_pressed(&input_streams, ClashStrategy::PressAll)[Action::Run.index()]
+                .clone()
+        };
+        let mut buttons = Input::<GamepadButton>::default();
+
+        // Axes at rest are not pressed
+        let action_data = run(&buttons, 0.0);
+        assert!(!action_data.state.pressed());
+        assert_eq!(action_data.axis_data.value, 0.0);
+
+        // Partial deflection is reported as-is
+        let action_data = run(&buttons, 0.5);
+        assert!(action_data.state.pressed());
+        assert_eq!(action_data.axis_data.value, 0.5);
+
+        // Values are clamped
+        assert_eq!(run(&buttons, -1.5).axis_data.value, -1.0);
+
+        // Buttons report a full value
+        buttons.press(GamepadButton(gamepad, GamepadButtonType::RightTrigger2));
+        assert_eq!(run(&buttons, 0.25).axis_data.value, 1.0);
+    }
+
+    #[test]
+    fn dual_axes() {
+        use crate::axislike::DeadZone;
+        use crate::orientation::Direction;
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
+
+        let gamepad = Gamepad(0);
+        let mut input_map = InputMap::<Action>::default();
+        input_map.set_gamepad(gamepad);
+        input_map.insert(Action::Run, UserInput::left_stick());
+        input_map.set_deadzone(Action::Run, DeadZone::Radial { radius: 0.5 });
+
+        let buttons = Input::<GamepadButton>::default();
+        let run = |x: f32, y: f32| -> ActionState<Action> {
+            let mut axes = Axis::<GamepadAxis>::default();
+            axes.set(GamepadAxis(gamepad, GamepadAxisType::LeftStickX), x);
+            axes.set(GamepadAxis(gamepad, GamepadAxisType::LeftStickY), y);
+            let mut input_streams = InputStreams::from_gamepad(&buttons, gamepad);
+            input_streams.gamepad_axes = Some(&axes);
+
+            let mut action_state = ActionState::default();
+            action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+            action_state
+        };
+
+        // Inside of the dead zone, the stick is at rest
+        let action_state = run(0.3, -0.3);
+        assert!(action_state.released(Action::Run));
+        assert_eq!(action_state.axis_pair(Action::Run), None);
+
+        // Outside of it, the direction is preserved and the magnitude is rescaled
+        let action_state = run(0.0, -0.75);
+        assert!(action_state.pressed(Action::Run));
+        let axis_pair = action_state.axis_pair(Action::Run).unwrap();
+        assert_eq!(axis_pair.xy(), Vec2::new(0.0, -0.5));
+        assert_eq!(axis_pair.direction(), Some(Direction::SOUTH));
+        assert_eq!(action_state.axis_value(Action::Run), 0.5);
+    }
+
+    #[test]
+    fn deadzones() {
+        use crate::action_state::ActionData;
+        use crate::axislike::DeadZone;
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
+
+        let gamepad = Gamepad(0);
+        let mut input_map = InputMap::<Action>::default();
+        input_map.set_gamepad(gamepad);
+        input_map.insert(Action::Run, GamepadAxisType::LeftStickY);
+        input_map.set_deadzone(Action::Run, DeadZone::Axial { threshold: 0.5 });
+        assert_eq!(
+            input_map.deadzone(Action::Run),
+            DeadZone::Axial { threshold: 0.5 }
+        );
+
+        let buttons = Input::<GamepadButton>::default();
+        let run = |value: f32| -> ActionData {
+            let mut axes = Axis::<GamepadAxis>::default();
+            axes.set(GamepadAxis(gamepad, GamepadAxisType::LeftStickY), value);
+            let mut input_streams = InputStreams::from_gamepad(&buttons, gamepad);
+            input_streams.gamepad_axes = Some(&axes);
+            input_map.which_pressed(&input_streams, ClashStrategy::PressAll)[Action::Run.index()]
+                .clone()
+        };
+
+        // Drift inside of the dead zone is ignored
+        assert!(!run(0.4).state.pressed());
+
+        // The remaining range is rescaled
+        let action_data = run(-0.75);
+        assert!(action_data.state.pressed());
+        assert_eq!(action_data.axis_data.value, -0.5);
+    }
+
+    #[test]
+    fn cooldowns() {
+        use crate::action_state::ActionState;
+        use bevy_utils::{Duration, Instant};
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.set_cooldown(Action::Jump, Duration::from_secs(2));
+
+        let mut action_state = ActionState::<Action>::default();
+        input_map.apply_cooldowns(&mut action_state);
+        assert_eq!(
+            action_state.cooldown(Action::Jump).unwrap().duration(),
+            Duration::from_secs(2)
+        );
+        assert!(action_state.cooldown(Action::Run).is_none());
+
+        let start = Instant::now();
+        action_state.tick(start);
+        assert!(action_state.trigger(Action::Jump));
+        assert!(action_state.trigger(Action::Run));
+
+        // Reapplying the same cooldown does not restart or refresh it
+        action_state.tick(start + Duration::from_secs(1));
+        input_map.apply_cooldowns(&mut action_state);
+        assert_eq!(
+            action_state.cooldown(Action::Jump).unwrap().remaining(),
+            Duration::from_secs(1)
+        );
+
+        // Shortening the cooldown caps the time remaining
+        input_map.set_cooldown(Action::Jump, Duration::from_millis(500));
+        input_map.apply_cooldowns(&mut action_state);
+        assert_eq!(
+            action_state.cooldown(Action::Jump).unwrap().remaining(),
+            Duration::from_millis(500)
+        );
+
+        action_state.tick(start + Duration::from_millis(1500));
+        assert!(action_state.ready(Action::Jump));
+
+        // Removing the cooldown from the map removes it from the action state
+        assert!(action_state.trigger(Action::Jump));
+        input_map.clear_cooldown(Action::Jump);
+        input_map.apply_cooldowns(&mut action_state);
+        assert!(action_state.cooldown(Action::Jump).is_none());
+        assert!(action_state.ready(Action::Jump));
+    }
+
+    #[test]
+    fn axis_thresholds() {
+        use crate::action_state::ActionState;
+        use crate::user_input::InputStreams;
+        use bevy::prelude::*;
+
+        let gamepad = Gamepad(0);
+        let mut input_map = InputMap::<Action>::default();
+        input_map.set_gamepad(gamepad);
+        input_map.insert(Action::Run, GamepadAxisType::RightZ);
+        input_map.set_axis_thresholds(Action::Run, 0.5, 0.3);
+
+        let buttons = Input::<GamepadButton>::default();
+        let mut action_state = ActionState::<Action>::default();
+        let mut update = |value: f32| -> bool {
+            let mut axes = Axis::<GamepadAxis>::default();
+            axes.set(GamepadAxis(gamepad, GamepadAxisType::RightZ), value);
+            let mut input_streams = InputStreams::from_gamepad(&buttons, gamepad);
+            input_streams.gamepad_axes = Some(&axes);
+
+            let mut action_data = input_map.which_pressed(&input_streams, ClashStrategy::PressAll);
+            input_map.apply_axis_thresholds(&mut action_data, &action_state);
+            action_state.update(action_data);
+            action_state.pressed(Action::Run)
+        };
+
+        // Values below the press threshold are ignored
+        assert!(!update(0.4));
+        assert!(update(0.5));
+        // Once pressed, the action is held until the value falls to the release threshold
+        assert!(update(0.4));
+        assert!(!update(0.3));
+        assert!(!update(0.4));
+    }
+
+    #[test]
+    fn half_axes() {
+        use crate::user_input::{InputStreams, UserInput};
+        use bevy::prelude::*;
+
+        let gamepad = Gamepad(0);
+        let mut input_map = InputMap::<Action>::default();
+        input_map.set_gamepad(gamepad);
+        // Both halves of the same axis, bound to opposing actions
+        input_map.insert(
+            Action::Run,
+            UserInput::positive_axis(GamepadAxisType::LeftStickY),
+        );
+        input_map.insert(
+            Action::Hide,
+            UserInput::negative_axis(GamepadAxisType::LeftStickY),
+        );
+
+        let buttons = Input::<GamepadButton>::default();
+        let which_pressed = |input_map: &InputMap<Action>, value: f32| -> Vec<(bool, f32)> {
+            let mut axes = Axis::<GamepadAxis>::default();
+            axes.set(GamepadAxis(gamepad, GamepadAxisType::LeftStickY), value);
+            let mut input_streams = InputStreams::from_gamepad(&buttons, gamepad);
+            input_streams.gamepad_axes = Some(&axes);
+            let action_data = input_map.which_pressed(&input_streams, ClashStrategy::PressAll);
+            [Action::Run, Action::Hide]
+                .into_iter()
+                .map(|action| {
+                    let action_data = &action_data[action.index()];
+                    (action_data.state.pressed(), action_data.axis_data.value)
+                })
+                .collect()
+        };
+
+        assert_eq!(
+            which_pressed(&input_map, 0.5),
+            vec![(true, 0.5), (false, 0.0)]
+        );
+        assert_eq!(
+            which_pressed(&input_map, -0.5),
+            vec![(false, 0.0), (true, 0.5)]
+        );
+
+        // Inverting the axis swaps the halves
+        input_map.set_axis_inverted(GamepadAxisType::LeftStickY, true);
+        assert!(input_map.axis_inverted(GamepadAxisType::LeftStickY));
+        assert_eq!(
+            which_pressed(&input_map, 0.5),
+            vec![(false, 0.0), (true, 0.5)]
+        );
+
+        input_map.set_axis_inverted(GamepadAxisType::LeftStickY, false);
+        assert_eq!(
+            which_pressed(&input_map, 0.5),
+            vec![(true, 0.5), (false, 0.0)]
+        );
+    }
+
+    #[test]
+    fn chord_windows() {
+        use crate::user_input::{ButtonPressTimes, InputStreams};
+        use bevy::prelude::*;
+        use bevy_utils::{Duration, Instant};
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert_chord_within(
+            Action::Hide,
+            [KeyCode::LControl, KeyCode::H],
+            Duration::from_millis(100),
+        );
+
+        let mut keyboard = Input::<KeyCode>::default();
+        let mut press_times = ButtonPressTimes::default();
+        let t0 = Instant::now();
+        let pressed = |input_map: &InputMap<Action>,
+                       keyboard: &Input<KeyCode>,
+                       press_times: &ButtonPressTimes| {
+            let input_streams = InputStreams {
+                press_times: Some(press_times),
+                ..InputStreams::from_keyboard(keyboard)
+            };
+            input_map.pressed(Action::Hide, &input_streams, ClashStrategy::PressAll)
+        };
+
+        // Holding Ctrl and later tapping H does not count
+        keyboard.press(KeyCode::LControl);
+        press_times.update(Some(&keyboard), None, None, t0);
+        keyboard.press(KeyCode::H);
+        press_times.update(Some(&keyboard), None, None, t0 + Duration::from_millis(500));
+        assert!(!pressed(&input_map, &keyboard, &press_times));
+
+        // Without press times, the window is ignored
+        assert!(input_map.pressed(
+            Action::Hide,
+            &InputStreams::from_keyboard(&keyboard),
+            ClashStrategy::PressAll
+        ));
+
+        // Pressing both keys quickly does, and remains pressed while held
+        keyboard.release(KeyCode::LControl);
+        keyboard.release(KeyCode::H);
+        press_times.update(Some(&keyboard), None, None, t0 + Duration::from_millis(600));
+        keyboard.press(KeyCode::H);
+        press_times.update(Some(&keyboard), None, None, t0 + Duration::from_millis(700));
+        keyboard.press(KeyCode::LControl);
+        press_times.update(Some(&keyboard), None, None, t0 + Duration::from_millis(750));
+        assert!(pressed(&input_map, &keyboard, &press_times));
+        press_times.update(Some(&keyboard), None, None, t0 + Duration::from_secs(10));
+        assert!(pressed(&input_map, &keyboard, &press_times));
+
+        // Removing the window restores the usual behavior
+        input_map.set_chord_window(
+            Action::Hide,
+            UserInput::chord([KeyCode::LControl, KeyCode::H]),
+            None,
+        );
+        keyboard.release(KeyCode::H);
+        press_times.update(Some(&keyboard), None, None, t0 + Duration::from_secs(11));
+        keyboard.press(KeyCode::H);
+        press_times.update(Some(&keyboard), None, None, t0 + Duration::from_secs(12));
+        assert!(pressed(&input_map, &keyboard, &press_times));
+    }
+
+    #[test]
+    fn removing_bindings_removes_chord_windows() {
+        use bevy::prelude::*;
+        use bevy_utils::Duration;
+
+        let chord = UserInput::chord([KeyCode::LControl, KeyCode::H]);
+        let within = Duration::from_millis(100);
+        let mut input_map = InputMap::<Action>::default();
+
+        // Rebinding the chord after removing it does not resurrect its window
+        input_map.insert_chord_within(Action::Hide, [KeyCode::LControl, KeyCode::H], within);
+        input_map.remove(Action::Hide, chord.clone());
+        input_map.insert(Action::Hide, chord.clone());
+        assert_eq!(input_map.chord_window(Action::Hide, chord.clone()), None);
+
+        input_map.set_chord_window(Action::Hide, chord.clone(), Some(within));
+        assert!(input_map.remove_at(Action::Hide, 0));
+        input_map.insert(Action::Hide, chord.clone());
+        assert_eq!(input_map.chord_window(Action::Hide, chord.clone()), None);
+
+        input_map.set_chord_window(Action::Hide, chord.clone(), Some(within));
+        input_map.clear_action(Action::Hide);
+        input_map.insert(Action::Hide, chord.clone());
+        assert_eq!(input_map.chord_window(Action::Hide, chord), None);
+    }
+
+    #[test]
+    fn mouse_motion() {
+        use crate::user_input::{InputStreams, MouseMotionDirection};
+        use bevy::ecs::event::Events;
+        use bevy::input::mouse::MouseMotion;
+        use bevy::prelude::*;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert(Action::Run, MouseMotionDirection::Right);
+        input_map.insert(Action::Jump, MouseMotionDirection::Up);
+        input_map.set_mouse_motion_threshold(5.0);
+
+        let keyboard = Input::<KeyCode>::default();
+        let mut events = Events::<MouseMotion>::default();
+        let pressed = |events: &Events<MouseMotion>, action: Action| {
+            let input_streams = InputStreams {
+                mouse_motion: Some(events),
+                mouse_motion_threshold: input_map.mouse_motion_threshold(),
+                ..InputStreams::from_keyboard(&keyboard)
+            };
+            input_map.pressed(action, &input_streams, ClashStrategy::PressAll)
+        };
+
+        // Motion is accumulated across events until it exceeds the threshold
+        events.send(MouseMotion {
+            delta: Vec2::new(3.0, 0.0),
+        });
+        assert!(!pressed(&events, Action::Run));
+        events.send(MouseMotion {
+            delta: Vec2::new(3.0, 0.0),
+        });
+        assert!(pressed(&events, Action::Run));
+
+        // Negative y is upwards
+        assert!(!pressed(&events, Action::Jump));
+        events.send(MouseMotion {
+            delta: Vec2::new(0.0, -10.0),
+        });
+        assert!(pressed(&events, Action::Jump));
+
+        // Motion from previous frames is forgotten
+        events.update();
+        events.update();
+        assert!(!pressed(&events, Action::Run));
+    }
+
+    #[test]
+    fn mouse_wheel() {
+        use crate::user_input::{InputStreams, MouseWheelDirection, MOUSE_WHEEL_PIXELS_PER_LINE};
+        use bevy::ecs::event::Events;
+        use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+        use bevy::prelude::*;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert(Action::Run, MouseWheelDirection::Up);
+        input_map.insert(Action::Jump, MouseWheelDirection::Left);
+
+        let keyboard = Input::<KeyCode>::default();
+        let mut events = Events::<MouseWheel>::default();
+        events.send(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.0,
+            y: 1.0,
+        });
+        // Pixel scrolling is normalized to lines
+        events.send(MouseWheel {
+            unit: MouseScrollUnit::Pixel,
+            x: -MOUSE_WHEEL_PIXELS_PER_LINE,
+            y: MOUSE_WHEEL_PIXELS_PER_LINE,
+        });
+
+        let input_streams = InputStreams {
+            mouse_wheel: Some(&events),
+            ..InputStreams::from_keyboard(&keyboard)
+        };
+        let mut action_state = ActionState::<Action>::default();
+        action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+
+        assert!(action_state.just_pressed(Action::Run));
+        assert_eq!(action_state.mouse_wheel_value(Action::Run), 2.0);
+        assert_eq!(action_state.axis_value(Action::Run), 1.0);
+        assert!(action_state.just_pressed(Action::Jump));
+        assert_eq!(action_state.mouse_wheel_value(Action::Jump), 1.0);
+
+        // Scrolling stops once the events are cleared
+        events.update();
+        events.update();
+        let input_streams = InputStreams {
+            mouse_wheel: Some(&events),
+            ..InputStreams::from_keyboard(&keyboard)
+        };
+        action_state.update(input_map.which_pressed(&input_streams, ClashStrategy::PressAll));
+        assert!(action_state.released(Action::Run));
+        assert_eq!(action_state.mouse_wheel_value(Action::Run), 0.0);
+    }
+
+    #[test]
+    fn mixed_device_chords() {
+        use crate::user_input::{InputButton, InputStreams};
+        use bevy::prelude::*;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.insert_chord(
+            Action::Run,
+            [
+                InputButton::from(KeyCode::LControl),
+                InputButton::from(MouseButton::Left),
+            ],
+        );
+        input_map.insert(Action::Jump, MouseButton::Left);
+        assert_eq!(input_map.get(Action::Run).iter().next().unwrap().len(), 2);
+
+        let mut keyboard = Input::<KeyCode>::default();
+        keyboard.press(KeyCode::LControl);
+        let mut mouse = Input::<MouseButton>::default();
+        mouse.press(MouseButton::Left);
+        let input_streams = InputStreams {
+            mouse: Some(&mouse),
+            ..InputStreams::from_keyboard(&keyboard)
+        };
+
+        // The chord is longer, and so wins the clash with the lone mouse button
+        let action_data = input_map.which_pressed(&input_streams, ClashStrategy::PrioritizeLongest);
+        assert!(action_data[Action::Run.index()].state.pressed());
+        assert!(action_data[Action::Jump.index()].state.released());
+
+        // Neither device alone presses the chord
+        let action_data = input_map.which_pressed(
+            &InputStreams::from_keyboard(&keyboard),
+            ClashStrategy::PrioritizeLongest,
+        );
+        assert!(action_data[Action::Run.index()].state.released());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        use crate::user_input::{InputButton, InputStreams};
+        use bevy::prelude::*;
+
+        let mut input_map = InputMap::<Action>::default();
+        input_map.set_gamepad(Gamepad(3));
+        input_map.insert(Action::Run, KeyCode::LShift);
+        input_map.insert(Action::Run, GamepadButtonType::LeftThumb);
+        input_map.insert_chord(Action::Jump, [KeyCode::LControl, KeyCode::Space]);
+        input_map.insert_chord(
+            Action::Hide,
+            [InputButton::Mouse(MouseButton::Right), KeyCode::H.into()],
+        );
+        input_map.set_binding_enabled(Action::Run, KeyCode::LShift, false);
+
+        let serialized = ron::to_string(&input_map).unwrap();
+        let deserialized: InputMap<Action> = ron::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, input_map);
+        assert_eq!(deserialized.gamepad(), Some(Gamepad(3)));
+
+        let mut keyboard = Input::<KeyCode>::default();
+        keyboard.press(KeyCode::LShift);
+        keyboard.press(KeyCode::LControl);
+        keyboard.press(KeyCode::Space);
+        let mut mouse = Input::<MouseButton>::default();
+        mouse.press(MouseButton::Right);
+        let input_streams = InputStreams {
+            mouse: Some(&mouse),
+            ..InputStreams::from_keyboard(&keyboard)
+        };
+
+        assert_eq!(
+            deserialized.which_pressed(&input_streams, ClashStrategy::PressAll),
+            input_map.which_pressed(&input_streams, ClashStrategy::PressAll)
+        );
+    }
 }
@@ -1,6 +1,8 @@
 //! Helpful utilities for testing input management by sending mock input events
 
-use crate::user_input::{InputStreams, MutableInputStreams, UserInput};
+use crate::user_input::{
+    ControllerLayout, GamepadTouchpadButton, InputStreams, MutableInputStreams, UserInput,
+};
 use bevy_app::App;
 use bevy_ecs::event::Events;
 use bevy_ecs::system::{Res, ResMut, SystemState};
@@ -8,12 +10,13 @@ use bevy_ecs::world::World;
 #[cfg(feature = "ui")]
 use bevy_ecs::{component::Component, query::With, system::Query};
 use bevy_input::{
-    gamepad::{Gamepad, GamepadButton, GamepadEvent, Gamepads},
+    gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadEvent, Gamepads},
     keyboard::{KeyCode, KeyboardInput},
-    mouse::{MouseButton, MouseButtonInput, MouseWheel},
+    mouse::{MouseButton, MouseButtonInput, MouseMotion, MouseWheel},
     touch::{TouchInput, Touches},
-    Input,
+    Axis, Input,
 };
+use bevy_math::Vec2;
 #[cfg(feature = "ui")]
 use bevy_ui::Interaction;
 use bevy_window::CursorMoved;
@@ -57,6 +60,8 @@ pub trait MockInput {
     ///
     /// Gamepad input will be sent by the first registed controller found.
     /// If none are found, gamepad input will be silently skipped.
+    /// [`UserInput::MouseMotion`] and [`UserInput::MouseWheel`] cannot be held down, and are skipped:
+    /// send [`MouseMotion`] or [`MouseWheel`] events instead.
     fn send_input(&mut self, input: impl Into<UserInput>);
 
     /// Send the specified `user_input` directly, using the specified gamepad
@@ -66,6 +71,29 @@ pub trait MockInput {
     /// Provide the [`Gamepad`] identifier to control which gamepad you are emulating inputs from
     fn send_input_to_gamepad(&mut self, input: impl Into<UserInput>, gamepad: Option<Gamepad>);
 
+    /// Sets the value of the `axis_type` directly, such as a trigger or one direction of a joystick
+    ///
+    /// Note that axes will keep their value until explicitly changed or [`MockInput::reset_inputs`] is called.
+    ///
+    /// Axis input will be sent by the first registed controller found.
+    /// If none are found, or the [`Axis<GamepadAxis>`] resource does not exist, the input will be silently skipped.
+    fn send_axis(&mut self, axis_type: GamepadAxisType, value: f32);
+
+    /// Sets the value of the `axis_type` directly, using the specified gamepad
+    ///
+    /// Provide the [`Gamepad`] identifier to control which gamepad you are emulating inputs from
+    fn send_axis_to_gamepad(
+        &mut self,
+        axis_type: GamepadAxisType,
+        value: f32,
+        gamepad: Option<Gamepad>,
+    );
+
+    /// Sets the values of a pair of axes at once, such as the `x` and `y` axes of a joystick
+    ///
+    /// Like [`MockInput::send_axis`], input will be sent by the first registered controller found.
+    fn send_axis_pair(&mut self, x: GamepadAxisType, y: GamepadAxisType, value: Vec2);
+
     /// Releases the specified `user_input` directly
     ///
     /// Gamepad input will be released by the first registed controller found.
@@ -98,8 +126,9 @@ pub trait MockInput {
     /// All buttons are released, and `just_pressed` and `just_released` information on the [`Input`] type are lost.
     /// `just_pressed` and `just_released` on the [`ActionState`](crate::action_state::ActionState) will be kept.
     ///
-    /// This will clear all [`KeyCode`], [`GamepadButton`] and [`MouseButton`] input streams,
+    /// This will clear all [`KeyCode`], [`GamepadButton`], [`MouseButton`] and [`GamepadTouchpadButton`] input streams,
     /// as well as any [`Interaction`] components and all input [`Events`].
+    /// Every [`GamepadAxis`] is reset to zero.
     fn reset_inputs(&mut self);
 
     /// Presses all `bevy_ui` buttons with the matching `Marker` component
@@ -143,6 +172,14 @@ impl<'a> MutableInputStreams<'a> {
                 mouse_input.press(button);
             }
         }
+
+        if let (Some(ref mut touchpad_input), Some(associated_gamepad)) =
+            (&mut self.touchpad, self.associated_gamepad)
+        {
+            for button in input_to_send.touchpad_buttons() {
+                touchpad_input.press(GamepadTouchpadButton(associated_gamepad, button));
+            }
+        }
     }
 
     /// Releases the specified `user_input` directly, using the specified gamepad
@@ -172,6 +209,14 @@ impl<'a> MutableInputStreams<'a> {
                 mouse_input.release(button);
             }
         }
+
+        if let (Some(ref mut touchpad_input), Some(associated_gamepad)) =
+            (&mut self.touchpad, self.associated_gamepad)
+        {
+            for button in input_to_release.touchpad_buttons() {
+                touchpad_input.release(GamepadTouchpadButton(associated_gamepad, button));
+            }
+        }
     }
 }
 
@@ -193,21 +238,51 @@ impl MockInput for World {
             Option<ResMut<Input<GamepadButton>>>,
             Option<ResMut<Input<KeyCode>>>,
             Option<ResMut<Input<MouseButton>>>,
+            Option<ResMut<Input<GamepadTouchpadButton>>>,
         )> = SystemState::new(self);
 
-        let (mut maybe_gamepad, mut maybe_keyboard, mut maybe_mouse) =
+        let (mut maybe_gamepad, mut maybe_keyboard, mut maybe_mouse, mut maybe_touchpad) =
             input_system_state.get_mut(self);
 
         let mut mutable_input_streams = MutableInputStreams {
             gamepad: maybe_gamepad.as_deref_mut(),
             keyboard: maybe_keyboard.as_deref_mut(),
             mouse: maybe_mouse.as_deref_mut(),
+            touchpad: maybe_touchpad.as_deref_mut(),
             associated_gamepad: gamepad,
         };
 
         mutable_input_streams.send_user_input(input);
     }
 
+    fn send_axis(&mut self, axis_type: GamepadAxisType, value: f32) {
+        let gamepad = if let Some(gamepads) = self.get_resource::<Gamepads>() {
+            gamepads.iter().next().copied()
+        } else {
+            None
+        };
+
+        self.send_axis_to_gamepad(axis_type, value, gamepad);
+    }
+
+    fn send_axis_to_gamepad(
+        &mut self,
+        axis_type: GamepadAxisType,
+        value: f32,
+        gamepad: Option<Gamepad>,
+    ) {
+        if let (Some(gamepad), Some(mut gamepad_axes)) =
+            (gamepad, self.get_resource_mut::<Axis<GamepadAxis>>())
+        {
+            gamepad_axes.set(GamepadAxis(gamepad, axis_type), value);
+        }
+    }
+
+    fn send_axis_pair(&mut self, x: GamepadAxisType, y: GamepadAxisType, value: Vec2) {
+        self.send_axis(x, value.x);
+        self.send_axis(y, value.y);
+    }
+
     fn release_input(&mut self, input: impl Into<UserInput>) {
         let gamepad = if let Some(gamepads) = self.get_resource::<Gamepads>() {
             gamepads.iter().next().copied()
@@ -223,15 +298,17 @@ impl MockInput for World {
             Option<ResMut<Input<GamepadButton>>>,
             Option<ResMut<Input<KeyCode>>>,
             Option<ResMut<Input<MouseButton>>>,
+            Option<ResMut<Input<GamepadTouchpadButton>>>,
         )> = SystemState::new(self);
 
-        let (mut maybe_gamepad, mut maybe_keyboard, mut maybe_mouse) =
+        let (mut maybe_gamepad, mut maybe_keyboard, mut maybe_mouse, mut maybe_touchpad) =
             input_system_state.get_mut(self);
 
         let mut mutable_input_streams = MutableInputStreams {
             gamepad: maybe_gamepad.as_deref_mut(),
             keyboard: maybe_keyboard.as_deref_mut(),
             mouse: maybe_mouse.as_deref_mut(),
+            touchpad: maybe_touchpad.as_deref_mut(),
             associated_gamepad: gamepad,
         };
 
@@ -257,15 +334,35 @@ impl MockInput for World {
             Option<Res<Input<GamepadButton>>>,
             Option<Res<Input<KeyCode>>>,
             Option<Res<Input<MouseButton>>>,
+            Option<Res<Input<GamepadTouchpadButton>>>,
+            Option<Res<Axis<GamepadAxis>>>,
+            Option<Res<Events<MouseMotion>>>,
+            Option<Res<Events<MouseWheel>>>,
         )> = SystemState::new(self);
 
-        let (maybe_gamepad, maybe_keyboard, maybe_mouse) = input_system_state.get(self);
+        let (
+            maybe_gamepad,
+            maybe_keyboard,
+            maybe_mouse,
+            maybe_touchpad,
+            maybe_gamepad_axes,
+            maybe_mouse_motion,
+            maybe_mouse_wheel,
+        ) = input_system_state.get(self);
 
         let input_streams = InputStreams {
             gamepad: maybe_gamepad.as_deref(),
             keyboard: maybe_keyboard.as_deref(),
             mouse: maybe_mouse.as_deref(),
+            touchpad: maybe_touchpad.as_deref(),
+            gamepad_axes: maybe_gamepad_axes.as_deref(),
+            mouse_motion: maybe_mouse_motion.as_deref(),
+            mouse_wheel: maybe_mouse_wheel.as_deref(),
             associated_gamepad: gamepad,
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         };
 
         input_streams.input_pressed(&input.into())
@@ -287,14 +384,21 @@ impl MockInput for World {
             Option<ResMut<Input<GamepadButton>>>,
             Option<ResMut<Input<KeyCode>>>,
             Option<ResMut<Input<MouseButton>>>,
+            Option<ResMut<Input<GamepadTouchpadButton>>>,
+            Option<ResMut<Axis<GamepadAxis>>>,
         )> = SystemState::new(self);
 
-        let (maybe_gamepad, maybe_keyboard, maybe_mouse) = input_system_state.get_mut(self);
+        let (maybe_gamepad, maybe_keyboard, maybe_mouse, maybe_touchpad, maybe_gamepad_axes) =
+            input_system_state.get_mut(self);
 
         if let Some(mut gamepad) = maybe_gamepad {
             *gamepad = Default::default();
         }
 
+        if let Some(mut gamepad_axes) = maybe_gamepad_axes {
+            *gamepad_axes = Default::default();
+        }
+
         if let Some(mut keyboard) = maybe_keyboard {
             *keyboard = Default::default();
         }
@@ -303,12 +407,17 @@ impl MockInput for World {
             *mouse = Default::default();
         }
 
+        if let Some(mut touchpad) = maybe_touchpad {
+            *touchpad = Default::default();
+        }
+
         self.insert_resource(Events::<GamepadEvent>::default());
 
         self.insert_resource(Events::<KeyboardInput>::default());
 
         self.insert_resource(Events::<MouseButtonInput>::default());
         self.insert_resource(Events::<MouseWheel>::default());
+        self.insert_resource(Events::<MouseMotion>::default());
         self.insert_resource(Events::<CursorMoved>::default());
 
         self.insert_resource(Touches::default());
@@ -343,6 +452,23 @@ impl MockInput for App {
         self.world.send_input_to_gamepad(input, gamepad);
     }
 
+    fn send_axis(&mut self, axis_type: GamepadAxisType, value: f32) {
+        self.world.send_axis(axis_type, value);
+    }
+
+    fn send_axis_to_gamepad(
+        &mut self,
+        axis_type: GamepadAxisType,
+        value: f32,
+        gamepad: Option<Gamepad>,
+    ) {
+        self.world.send_axis_to_gamepad(axis_type, value, gamepad);
+    }
+
+    fn send_axis_pair(&mut self, x: GamepadAxisType, y: GamepadAxisType, value: Vec2) {
+        self.world.send_axis_pair(x, y, value);
+    }
+
     fn release_input(&mut self, input: impl Into<UserInput>) {
         self.world.release_input(input);
     }
@@ -422,6 +548,30 @@ mod test {
         assert!(!world.pressed_for_gamepad(GamepadButtonType::North, gamepad));
     }
 
+    #[test]
+    fn axis_inputs() {
+        use crate::input_mocking::MockInput;
+        use crate::user_input::UserInput;
+        use bevy::prelude::*;
+
+        let mut world = World::new();
+        world.insert_resource(Axis::<GamepadAxis>::default());
+        let gamepad = Some(Gamepad(0));
+
+        world.send_axis_to_gamepad(GamepadAxisType::LeftZ, 0.5, gamepad);
+        assert_eq!(
+            world
+                .resource::<Axis<GamepadAxis>>()
+                .get(GamepadAxis(Gamepad(0), GamepadAxisType::LeftZ)),
+            Some(0.5)
+        );
+        assert!(world.pressed_for_gamepad(UserInput::SingleAxis(GamepadAxisType::LeftZ), gamepad));
+
+        // Test that resetting inputs zeroes all axes
+        world.reset_inputs();
+        assert!(!world.pressed_for_gamepad(UserInput::SingleAxis(GamepadAxisType::LeftZ), gamepad));
+    }
+
     #[test]
     #[cfg(feature = "ui")]
     fn ui_inputs() {
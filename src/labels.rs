@@ -0,0 +1,289 @@
+//! Human-readable names for bindings, for control hints like "Press [Space]"
+//!
+//! [`UserInput::display_name`] uses generic names that do not depend on the platform.
+//! To substitute platform-specific names or glyph keys, such as the shapes on the face buttons of some controllers,
+//! implement [`ButtonLabels`] and pass it to [`UserInput::display_name_with`].
+
+use crate::user_input::{
+    InputButton, ModifierKey, MouseMotionDirection, MouseWheelDirection, TouchpadButton, UserInput,
+};
+use bevy_input::{
+    gamepad::{GamepadAxisType, GamepadButtonType},
+    keyboard::KeyCode,
+    mouse::MouseButton,
+};
+
+/// A mapping from each [`InputButton`] to the text or glyph key used to display it
+///
+/// Axes, mouse motion and mouse wheel inputs are always given generic names.
+///
+/// # Example
+/// ```rust
+/// use bevy_input::gamepad::GamepadButtonType;
+/// use bevy_input::keyboard::KeyCode;
+/// use leafwing_input_manager::labels::{ButtonLabels, GenericButtonLabels};
+/// use leafwing_input_manager::user_input::{InputButton, UserInput};
+///
+/// struct PlayStationLabels;
+///
+/// impl ButtonLabels for PlayStationLabels {
+///     fn button_label(&self, button: InputButton) -> String {
+///         match button {
+///             InputButton::Gamepad(GamepadButtonType::South) => "Cross".to_string(),
+///             InputButton::Gamepad(GamepadButtonType::East) => "Circle".to_string(),
+///             InputButton::Gamepad(GamepadButtonType::West) => "Square".to_string(),
+///             InputButton::Gamepad(GamepadButtonType::North) => "Triangle".to_string(),
+///             // Fall back to the generic names for everything else
+///             other => GenericButtonLabels.button_label(other),
+///         }
+///     }
+/// }
+///
+/// let jump = UserInput::from(GamepadButtonType::South);
+/// assert_eq!(jump.display_name(), "South Button");
+/// assert_eq!(jump.display_name_with(&PlayStationLabels), "Cross");
+/// ```
+pub trait ButtonLabels {
+    /// The name or glyph key used to display the `button`
+    fn button_label(&self, button: InputButton) -> String;
+}
+
+/// Generic, platform-independent names for every [`InputButton`]
+///
+/// Gamepad buttons are named by their position, such as "South Button" or "Left Bumper",
+/// as the labels printed on them differ between controllers.
+/// Used by [`UserInput::display_name`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GenericButtonLabels;
+
+impl ButtonLabels for GenericButtonLabels {
+    fn button_label(&self, button: InputButton) -> String {
+        match button {
+            InputButton::Gamepad(button_type) => gamepad_button_name(button_type).to_string(),
+            InputButton::Keyboard(keycode) => key_name(keycode),
+            InputButton::Mouse(mouse_button) => match mouse_button {
+                MouseButton::Left => "Left Click".to_string(),
+                MouseButton::Right => "Right Click".to_string(),
+                MouseButton::Middle => "Middle Click".to_string(),
+                MouseButton::Other(index) => format!("Mouse Button {index}"),
+            },
+            InputButton::Touchpad(touchpad_button) => match touchpad_button {
+                TouchpadButton::Press => "Touchpad",
+                TouchpadButton::SwipeUp => "Touchpad Swipe Up",
+                TouchpadButton::SwipeDown => "Touchpad Swipe Down",
+                TouchpadButton::SwipeLeft => "Touchpad Swipe Left",
+                TouchpadButton::SwipeRight => "Touchpad Swipe Right",
+            }
+            .to_string(),
+            InputButton::Modifier(modifier) => match modifier {
+                ModifierKey::Alt => "Alt",
+                ModifierKey::Control => "Ctrl",
+                ModifierKey::Shift => "Shift",
+                ModifierKey::Super => "Super",
+            }
+            .to_string(),
+        }
+    }
+}
+
+impl UserInput {
+    /// A human-readable name for this input, using the [`GenericButtonLabels`]
+    ///
+    /// The buttons of chords are joined with " + ".
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_input::keyboard::KeyCode;
+    /// use leafwing_input_manager::user_input::UserInput;
+    ///
+    /// assert_eq!(UserInput::from(KeyCode::Space).display_name(), "Space");
+    /// assert_eq!(
+    ///     UserInput::chord([KeyCode::LControl, KeyCode::Key1]).display_name(),
+    ///     "Left Ctrl + 1"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.display_name_with(&GenericButtonLabels)
+    }
+
+    /// A human-readable name for this input, using the provided `labels` for each of its buttons
+    ///
+    /// See [`ButtonLabels`] for an example.
+    #[must_use]
+    pub fn display_name_with(&self, labels: &dyn ButtonLabels) -> String {
+        match self {
+            UserInput::Single(button) => labels.button_label(*button),
+            UserInput::Chord(buttons) => buttons
+                .iter()
+                .map(|&button| labels.button_label(button))
+                .collect::<Vec<_>>()
+                .join(" + "),
+            UserInput::SingleAxis(axis_type) => axis_name(*axis_type).to_string(),
+            UserInput::HalfAxis {
+                axis_type,
+                inverted,
+            } => format!(
+                "{} {}",
+                axis_name(*axis_type),
+                half_axis_name(*axis_type, *inverted)
+            ),
+            UserInput::DualAxis { x, y } => match (x, y) {
+                (GamepadAxisType::LeftStickX, GamepadAxisType::LeftStickY) => {
+                    "Left Stick".to_string()
+                }
+                (GamepadAxisType::RightStickX, GamepadAxisType::RightStickY) => {
+                    "Right Stick".to_string()
+                }
+                (GamepadAxisType::DPadX, GamepadAxisType::DPadY) => "D-Pad".to_string(),
+                _ => format!("{} / {}", axis_name(*x), axis_name(*y)),
+            },
+            UserInput::MouseMotion { direction } => match direction {
+                MouseMotionDirection::Up => "Mouse Up",
+                MouseMotionDirection::Down => "Mouse Down",
+                MouseMotionDirection::Left => "Mouse Left",
+                MouseMotionDirection::Right => "Mouse Right",
+            }
+            .to_string(),
+            UserInput::MouseWheel { direction } => match direction {
+                MouseWheelDirection::Up => "Scroll Up",
+                MouseWheelDirection::Down => "Scroll Down",
+                MouseWheelDirection::Left => "Scroll Left",
+                MouseWheelDirection::Right => "Scroll Right",
+            }
+            .to_string(),
+        }
+    }
+}
+
+/// The generic name of a gamepad button, based on its position
+fn gamepad_button_name(button_type: GamepadButtonType) -> &'static str {
+    use GamepadButtonType::*;
+
+    match button_type {
+        South => "South Button",
+        East => "East Button",
+        North => "North Button",
+        West => "West Button",
+        C => "C Button",
+        Z => "Z Button",
+        LeftTrigger => "Left Bumper",
+        LeftTrigger2 => "Left Trigger",
+        RightTrigger => "Right Bumper",
+        RightTrigger2 => "Right Trigger",
+        Select => "Select",
+        Start => "Start",
+        Mode => "Mode",
+        LeftThumb => "Left Stick Press",
+        RightThumb => "Right Stick Press",
+        DPadUp => "D-Pad Up",
+        DPadDown => "D-Pad Down",
+        DPadLeft => "D-Pad Left",
+        DPadRight => "D-Pad Right",
+    }
+}
+
+/// The generic name of a gamepad axis
+fn axis_name(axis_type: GamepadAxisType) -> &'static str {
+    use GamepadAxisType::*;
+
+    match axis_type {
+        LeftStickX => "Left Stick X",
+        LeftStickY => "Left Stick Y",
+        LeftZ => "Left Z",
+        RightStickX => "Right Stick X",
+        RightStickY => "Right Stick Y",
+        RightZ => "Right Z",
+        DPadX => "D-Pad X",
+        DPadY => "D-Pad Y",
+    }
+}
+
+/// The name of one half of an axis, where positive y is up and positive x is right
+fn half_axis_name(axis_type: GamepadAxisType, inverted: bool) -> &'static str {
+    use GamepadAxisType::*;
+
+    match (axis_type, inverted) {
+        (LeftStickX | RightStickX | DPadX, false) => "Right",
+        (LeftStickX | RightStickX | DPadX, true) => "Left",
+        (LeftStickY | RightStickY | DPadY, false) => "Up",
+        (LeftStickY | RightStickY | DPadY, true) => "Down",
+        (LeftZ | RightZ, false) => "+",
+        (LeftZ | RightZ, true) => "-",
+    }
+}
+
+/// The generic name of a keyboard key
+///
+/// Modifiers and digits are given short, conventional names,
+/// and other keys have spaces inserted between the words of their [`KeyCode`] variant.
+fn key_name(keycode: KeyCode) -> String {
+    use KeyCode::*;
+
+    let name = match keycode {
+        Key1 => "1",
+        Key2 => "2",
+        Key3 => "3",
+        Key4 => "4",
+        Key5 => "5",
+        Key6 => "6",
+        Key7 => "7",
+        Key8 => "8",
+        Key9 => "9",
+        Key0 => "0",
+        LControl => "Left Ctrl",
+        RControl => "Right Ctrl",
+        LShift => "Left Shift",
+        RShift => "Right Shift",
+        LAlt => "Left Alt",
+        RAlt => "Right Alt",
+        LWin => "Left Super",
+        RWin => "Right Super",
+        LBracket => "Left Bracket",
+        RBracket => "Right Bracket",
+        Return => "Enter",
+        Back => "Backspace",
+        Up => "Up Arrow",
+        Down => "Down Arrow",
+        Left => "Left Arrow",
+        Right => "Right Arrow",
+        _ => {
+            // Split the variant name into words, like "PageUp" into "Page Up" and "Numpad1" into "Numpad 1",
+            // while keeping names like "F12" together
+            let debug_name = format!("{keycode:?}");
+            let mut name = String::with_capacity(debug_name.len() + 2);
+            let mut previous: Option<char> = None;
+            for character in debug_name.chars() {
+                if let Some(previous) = previous {
+                    let new_word = (character.is_uppercase() && previous.is_lowercase())
+                        || (character.is_ascii_digit() && previous.is_lowercase());
+                    if new_word {
+                        name.push(' ');
+                    }
+                }
+                name.push(character);
+                previous = Some(character);
+            }
+            return name;
+        }
+    };
+
+    name.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_names() {
+        assert_eq!(key_name(KeyCode::F1), "F1");
+        assert_eq!(key_name(KeyCode::F12), "F12");
+        assert_eq!(key_name(KeyCode::F24), "F24");
+        assert_eq!(key_name(KeyCode::Numpad1), "Numpad 1");
+        assert_eq!(key_name(KeyCode::NumpadAdd), "Numpad Add");
+        assert_eq!(key_name(KeyCode::Key1), "1");
+        assert_eq!(key_name(KeyCode::LBracket), "Left Bracket");
+        assert_eq!(key_name(KeyCode::PageUp), "Page Up");
+    }
+}
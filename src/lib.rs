@@ -12,15 +12,21 @@ pub mod action_state;
 pub mod clashing_inputs;
 mod display_impl;
 pub mod errors;
+pub mod input_context;
 pub mod input_map;
 mod input_mocking;
 // Re-export this at the root level for convenience
 pub use input_mocking::MockInput;
 pub mod axislike;
 pub mod buttonlike;
+pub mod labels;
 pub mod orientation;
 pub mod plugin;
+pub mod rebinding;
+pub mod scripted_input;
 pub mod systems;
+pub mod text_input;
+pub mod touch;
 pub mod user_input;
 
 // Importing the derive macro
@@ -85,6 +91,50 @@ pub trait Actionlike: Send + Sync + Clone + 'static {
 
     /// Returns the position in the defining enum of the given action
     fn index(&self) -> usize;
+
+    /// Returns the priority of the given action when resolving clashes with [`ClashStrategy::UseActionOrder`](crate::clashing_inputs::ClashStrategy::UseActionOrder)
+    ///
+    /// Actions with a lower ordering win clashes.
+    /// By default, this is the [`index`](Actionlike::index) of the action,
+    /// which `#[derive(Actionlike)]` assigns in the order that the variants are declared, starting from 0.
+    /// The derive macro does not generate this method,
+    /// so implement [`Actionlike`] manually to tune clash priority independently of the declaration (and display) order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_input_manager::Actionlike;
+    ///
+    /// #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+    /// enum Action {
+    ///     Save,
+    ///     SaveAs,
+    /// }
+    ///
+    /// impl Actionlike for Action {
+    ///     const N_VARIANTS: usize = 2;
+    ///
+    ///     fn get_at(index: usize) -> Option<Self> {
+    ///         [Action::Save, Action::SaveAs].get(index).copied()
+    ///     }
+    ///
+    ///     fn index(&self) -> usize {
+    ///         *self as usize
+    ///     }
+    ///
+    ///     // `SaveAs` wins clashes, even though it is declared second
+    ///     fn ordering(&self) -> usize {
+    ///         match self {
+    ///             Action::Save => 1,
+    ///             Action::SaveAs => 0,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert!(Action::SaveAs.ordering() < Action::Save.ordering());
+    /// ```
+    fn ordering(&self) -> usize {
+        self.index()
+    }
 }
 
 /// An iterator of [`Actionlike`] actions
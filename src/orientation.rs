@@ -284,6 +284,60 @@ mod rotation {
         }
     }
 
+    // Interpolation
+    impl Rotation {
+        /// The signed number of tenths of a degree needed to rotate from `self` to `target` along the shortest path
+        ///
+        /// Clockwise rotations are positive.
+        /// The result lies between -1799 and 1800, so rotations of exactly half a circle are clockwise,
+        /// as in [`Orientation::rotation_direction`](crate::orientation::Orientation::rotation_direction).
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_input_manager::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::NORTH.shortest_delta(Rotation::EAST), 900);
+        /// assert_eq!(Rotation::NORTH.shortest_delta(Rotation::WEST), -900);
+        /// assert_eq!(Rotation::NORTHWEST.shortest_delta(Rotation::NORTHEAST), 900);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn shortest_delta(self, target: Rotation) -> i16 {
+            let clockwise = (target - self).deci_degrees as i16;
+
+            if clockwise <= (Rotation::FULL_CIRCLE / 2) as i16 {
+                clockwise
+            } else {
+                clockwise - Rotation::FULL_CIRCLE as i16
+            }
+        }
+
+        /// Interpolates from `self` to `target` along the shortest path, where `s` is the fraction of the way to go
+        ///
+        /// An `s` of 0 returns `self` and an `s` of 1 returns `target`.
+        /// Because this interpolates the angle directly, it moves at a constant angular speed,
+        /// and is equivalent to spherical interpolation (slerp) of the corresponding [`Direction`](crate::orientation::Direction)s.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_input_manager::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::NORTH.lerp(Rotation::EAST, 0.5), Rotation::NORTHEAST);
+        /// // The shortest path crosses midnight
+        /// assert_eq!(Rotation::NORTHWEST.lerp(Rotation::NORTHEAST, 0.5), Rotation::NORTH);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn lerp(self, target: Rotation, s: f32) -> Rotation {
+            let deci_degrees = self.deci_degrees as f32 + self.shortest_delta(target) as f32 * s;
+            let normalized = deci_degrees
+                .round()
+                .rem_euclid(Rotation::FULL_CIRCLE as f32);
+
+            Rotation::new(normalized as u16)
+        }
+    }
+
     // Constants
     impl Rotation {
         /// The number of deci-degrees that make up a full circle
@@ -515,6 +569,81 @@ mod direction {
         pub const fn unit_vector(&self) -> Vec2 {
             self.unit_vector
         }
+
+        /// Returns this direction rotated clockwise by `radians`, matching the convention of [`Rotation`](crate::orientation::Rotation)
+        ///
+        /// Negative values rotate counterclockwise.
+        /// Unlike adding [`Rotation`](crate::orientation::Rotation)s, this is not discretized,
+        /// which makes it suitable for aiming cones or for rotating movement relative to a camera.
+        /// If floating point error would make the result [nearly singular](crate::errors::NearlySingularConversion),
+        /// the direction is left unchanged.
+        ///
+        /// # Example
+        /// ```rust
+        /// use core::f32::consts::FRAC_PI_2;
+        /// use leafwing_input_manager::orientation::{Direction, Orientation};
+        ///
+        /// Direction::NORTH.rotate(FRAC_PI_2).assert_approx_eq(Direction::EAST);
+        /// Direction::NORTH.rotate(-FRAC_PI_2).assert_approx_eq(Direction::WEST);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn rotate(self, radians: f32) -> Direction {
+            let (sin, cos) = radians.sin_cos();
+            let (x, y) = (self.unit_vector.x, self.unit_vector.y);
+
+            Direction::try_from(Vec2::new(x * cos + y * sin, y * cos - x * sin)).unwrap_or(self)
+        }
+
+        /// Spherically interpolates from `self` to `target`, where `s` is the fraction of the way to go
+        ///
+        /// The direction rotates at a constant angular speed along the shortest path,
+        /// so unlike interpolating the unit vectors, this never passes through the zero vector.
+        /// When `self` and `target` are exactly opposite, the rotation is clockwise.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_input_manager::orientation::{Direction, Orientation};
+        ///
+        /// Direction::NORTH.slerp(Direction::EAST, 0.5).assert_approx_eq(Direction::NORTHEAST);
+        /// Direction::NORTH.slerp(Direction::SOUTH, 0.5).assert_approx_eq(Direction::EAST);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn slerp(self, target: Direction, s: f32) -> Direction {
+            let angle = self.angle_between(target);
+            // `atan2` returns -π for exact opposites, so enforce the clockwise tie-break
+            let angle = if angle <= -std::f32::consts::PI + f32::EPSILON {
+                std::f32::consts::PI
+            } else {
+                angle
+            };
+
+            self.rotate(angle * s)
+        }
+
+        /// Returns the signed angle in radians needed to rotate this direction to `other`, using [`Direction::rotate`]
+        ///
+        /// Positive values are clockwise, and the result always lies between -π and π.
+        /// This is computed from both the dot and cross products of the unit vectors,
+        /// so it remains accurate for directions that are nearly parallel or nearly opposite.
+        ///
+        /// # Example
+        /// ```rust
+        /// use core::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+        /// use leafwing_input_manager::orientation::Direction;
+        ///
+        /// assert!((Direction::NORTH.angle_between(Direction::EAST) - FRAC_PI_2).abs() < 1e-6);
+        /// assert!((Direction::NORTH.angle_between(Direction::NORTHWEST) + FRAC_PI_4).abs() < 1e-6);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn angle_between(self, other: Direction) -> f32 {
+            let a = self.unit_vector;
+            let b = other.unit_vector;
+
+            f32::atan2(a.y * b.x - a.x * b.y, a.dot(b))
+        }
     }
 
     // Constants
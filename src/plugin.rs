@@ -1,6 +1,9 @@
 //! Contains main plugin exported by this crate.
 
+use crate::action_state::{ActionData, ActionDiff, ActionDiffBatch, ActionState, AxisChanged};
 use crate::clashing_inputs::ClashStrategy;
+use crate::input_map::InputMapChanged;
+use crate::user_input::ButtonPressTimes;
 use crate::Actionlike;
 use core::hash::Hash;
 use core::marker::PhantomData;
@@ -8,6 +11,8 @@ use std::fmt::Debug;
 
 use bevy_app::{App, CoreStage, Plugin};
 use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::ParallelSystemDescriptor;
+use bevy_ecs::system::AsSystemLabel;
 use bevy_input::InputSystem;
 #[cfg(feature = "ui")]
 use bevy_ui::UiSystem;
@@ -23,15 +28,78 @@ use bevy_ui::UiSystem;
 ///
 /// ## Systems
 /// - [`tick_action_state`](crate::systems::tick_action_state), which resets the `pressed` and `just_pressed` fields of the [`ActionState`](crate::action_state::ActionState) each frame
-///     - labeled [`InputManagerSystem::Reset`]
+///     - labeled [`InputManagerSystem::Tick`], and runs in [`CoreStage::First`]
+///     - not added if [`InputManagerPlugin::manual_tick`] is used
+/// - [`assign_gamepads`](crate::systems::assign_gamepads), which associates connected gamepads with each [`InputMap`](crate::input_map::InputMap) that has a [`GamepadAssignment`](crate::input_map::GamepadAssignment)
+///     - runs before [`InputManagerSystem::Update`]
+/// - [`record_button_press_times`](crate::systems::record_button_press_times), which records when each button was pressed in the [`ButtonPressTimes`] resource, for chord windows
+///     - runs before [`InputManagerSystem::Update`]
+/// - [`update_mouse_motion`](crate::systems::update_mouse_motion), which scales and smooths mouse motion using the [`MouseMotionConfig`](crate::user_input::MouseMotionConfig) resource, if it exists
+///     - runs before [`InputManagerSystem::Update`]
 /// - [`update_action_state`](crate::systems::update_action_state), which collects [`Input`](bevy::input::Input) resources to update the [`ActionState`](crate::action_state::ActionState)
+///     - actions are released while suppressed by the [`InputContext`](crate::input_context::InputContext) resource, if it exists
 ///     - labeled [`InputManagerSystem::Update`]
+/// - [`update_shared_action_states`](crate::systems::update_shared_action_states), which does the same for the targets of each [`SharedInputMap`](crate::input_map::SharedInputMap)
+///     - labeled [`InputManagerSystem::Update`]
+/// - [`clear_touchpad_input`](crate::systems::clear_touchpad_input), which clears the `just_pressed` and `just_released` touchpad buttons each frame
+///     - runs in [`CoreStage::First`]
+///     - only added by the first client plugin, as the touchpad input is shared by every action type
+/// - [`release_touchpad_swipes`](crate::systems::release_touchpad_swipes), which releases touchpad swipes once they have been read
+///     - runs after [`InputManagerSystem::Update`]
+///     - only added by the first client plugin
+/// - [`release_on_gamepad_disconnect`](crate::systems::release_on_gamepad_disconnect), which releases the actions of disconnected gamepads
+///     - runs after [`InputManagerSystem::Update`]
+/// - [`enforce_exclusive_groups`](crate::systems::enforce_exclusive_groups), which allows only one action of each [`ExclusiveGroups`] group to be pressed at once
+///     - runs after [`InputManagerSystem::Update`]
+/// - [`apply_input_macros`](crate::systems::apply_input_macros), which plays back the [`InputMacros`](crate::scripted_input::InputMacros) triggered by each [`ActionState`](crate::action_state::ActionState)
+///     - labeled [`InputManagerSystem::ManualControl`]
+/// - [`record_input`](crate::systems::record_input) and [`playback_input`](crate::systems::playback_input), which record and replay inputs using the [`CapturedInput`](crate::scripted_input::CapturedInput) resource
+///     - [`playback_input`](crate::systems::playback_input) is labeled [`InputManagerSystem::ManualControl`]
+/// - [`release_on_focus_lost`](crate::systems::release_on_focus_lost), which releases all actions when a window loses focus
+///     - only added when [`InputManagerPlugin::release_on_focus_lost`] is used
+///     - runs after [`InputManagerSystem::ManualControl`]
+/// - [`update_action_state_from_touches`](crate::systems::update_action_state_from_touches), which presses the actions of [`VirtualButton`](crate::touch::VirtualButton)s while they are touched
+///     - labeled [`InputManagerSystem::ManualControl`]
+/// - [`update_action_state_from_virtual_joysticks`](crate::systems::update_action_state_from_virtual_joysticks), which sets the analog values of actions driven by [`VirtualJoystick`](crate::touch::VirtualJoystick)s
+///     - labeled [`InputManagerSystem::ManualControl`]
+/// - [`generate_axis_events`](crate::systems::generate_axis_events), which sends an [`AxisChanged`] event whenever the value of an action changes
+///     - only added when [`InputManagerPlugin::with_axis_events`] is used
+///     - runs after [`InputManagerSystem::ReleaseOnDisable`], even while [`ToggleActions`] is disabled
+/// - [`detect_input_map_changes`](crate::systems::detect_input_map_changes), which sends an [`InputMapChanged`] event whenever an [`InputMap`](crate::input_map::InputMap) is modified
+///     - runs in [`CoreStage::PostUpdate`]
 /// - [`update_action_state_from_interaction`](crate::systems::update_action_state_from_interaction), for triggering actions from buttons
 ///    - powers the [`ActionStateDriver`](crate::action_state::ActionStateDriver) component baseod on an [`Interaction`](bevy::ui::Interaction) component
 ///    - labeled [`InputManagerSystem::Update`]
+///    - not added if [`InputManagerPlugin::without_ui_interaction`] is used
+///
+/// When [`InputManagerPlugin::with_action_diffs`] is used, [`process_action_diffs`](crate::systems::process_action_diffs) is added on the server,
+/// and [`generate_action_diffs`](crate::systems::generate_action_diffs) on the client.
+/// [`InputManagerPlugin::with_action_diff_batches`] adds the equivalent systems for [`ActionDiffBatch`] instead.
+///
+/// Every system other than [`tick_action_state`](crate::systems::tick_action_state) runs in [`CoreStage::PreUpdate`],
+/// unless another stage is chosen with [`InputManagerPlugin::run_in`].
+///
+/// ## Lifecycle of `just_pressed` and `just_released`
+/// 1. In [`CoreStage::First`], directly after [`Time`](bevy_core::Time) is updated,
+///    [`InputManagerSystem::Tick`] clears every `just_pressed` and `just_released` edge.
+/// 2. In [`CoreStage::PreUpdate`], [`InputManagerSystem::Update`] reads the inputs and creates new edges.
+/// 3. Edges are then stable for every later system in the frame, in every stage,
+///    until they are cleared again at the start of the next frame.
+///
+/// As no tick ever happens part-way through a frame, systems in different stages can all rely on seeing the same edges.
+/// If edges should be cleared at some other time (such as once per fixed timestep),
+/// use [`InputManagerPlugin::manual_tick`] and schedule [`tick_action_state`](crate::systems::tick_action_state) yourself.
 pub struct InputManagerPlugin<A: Actionlike> {
     _phantom: PhantomData<A>,
     machine: Machine,
+    tick_automatically: bool,
+    release_on_focus_lost: bool,
+    clash_strategy: Option<ClashStrategy>,
+    stage: CoreStage,
+    #[cfg(feature = "ui")]
+    ui_interaction: bool,
+    add_action_diff_systems: Option<fn(&mut App, &Machine, CoreStage)>,
+    axis_events: bool,
 }
 
 // Deriving default induces an undesired bound on the generic
@@ -40,21 +108,157 @@ impl<A: Actionlike> Default for InputManagerPlugin<A> {
         Self {
             _phantom: PhantomData::default(),
             machine: Machine::Client,
+            tick_automatically: true,
+            release_on_focus_lost: false,
+            clash_strategy: None,
+            stage: CoreStage::PreUpdate,
+            #[cfg(feature = "ui")]
+            ui_interaction: true,
+            add_action_diff_systems: None,
+            axis_events: false,
         }
     }
 }
 
 impl<A: Actionlike> InputManagerPlugin<A> {
+    /// Creates a version of the plugin intended to run on the client, with the default configuration
+    ///
+    /// This is equivalent to [`InputManagerPlugin::default`],
+    /// and is intended as the starting point for the builder methods.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
     /// Creates a version of the plugin intended to run on the server
     ///
     /// Inputs will not be processed; instead, [`ActionState`](crate::action_state::ActionState)
     /// should be copied directly from the state provided by the client,
     /// or constructed from [`ActionDiff`](crate::action_state::ActionDiff) event streams.
+    /// Only [`tick_action_state`](crate::systems::tick_action_state) is added,
+    /// so none of the [`Input`](bevy_input::Input) resources need to exist.
+    ///
+    /// Use [`InputManagerPlugin::with_action_diffs`] to also apply the [`ActionDiff`] events received from clients.
     #[must_use]
     pub fn server() -> Self {
         Self {
-            _phantom: PhantomData::default(),
             machine: Machine::Server,
+            ..Self::default()
+        }
+    }
+
+    /// Does not add the [`tick_action_state`](crate::systems::tick_action_state) system,
+    /// so that `just_pressed` and `just_released` are only cleared when you choose
+    ///
+    /// Add [`tick_action_state`](crate::systems::tick_action_state) (or call [`ActionState::tick`](crate::action_state::ActionState::tick))
+    /// wherever the edges should be cleared.
+    /// It must run before [`InputManagerSystem::Update`] on any frame where inputs are collected,
+    /// or newly created edges will be cleared before they can be read.
+    #[must_use]
+    pub fn manual_tick(mut self) -> Self {
+        self.tick_automatically = false;
+        self
+    }
+
+    /// Adds the [`release_on_focus_lost`](crate::systems::release_on_focus_lost) system,
+    /// so that every action is released whenever a window loses focus
+    ///
+    /// Without this, keys held while the user tabs away can remain pressed until they are pressed and released again.
+    /// This has no effect on the server.
+    #[must_use]
+    pub fn release_on_focus_lost(mut self) -> Self {
+        self.release_on_focus_lost = true;
+        self
+    }
+
+    /// Inserts the `clash_strategy` as the [`ClashStrategy`] resource when the plugin is added
+    ///
+    /// [`ClashStrategy`] is shared by every action type,
+    /// so if several plugins set it, the last one added wins.
+    /// Without this, the [`ClashStrategy::default`] is used unless the resource already exists.
+    #[must_use]
+    pub fn with_clash_strategy(mut self, clash_strategy: ClashStrategy) -> Self {
+        self.clash_strategy = Some(clash_strategy);
+        self
+    }
+
+    /// Adds the systems that read inputs to the `stage`, rather than [`CoreStage::PreUpdate`]
+    ///
+    /// Inputs are still collected by Bevy in [`CoreStage::PreUpdate`],
+    /// so any later stage will see the inputs of the current frame.
+    /// Ticking is unaffected, and always happens in [`CoreStage::First`].
+    ///
+    /// The systems are only ordered after [`InputSystem`] and [`UiSystem::Focus`](bevy_ui::UiSystem::Focus)
+    /// when they run in [`CoreStage::PreUpdate`], as those labels do not exist in any other stage.
+    /// Stages run one after another, so later stages need no further ordering;
+    /// in [`CoreStage::First`], the systems read the inputs of the previous frame.
+    /// If you add your own input-collecting systems to the chosen stage,
+    /// order them before [`InputManagerSystem::Update`] yourself.
+    #[must_use]
+    pub fn run_in(mut self, stage: CoreStage) -> Self {
+        self.stage = stage;
+        self
+    }
+
+    /// Adds the [`ActionDiff`] event for the stable entity identifier `ID`, and the systems that use it
+    ///
+    /// On the server, [`process_action_diffs`](crate::systems::process_action_diffs) applies the received diffs,
+    /// and is labeled [`InputManagerSystem::Update`].
+    /// On the client, [`generate_action_diffs`](crate::systems::generate_action_diffs) creates them in [`CoreStage::PostUpdate`],
+    /// ready to be sent to the server.
+    #[must_use]
+    pub fn with_action_diffs<ID: Eq + Clone + Component>(mut self) -> Self {
+        self.add_action_diff_systems = Some(add_action_diff_systems::<A, ID>);
+        self
+    }
+
+    /// Like [`InputManagerPlugin::with_action_diffs`], but uses the [`ActionDiffBatch`] event,
+    /// so that the diffs of each entity are sent as a single event per frame
+    ///
+    /// On the server, [`process_action_diff_batches`](crate::systems::process_action_diff_batches) applies the received batches.
+    /// On the client, [`generate_action_diff_batches`](crate::systems::generate_action_diff_batches) creates them.
+    /// This replaces any previous call to [`InputManagerPlugin::with_action_diffs`].
+    #[must_use]
+    pub fn with_action_diff_batches<ID: Eq + Clone + Component>(mut self) -> Self {
+        self.add_action_diff_systems = Some(add_action_diff_batch_systems::<A, ID>);
+        self
+    }
+
+    /// Adds the [`AxisChanged`](crate::action_state::AxisChanged) event, and the
+    /// [`generate_axis_events`](crate::systems::generate_axis_events) system that sends it
+    ///
+    /// The events are sent after [`InputManagerSystem::ReleaseOnDisable`], so they include values set by
+    /// [`InputManagerSystem::ManualControl`] systems, and systems in later stages can read them in the same frame.
+    /// They are also sent while [`ToggleActions`] is disabled, so the release of every action is reported.
+    /// This has no effect on the server.
+    #[must_use]
+    pub fn with_axis_events(mut self) -> Self {
+        self.axis_events = true;
+        self
+    }
+
+    /// Does not add the [`update_action_state_from_interaction`](crate::systems::update_action_state_from_interaction) system,
+    /// so that [`ActionStateDriver`](crate::action_state::ActionStateDriver) components are ignored
+    #[cfg(feature = "ui")]
+    #[must_use]
+    pub fn without_ui_interaction(mut self) -> Self {
+        self.ui_interaction = false;
+        self
+    }
+}
+
+impl<A: Actionlike> InputManagerPlugin<A> {
+    /// Orders the `system` after the `label`, which only exists in [`CoreStage::PreUpdate`],
+    /// if this plugin's systems run in that stage
+    fn after_in_pre_update<Marker>(
+        &self,
+        system: ParallelSystemDescriptor,
+        label: impl AsSystemLabel<Marker>,
+    ) -> ParallelSystemDescriptor {
+        if self.stage == CoreStage::PreUpdate {
+            system.after(label)
+        } else {
+            system
         }
     }
 }
@@ -69,67 +273,243 @@ impl<A: Actionlike> Plugin for InputManagerPlugin<A> {
     fn build(&self, app: &mut App) {
         use crate::systems::*;
 
+        if self.tick_automatically {
+            // The exclusive system that updates `Time` runs at the start of this stage,
+            // so edges are always cleared before any other system can observe them
+            app.add_system_to_stage(
+                CoreStage::First,
+                tick_action_state::<A>
+                    .with_run_criteria(run_if_enabled::<A>)
+                    .label(InputManagerSystem::Tick),
+            );
+        }
+
         match self.machine {
             Machine::Client => {
                 app.add_system_to_stage(
-                    CoreStage::PreUpdate,
-                    tick_action_state::<A>
+                    self.stage.clone(),
+                    self.after_in_pre_update(
+                        assign_gamepads::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .before(InputManagerSystem::Update),
+                        InputSystem,
+                    ),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    self.after_in_pre_update(
+                        record_button_press_times.before(InputManagerSystem::Update),
+                        InputSystem,
+                    ),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    self.after_in_pre_update(
+                        update_mouse_motion.before(InputManagerSystem::Update),
+                        InputSystem,
+                    ),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    self.after_in_pre_update(
+                        update_action_state::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .label(InputManagerSystem::Update),
+                        InputSystem,
+                    ),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    self.after_in_pre_update(
+                        update_shared_action_states::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .label(InputManagerSystem::Update),
+                        InputSystem,
+                    ),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    release_on_gamepad_disconnect::<A>
                         .with_run_criteria(run_if_enabled::<A>)
-                        .label(InputManagerSystem::Tick)
-                        .before(InputManagerSystem::Update),
+                        .after(InputManagerSystem::Update)
+                        .before(InputManagerSystem::ManualControl),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    enforce_exclusive_groups::<A>
+                        .with_run_criteria(run_if_enabled::<A>)
+                        .after(InputManagerSystem::Update)
+                        .before(InputManagerSystem::ReleaseOnDisable),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    record_input::<A>
+                        .with_run_criteria(run_if_enabled::<A>)
+                        .after(InputManagerSystem::Update)
+                        .before(InputManagerSystem::ManualControl),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    playback_input::<A>
+                        .with_run_criteria(run_if_enabled::<A>)
+                        .label(InputManagerSystem::ManualControl)
+                        .after(InputManagerSystem::Update)
+                        .before(InputManagerSystem::ReleaseOnDisable),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    apply_input_macros::<A>
+                        .label(InputManagerSystem::ManualControl)
+                        .after(InputManagerSystem::Update)
+                        .before(InputManagerSystem::ReleaseOnDisable),
+                )
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    update_action_state_from_touches::<A>
+                        .with_run_criteria(run_if_enabled::<A>)
+                        .label(InputManagerSystem::ManualControl)
+                        .after(InputManagerSystem::Update)
+                        .before(InputManagerSystem::ReleaseOnDisable),
                 )
                 .add_system_to_stage(
-                    CoreStage::PreUpdate,
-                    update_action_state::<A>
+                    self.stage.clone(),
+                    update_action_state_from_virtual_joysticks::<A>
                         .with_run_criteria(run_if_enabled::<A>)
-                        .label(InputManagerSystem::Update)
-                        .after(InputSystem),
+                        .label(InputManagerSystem::ManualControl)
+                        .after(InputManagerSystem::Update)
+                        .before(InputManagerSystem::ReleaseOnDisable),
                 )
                 .add_system_to_stage(
-                    CoreStage::PreUpdate,
+                    self.stage.clone(),
                     release_on_disable::<A>
                         .label(InputManagerSystem::ReleaseOnDisable)
                         .after(InputManagerSystem::Update),
                 );
 
+                // The touchpad input is shared by every action type, so it is only managed by the first client plugin
+                if !app.world.contains_resource::<TouchpadSystems>() {
+                    app.init_resource::<TouchpadSystems>()
+                        .add_system_to_stage(CoreStage::First, clear_touchpad_input)
+                        .add_system_to_stage(
+                            self.stage.clone(),
+                            release_touchpad_swipes.after(InputManagerSystem::Update),
+                        );
+                }
+
+                app.init_resource::<ButtonPressTimes>()
+                    .add_event::<InputMapChanged<A>>()
+                    .add_system_to_stage(CoreStage::PostUpdate, detect_input_map_changes::<A>);
+
+                if self.axis_events {
+                    app.add_event::<AxisChanged<A>>().add_system_to_stage(
+                        self.stage.clone(),
+                        // Runs even while disabled, so that the values zeroed by `release_on_disable` are reported
+                        generate_axis_events::<A>.after(InputManagerSystem::ReleaseOnDisable),
+                    );
+                }
+
+                if self.release_on_focus_lost {
+                    app.add_system_to_stage(
+                        self.stage.clone(),
+                        release_on_focus_lost::<A>
+                            .with_run_criteria(run_if_enabled::<A>)
+                            .after(InputManagerSystem::ManualControl)
+                            .before(InputManagerSystem::ReleaseOnDisable),
+                    );
+                }
+
                 #[cfg(feature = "ui")]
-                app.add_system_to_stage(
-                    CoreStage::PreUpdate,
-                    update_action_state_from_interaction::<A>
+                if self.ui_interaction {
+                    let system = update_action_state_from_interaction::<A>
                         .with_run_criteria(run_if_enabled::<A>)
                         .label(InputManagerSystem::ManualControl)
                         .before(InputManagerSystem::ReleaseOnDisable)
-                        .after(InputManagerSystem::Tick)
                         // Must run after the system is updated from inputs, or it will be forcibly released due to the inputs
                         // not being pressed
-                        .after(InputManagerSystem::Update)
-                        .after(UiSystem::Focus)
-                        .after(InputSystem),
-                );
-            }
-            Machine::Server => {
-                app.add_system_to_stage(
-                    CoreStage::PreUpdate,
-                    tick_action_state::<A>
-                        .with_run_criteria(run_if_enabled::<A>)
-                        .label(InputManagerSystem::Tick)
-                        .before(InputManagerSystem::Update),
-                );
+                        .after(InputManagerSystem::Update);
+                    let system = self.after_in_pre_update(system, UiSystem::Focus);
+                    app.add_system_to_stage(
+                        self.stage.clone(),
+                        self.after_in_pre_update(system, InputSystem),
+                    );
+                }
             }
+            // Only ticking is needed on the server
+            Machine::Server => (),
         };
 
+        if let Some(add_action_diff_systems) = self.add_action_diff_systems {
+            add_action_diff_systems(app, &self.machine, self.stage.clone());
+        }
+
         // Resources
-        app.init_resource::<ToggleActions<A>>()
-            .init_resource::<ClashStrategy>();
+        app.init_resource::<ToggleActions<A>>();
+        match self.clash_strategy {
+            Some(clash_strategy) => app.insert_resource(clash_strategy),
+            None => app.init_resource::<ClashStrategy>(),
+        };
     }
 }
 
+/// Registers the [`ActionDiff`] event and its systems, as requested by [`InputManagerPlugin::with_action_diffs`]
+fn add_action_diff_systems<A: Actionlike, ID: Eq + Clone + Component>(
+    app: &mut App,
+    machine: &Machine,
+    stage: CoreStage,
+) {
+    use crate::systems::{generate_action_diffs, process_action_diffs, run_if_enabled};
+
+    app.add_event::<ActionDiff<A, ID>>();
+    match machine {
+        Machine::Server => app.add_system_to_stage(
+            stage,
+            process_action_diffs::<A, ID>
+                .with_run_criteria(run_if_enabled::<A>)
+                .label(InputManagerSystem::Update),
+        ),
+        Machine::Client => app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            generate_action_diffs::<A, ID>.with_run_criteria(run_if_enabled::<A>),
+        ),
+    };
+}
+
+/// Registers the [`ActionDiffBatch`] event and its systems, as requested by [`InputManagerPlugin::with_action_diff_batches`]
+fn add_action_diff_batch_systems<A: Actionlike, ID: Eq + Clone + Component>(
+    app: &mut App,
+    machine: &Machine,
+    stage: CoreStage,
+) {
+    use crate::systems::{
+        generate_action_diff_batches, process_action_diff_batches, run_if_enabled,
+    };
+
+    app.add_event::<ActionDiffBatch<A, ID>>();
+    match machine {
+        Machine::Server => app.add_system_to_stage(
+            stage,
+            process_action_diff_batches::<A, ID>
+                .with_run_criteria(run_if_enabled::<A>)
+                .label(InputManagerSystem::Update),
+        ),
+        Machine::Client => app.add_system_to_stage(
+            CoreStage::PostUpdate,
+            generate_action_diff_batches::<A, ID>.with_run_criteria(run_if_enabled::<A>),
+        ),
+    };
+}
+
 /// Controls whether or not the [`ActionState`] / [`InputMap`] pairs of type `A` are active
 pub struct ToggleActions<A: Actionlike> {
     /// When this is false, [`ActionState`]'s corresponding to `A` will ignore user inputs
     ///
-    /// When this is set to false, all corresponding [`ActionState`]s are released
+    /// When this is set to false, all corresponding [`ActionState`]s are released.
+    /// To disable only some of the actions, use [`ToggleActions::disable`] instead.
     pub enabled: bool,
+    /// Controls what happens to actions whose inputs are still held when `enabled` is set back to true
+    pub reenable_policy: ReenablePolicy,
+    /// Which actions have been individually disabled, indexed by [`Actionlike::index`]
+    disabled_actions: Vec<bool>,
     _phantom: PhantomData<A>,
 }
 
@@ -138,17 +518,215 @@ impl<A: Actionlike> Default for ToggleActions<A> {
     fn default() -> Self {
         Self {
             enabled: true,
+            reenable_policy: ReenablePolicy::default(),
+            disabled_actions: vec![false; A::N_VARIANTS],
             _phantom: PhantomData::<A>,
         }
     }
 }
 
+impl<A: Actionlike> ToggleActions<A> {
+    /// Disables only the `action`, while the other actions of type `A` remain active
+    ///
+    /// The `action` is released, and its inputs are ignored until it is [enabled](ToggleActions::enable) again.
+    /// Held inputs are handled according to the [`ReenablePolicy`], just like when all actions are disabled.
+    pub fn disable(&mut self, action: A) {
+        self.disabled_actions[action.index()] = true;
+    }
+
+    /// Re-enables an `action` that was disabled by [`ToggleActions::disable`]
+    ///
+    /// This has no effect while [`ToggleActions::enabled`] is false.
+    pub fn enable(&mut self, action: A) {
+        self.disabled_actions[action.index()] = false;
+    }
+
+    /// Is the `action` currently responding to inputs?
+    ///
+    /// This is false if either all actions or this particular action are disabled.
+    #[must_use]
+    pub fn action_enabled(&self, action: A) -> bool {
+        self.enabled && !self.disabled_actions[action.index()]
+    }
+
+    /// Is the `action` disabled by [`ToggleActions::disable`], regardless of [`ToggleActions::enabled`]?
+    pub(crate) fn individually_disabled(&self, action: A) -> bool {
+        self.disabled_actions[action.index()]
+    }
+
+    /// Strips the inputs of individually disabled actions from the `action_data` that is about to update the `action_state`
+    ///
+    /// Under [`ReenablePolicy::WaitForRelease`], disabled actions whose inputs are held are consumed instead,
+    /// so they stay released until their inputs are released, even after being re-enabled.
+    pub(crate) fn filter_disabled(
+        &self,
+        action_data: &mut [ActionData],
+        action_state: &mut ActionState<A>,
+    ) {
+        for action in A::variants() {
+            if !self.individually_disabled(action.clone()) {
+                continue;
+            }
+
+            let index = action.index();
+            match self.reenable_policy {
+                ReenablePolicy::PressHeld | ReenablePolicy::ContinueHeld => {
+                    action_data[index] = ActionData::default()
+                }
+                ReenablePolicy::WaitForRelease => {
+                    if action_data[index].state.pressed()
+                        && !action_state.action_data(action.clone()).consumed
+                    {
+                        action_state.consume(action);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// How actions whose inputs are held while [`ToggleActions`] is disabled behave once it is re-enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReenablePolicy {
+    /// Actions whose inputs are still held are pressed again as soon as input is re-enabled
+    ///
+    /// This produces a fresh `just_pressed` edge on the first frame after re-enabling.
+    #[default]
+    PressHeld,
+    /// Actions stay released until their inputs are released and pressed again
+    ///
+    /// This is achieved by [consuming](crate::action_state::ActionState::consume) every action when input is disabled,
+    /// and avoids triggering actions with inputs that were held down to close a menu.
+    WaitForRelease,
+    /// Actions whose inputs are still held are pressed again as soon as input is re-enabled,
+    /// as if they had been held all along
+    ///
+    /// Unlike [`ReenablePolicy::PressHeld`], this does not produce a `just_pressed` edge,
+    /// so held movement continues smoothly without retriggering one-shot actions.
+    /// The timing of these actions is kept from before they were disabled, and the new press is not counted.
+    ContinueHeld,
+}
+
+/// Flips [`ToggleActions<Target>`] whenever `action` is just pressed in an [`ActionState<Source>`](crate::action_state::ActionState)
+///
+/// This encodes the common "pause" pattern, where pressing a menu action disables gameplay input
+/// while leaving the menu input active.
+/// Insert this as a resource, and add the [`toggle_actions_on_press`](crate::systems::toggle_actions_on_press) system.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::plugin::{InputManagerSystem, ToggleActionsOnPress};
+/// use leafwing_input_manager::systems::toggle_actions_on_press;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum MenuAction {
+///     Pause,
+/// }
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum GameplayAction {
+///     Jump,
+/// }
+///
+/// App::new()
+///     .add_plugin(InputManagerPlugin::<MenuAction>::default())
+///     .add_plugin(InputManagerPlugin::<GameplayAction>::default())
+///     .insert_resource(ToggleActionsOnPress::<MenuAction, GameplayAction>::new(MenuAction::Pause))
+///     .add_system_to_stage(
+///         CoreStage::PreUpdate,
+///         toggle_actions_on_press::<MenuAction, GameplayAction>
+///             .after(InputManagerSystem::Update)
+///             .before(InputManagerSystem::ReleaseOnDisable),
+///     );
+/// ```
+pub struct ToggleActionsOnPress<Source: Actionlike, Target: Actionlike> {
+    /// The action which toggles whether or not actions of type `Target` are enabled
+    pub action: Source,
+    _phantom: PhantomData<Target>,
+}
+
+impl<Source: Actionlike, Target: Actionlike> ToggleActionsOnPress<Source, Target> {
+    /// Creates a new [`ToggleActionsOnPress`], which is triggered by the provided `action`
+    #[must_use]
+    pub fn new(action: Source) -> Self {
+        Self {
+            action,
+            _phantom: PhantomData::<Target>,
+        }
+    }
+}
+
+/// Groups of mutually exclusive actions of type `A`, where only one action in each group can be pressed at once
+///
+/// This encodes "radio button" semantics, as used for stance or weapon selection.
+/// Whenever an action in a group is just pressed, every other pressed action in that group is
+/// [consumed](crate::action_state::ActionState::consume), and will stay released until its inputs are released.
+/// If several actions in a group are just pressed during the same frame, the one that was added to the group first wins.
+///
+/// Insert this as a resource: the [`InputManagerPlugin`] enforces it automatically for every [`ActionState`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::plugin::ExclusiveGroups;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Sword,
+///     Bow,
+///     Staff,
+///     Jump,
+/// }
+///
+/// let mut exclusive_groups = ExclusiveGroups::<Action>::default();
+/// exclusive_groups.add_group([Action::Sword, Action::Bow, Action::Staff]);
+///
+/// App::new()
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .insert_resource(exclusive_groups);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExclusiveGroups<A: Actionlike> {
+    groups: Vec<Vec<A>>,
+}
+
+// Implement manually to not require [`Default`] for `A`
+impl<A: Actionlike> Default for ExclusiveGroups<A> {
+    fn default() -> Self {
+        Self { groups: Vec::new() }
+    }
+}
+
+impl<A: Actionlike> ExclusiveGroups<A> {
+    /// Adds a new group of mutually exclusive `actions`
+    ///
+    /// The order of the `actions` determines which action wins when several are pressed during the same frame.
+    pub fn add_group(&mut self, actions: impl IntoIterator<Item = A>) -> &mut Self {
+        self.groups.push(actions.into_iter().collect());
+        self
+    }
+
+    /// Returns an iterator over the registered groups
+    pub fn iter(&self) -> impl Iterator<Item = &[A]> {
+        self.groups.iter().map(Vec::as_slice)
+    }
+}
+
+/// Marks that the touchpad systems, which are shared by every action type, have already been added
+#[derive(Default)]
+struct TouchpadSystems;
+
 /// [`SystemLabel`]s for the [`crate::systems`] used by this crate
 ///
-/// `Reset` must occur before `Update`
+/// `Tick` must occur before `Update`
 #[derive(SystemLabel, Clone, Hash, Debug, PartialEq, Eq)]
 pub enum InputManagerSystem {
-    /// Advances actions timers to clean up the state of the input manager and clear `just_pressed` and just_released`
+    /// Advances actions timers to clean up the state of the input manager and clear `just_pressed` and `just_released`
+    ///
+    /// By default, this runs in [`CoreStage::First`], so edges persist for the whole of the frame in which they were created.
     Tick,
     /// Collects input data to update the [`ActionState`](crate::action_state::ActionState)
     Update,
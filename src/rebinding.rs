@@ -0,0 +1,229 @@
+//! Captures the next input pressed by the user, for "press a key now" rebinding menus
+
+use crate::user_input::UserInput;
+use crate::Actionlike;
+use bevy_app::App;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::{Command, Commands};
+use bevy_ecs::world::World;
+use bevy_input::keyboard::KeyCode;
+use std::marker::PhantomData;
+
+/// A resource that waits for the user to press an input, then binds it to an action
+///
+/// While capturing, the [`capture_binding`](crate::systems::capture_binding) system records the first
+/// keyboard key, mouse button or gamepad button that is pressed.
+/// The captured input replaces the existing bindings of the action for the same device
+/// in the targeted [`InputMap`](crate::input_map::InputMap), and the [`BindingCaptureOutcome`] is stored until it is read with [`BindingCapture::take_outcome`].
+///
+/// Pressing the [`cancel_key`](BindingCapture::cancel_key) ends capture without changing any bindings.
+///
+/// # Example
+/// ```rust
+/// use bevy_input::keyboard::KeyCode;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::rebinding::{BindingCapture, BindingCaptureOutcome};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// let mut binding_capture = BindingCapture::<Action>::default();
+/// // `None` targets the `InputMap<Action>` resource, rather than a component
+/// binding_capture.start(Action::Jump, None);
+/// assert_eq!(binding_capture.capturing(), Some(Action::Jump));
+///
+/// // Typically performed by the `capture_binding` system
+/// binding_capture.complete(KeyCode::Space.into());
+/// assert_eq!(binding_capture.capturing(), None);
+/// assert_eq!(
+///     binding_capture.take_outcome(),
+///     Some(BindingCaptureOutcome::Bound {
+///         action: Action::Jump,
+///         input: KeyCode::Space.into(),
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BindingCapture<A: Actionlike> {
+    /// The key that cancels capture, rather than being bound
+    ///
+    /// Defaults to [`KeyCode::Escape`].
+    pub cancel_key: KeyCode,
+    target: Option<(A, Option<Entity>)>,
+    outcome: Option<BindingCaptureOutcome<A>>,
+}
+
+// Implement manually to not require [`Default`] for `A`
+impl<A: Actionlike> Default for BindingCapture<A> {
+    fn default() -> Self {
+        Self {
+            cancel_key: KeyCode::Escape,
+            target: None,
+            outcome: None,
+        }
+    }
+}
+
+impl<A: Actionlike> BindingCapture<A> {
+    /// Begins waiting for an input to bind to the `action`
+    ///
+    /// The input is bound in the [`InputMap`](crate::input_map::InputMap) component of the `entity`,
+    /// or in the [`InputMap`](crate::input_map::InputMap) resource if `entity` is `None`.
+    /// Any capture already in progress is abandoned, and any unread outcome is discarded.
+    pub fn start(&mut self, action: A, entity: Option<Entity>) {
+        self.target = Some((action, entity));
+        self.outcome = None;
+    }
+
+    /// Ends capture without binding an input, recording [`BindingCaptureOutcome::Cancelled`]
+    ///
+    /// This has no effect if capture is not in progress.
+    pub fn cancel(&mut self) {
+        if let Some((action, _)) = self.target.take() {
+            self.outcome = Some(BindingCaptureOutcome::Cancelled { action });
+        }
+    }
+
+    /// Ends capture by binding the `input`, recording [`BindingCaptureOutcome::Bound`]
+    ///
+    /// This only records the outcome: the [`InputMap`](crate::input_map::InputMap) is updated by the
+    /// [`capture_binding`](crate::systems::capture_binding) system.
+    /// This has no effect if capture is not in progress.
+    pub fn complete(&mut self, input: UserInput) {
+        if let Some((action, _)) = self.target.take() {
+            self.outcome = Some(BindingCaptureOutcome::Bound { action, input });
+        }
+    }
+
+    /// The action that an input is currently being captured for, if any
+    #[must_use]
+    pub fn capturing(&self) -> Option<A> {
+        self.target.as_ref().map(|(action, _)| action.clone())
+    }
+
+    /// The entity whose [`InputMap`](crate::input_map::InputMap) will be changed by the capture in progress
+    ///
+    /// Returns `None` both when the resource is targeted, and when no capture is in progress.
+    #[must_use]
+    pub fn target_entity(&self) -> Option<Entity> {
+        self.target.as_ref().and_then(|(_, entity)| *entity)
+    }
+
+    /// The result of the most recent capture, if it has finished and has not yet been taken
+    #[must_use]
+    pub fn outcome(&self) -> Option<&BindingCaptureOutcome<A>> {
+        self.outcome.as_ref()
+    }
+
+    /// Removes and returns the result of the most recent capture
+    pub fn take_outcome(&mut self) -> Option<BindingCaptureOutcome<A>> {
+        self.outcome.take()
+    }
+}
+
+/// How a [`BindingCapture`] ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum BindingCaptureOutcome<A: Actionlike> {
+    /// The `input` was pressed, and is now bound to the `action`
+    Bound {
+        /// The action that was rebound
+        action: A,
+        /// The input that was captured
+        input: UserInput,
+    },
+    /// Capture was cancelled, and the bindings of the `action` were left unchanged
+    Cancelled {
+        /// The action that capture was started for
+        action: A,
+    },
+}
+
+/// Begins and cancels [`BindingCapture`], inserting the resource if needed
+///
+/// The [`capture_binding`](crate::systems::capture_binding) system must be added for inputs to actually be captured.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::rebinding::{BindingCapture, CaptureBinding};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// let mut app = App::new();
+/// app.start_binding_capture(Action::Jump, None);
+/// assert_eq!(
+///     app.world.resource::<BindingCapture<Action>>().capturing(),
+///     Some(Action::Jump)
+/// );
+/// ```
+pub trait CaptureBinding {
+    /// Begins waiting for an input to bind to the `action`, as described in [`BindingCapture::start`]
+    fn start_binding_capture<A: Actionlike>(&mut self, action: A, entity: Option<Entity>);
+
+    /// Cancels any [`BindingCapture<A>`] in progress
+    fn cancel_binding_capture<A: Actionlike>(&mut self);
+}
+
+impl CaptureBinding for World {
+    fn start_binding_capture<A: Actionlike>(&mut self, action: A, entity: Option<Entity>) {
+        self.get_resource_or_insert_with(BindingCapture::<A>::default)
+            .start(action, entity);
+    }
+
+    fn cancel_binding_capture<A: Actionlike>(&mut self) {
+        if let Some(mut binding_capture) = self.get_resource_mut::<BindingCapture<A>>() {
+            binding_capture.cancel();
+        }
+    }
+}
+
+impl CaptureBinding for App {
+    fn start_binding_capture<A: Actionlike>(&mut self, action: A, entity: Option<Entity>) {
+        self.world.start_binding_capture(action, entity);
+    }
+
+    fn cancel_binding_capture<A: Actionlike>(&mut self) {
+        self.world.cancel_binding_capture::<A>();
+    }
+}
+
+impl<'w, 's> CaptureBinding for Commands<'w, 's> {
+    fn start_binding_capture<A: Actionlike>(&mut self, action: A, entity: Option<Entity>) {
+        self.add(StartBindingCapture { action, entity });
+    }
+
+    fn cancel_binding_capture<A: Actionlike>(&mut self) {
+        self.add(CancelBindingCapture::<A> {
+            _phantom: PhantomData,
+        });
+    }
+}
+
+/// The [`Command`] used by [`CaptureBinding::start_binding_capture`]
+struct StartBindingCapture<A: Actionlike> {
+    action: A,
+    entity: Option<Entity>,
+}
+
+impl<A: Actionlike> Command for StartBindingCapture<A> {
+    fn write(self, world: &mut World) {
+        world.start_binding_capture(self.action, self.entity);
+    }
+}
+
+/// The [`Command`] used by [`CaptureBinding::cancel_binding_capture`]
+struct CancelBindingCapture<A: Actionlike> {
+    _phantom: PhantomData<A>,
+}
+
+impl<A: Actionlike> Command for CancelBindingCapture<A> {
+    fn write(self, world: &mut World) {
+        world.cancel_binding_capture::<A>();
+    }
+}
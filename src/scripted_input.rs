@@ -0,0 +1,733 @@
+//! Drives actions from a scripted timeline of presses and releases, for deterministic gameplay tests and input macros
+
+use crate::action_state::{ActionData, ActionState};
+use crate::buttonlike::ButtonState;
+use crate::Actionlike;
+use bevy_app::App;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::world::World;
+use bevy_utils::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Whether a [`ScriptedEvent`] presses or releases its action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScriptedEventKind {
+    /// The action is pressed, and held until it is released by a later event
+    Press,
+    /// The action is released
+    Release,
+}
+
+/// A single timed event in a [`ScriptedInput`] timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptedEvent<A: Actionlike> {
+    /// The time at which this event occurs, measured from the start of the script
+    pub time: Duration,
+    /// The action that is pressed or released
+    pub action: A,
+    /// Whether the action is pressed or released
+    pub kind: ScriptedEventKind,
+}
+
+/// A resource that stores a timeline of action presses and releases, applied over time to every [`ActionState<A>`](crate::action_state::ActionState)
+///
+/// This is higher-level than [`MockInput`](crate::MockInput): rather than sending inputs frame-by-frame,
+/// the whole timeline is described up front, making it well-suited for testing time-dependent behavior.
+/// Timelines can be loaded from data, as long as `A` implements [`Serialize`] and [`Deserialize`].
+///
+/// The timeline is applied by the [`apply_scripted_input`](crate::systems::apply_scripted_input) system,
+/// which is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually.
+/// It should run after [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update),
+/// so that scripted presses are not released by the absence of real inputs.
+///
+/// By default, the script follows the [`Time`](bevy_core::Time) resource, starting from the first frame it is applied.
+/// For fully deterministic tests, use [`ScriptedInput::with_fixed_timestep`] to advance by a fixed amount each frame instead.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_utils::Duration;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::plugin::InputManagerSystem;
+/// use leafwing_input_manager::scripted_input::ScriptedInput;
+/// use leafwing_input_manager::systems::apply_scripted_input;
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// let mut scripted_input = ScriptedInput::<Action>::default();
+/// scripted_input
+///     .press_at(Duration::from_millis(500), Action::Jump)
+///     .release_at(Duration::from_millis(700), Action::Jump);
+///
+/// App::new()
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .insert_resource(scripted_input.with_fixed_timestep(Duration::from_millis(100)))
+///     .add_system_to_stage(
+///         CoreStage::PreUpdate,
+///         apply_scripted_input::<Action>
+///             .label(InputManagerSystem::ManualControl)
+///             .after(InputManagerSystem::Update),
+///     );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScriptedInput<A: Actionlike> {
+    /// The events of the timeline, sorted by time
+    events: Vec<ScriptedEvent<A>>,
+    /// If set, the script advances by this much each time it is applied, rather than following the clock
+    #[serde(default)]
+    fixed_timestep: Option<Duration>,
+    #[serde(skip)]
+    started: Option<Instant>,
+    #[serde(skip)]
+    elapsed: Duration,
+    #[serde(skip)]
+    n_advances: u32,
+    /// The index of the first event that has not yet been applied
+    #[serde(skip)]
+    cursor: usize,
+}
+
+// Implement manually to not require [`Default`] for `A`
+impl<A: Actionlike> Default for ScriptedInput<A> {
+    fn default() -> Self {
+        Self {
+            events: Vec::new(),
+            fixed_timestep: None,
+            started: None,
+            elapsed: Duration::ZERO,
+            n_advances: 0,
+            cursor: 0,
+        }
+    }
+}
+
+// Building the timeline
+impl<A: Actionlike> ScriptedInput<A> {
+    /// Creates a new [`ScriptedInput`] from the provided `events`, which may be in any order
+    #[must_use]
+    pub fn new(events: impl IntoIterator<Item = ScriptedEvent<A>>) -> Self {
+        let mut scripted_input = ScriptedInput::default();
+        for event in events {
+            scripted_input.insert(event);
+        }
+        scripted_input
+    }
+
+    /// Adds an `event` to the timeline
+    ///
+    /// Events that occur at the same time are applied in the order that they were added.
+    pub fn insert(&mut self, event: ScriptedEvent<A>) -> &mut Self {
+        let index = self
+            .events
+            .partition_point(|other| other.time <= event.time);
+        self.events.insert(index, event);
+        self
+    }
+
+    /// Presses the `action` at the provided `time`
+    pub fn press_at(&mut self, time: Duration, action: A) -> &mut Self {
+        self.insert(ScriptedEvent {
+            time,
+            action,
+            kind: ScriptedEventKind::Press,
+        })
+    }
+
+    /// Releases the `action` at the provided `time`
+    pub fn release_at(&mut self, time: Duration, action: A) -> &mut Self {
+        self.insert(ScriptedEvent {
+            time,
+            action,
+            kind: ScriptedEventKind::Release,
+        })
+    }
+
+    /// Advances the script by `timestep` each time it is applied, rather than following the clock
+    #[must_use]
+    pub fn with_fixed_timestep(mut self, timestep: Duration) -> Self {
+        self.fixed_timestep = Some(timestep);
+        self
+    }
+}
+
+// Playback
+impl<A: Actionlike> ScriptedInput<A> {
+    /// Advances the script to `current_time`, returning the events that occurred since the last advance, in order
+    ///
+    /// If a fixed timestep is set, `current_time` is ignored.
+    /// Typically called by [`apply_scripted_input`](crate::systems::apply_scripted_input).
+    pub fn advance(&mut self, current_time: Instant) -> &[ScriptedEvent<A>] {
+        self.elapsed = match self.fixed_timestep {
+            Some(timestep) => timestep * self.n_advances,
+            None => current_time - *self.started.get_or_insert(current_time),
+        };
+        self.n_advances += 1;
+
+        let start = self.cursor;
+        while self.cursor < self.events.len() && self.events[self.cursor].time <= self.elapsed {
+            self.cursor += 1;
+        }
+
+        &self.events[start..self.cursor]
+    }
+
+    /// Returns the actions pressed by the events that have been applied so far, and not yet released
+    #[must_use]
+    pub fn held(&self) -> Vec<A> {
+        let mut held = vec![false; A::N_VARIANTS];
+        for event in &self.events[..self.cursor] {
+            held[event.action.index()] = event.kind == ScriptedEventKind::Press;
+        }
+
+        A::variants()
+            .filter(|action| held[action.index()])
+            .collect()
+    }
+
+    /// How far into the timeline has the script advanced?
+    #[inline]
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Have all of the events in the timeline been applied?
+    #[inline]
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.cursor == self.events.len()
+    }
+
+    /// Restarts the script from the beginning
+    pub fn restart(&mut self) {
+        self.started = None;
+        self.elapsed = Duration::ZERO;
+        self.n_advances = 0;
+        self.cursor = 0;
+    }
+}
+
+/// A single step of an [`InputMacro`]: `action` is pressed `delay` after the macro starts, and held for `duration`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MacroStep<A: Actionlike> {
+    /// The action that is pressed
+    pub action: A,
+    /// How long after the macro is triggered the action is pressed
+    pub delay: Duration,
+    /// How long the action is held for
+    ///
+    /// Actions are always held for at least one frame, even if this is [`Duration::ZERO`].
+    pub duration: Duration,
+}
+
+/// A timed sequence of actions, played back whenever the `trigger` action is just pressed
+///
+/// Register these with the [`InputMacros`] resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputMacro<A: Actionlike> {
+    /// The action that starts the macro
+    pub trigger: A,
+    /// The steps of the macro, which may overlap
+    pub steps: Vec<MacroStep<A>>,
+    /// Should the macro be cancelled if the `trigger` is released before it has finished?
+    pub cancel_on_release: bool,
+}
+
+impl<A: Actionlike> InputMacro<A> {
+    /// Creates a new [`InputMacro`] with no steps, which is started by the `trigger` action
+    #[must_use]
+    pub fn new(trigger: A) -> Self {
+        InputMacro {
+            trigger,
+            steps: Vec::new(),
+            cancel_on_release: false,
+        }
+    }
+
+    /// Adds a step, which presses the `action` `delay` after the macro starts and holds it for `duration`
+    #[must_use]
+    pub fn step(mut self, action: A, delay: Duration, duration: Duration) -> Self {
+        self.steps.push(MacroStep {
+            action,
+            delay,
+            duration,
+        });
+        self
+    }
+
+    /// Cancels the macro if the `trigger` is released before the macro has finished
+    #[must_use]
+    pub fn cancel_on_release(mut self) -> Self {
+        self.cancel_on_release = true;
+        self
+    }
+
+    /// Converts the steps of this macro into a [`ScriptedInput`] timeline
+    #[must_use]
+    pub fn timeline(&self) -> ScriptedInput<A> {
+        let mut timeline = ScriptedInput::default();
+        for step in self.steps.iter() {
+            timeline
+                .press_at(step.delay, step.action.clone())
+                .release_at(step.delay + step.duration, step.action.clone());
+        }
+        timeline
+    }
+}
+
+/// A macro that is currently being played back for a single [`ActionState`]
+#[derive(Debug, Clone, PartialEq)]
+struct MacroPlayback<A: Actionlike> {
+    /// The entity whose [`ActionState`] is being driven, or `None` for the [`ActionState`] resource
+    target: Option<Entity>,
+    /// The index of the macro in [`InputMacros`]
+    macro_index: usize,
+    timeline: ScriptedInput<A>,
+}
+
+impl<A: Actionlike> MacroPlayback<A> {
+    /// The actions currently held by this playback, paired with its target
+    fn held(&self) -> impl Iterator<Item = (Option<Entity>, A)> {
+        let target = self.target;
+        self.timeline
+            .held()
+            .into_iter()
+            .map(move |action| (target, action))
+    }
+}
+
+/// A resource that stores the [`InputMacro`]s for actions of type `A`, and tracks the macros that are currently playing
+///
+/// Macros are played back by the [`apply_input_macros`](crate::systems::apply_input_macros) system,
+/// which is added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin).
+/// Each [`ActionState`] that presses the trigger of a macro plays it back independently.
+///
+/// Macros only ever press actions: they are combined with real inputs,
+/// and the actions that they press are released by the absence of real inputs once they are no longer held by the macro.
+/// As a result, real inputs that are held throughout a macro are never released by it.
+/// Pressing the trigger of a macro while it is still playing restarts it.
+/// Cancelled or restarted macros release the actions they were holding the next time their [`ActionState`] is played back.
+///
+/// All playing macros are cancelled when [`ToggleActions`](crate::plugin::ToggleActions) is disabled.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_utils::Duration;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::scripted_input::{InputMacro, InputMacros};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Hadouken,
+///     Down,
+///     Forward,
+///     Punch,
+/// }
+///
+/// let mut input_macros = InputMacros::<Action>::default();
+/// input_macros.add(
+///     InputMacro::new(Action::Hadouken)
+///         .step(Action::Down, Duration::ZERO, Duration::from_millis(50))
+///         .step(Action::Forward, Duration::from_millis(50), Duration::from_millis(50))
+///         .step(Action::Punch, Duration::from_millis(100), Duration::from_millis(50)),
+/// );
+///
+/// App::new()
+///     .add_plugin(InputManagerPlugin::<Action>::default())
+///     .insert_resource(input_macros);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputMacros<A: Actionlike> {
+    macros: Vec<InputMacro<A>>,
+    /// If set, playing macros advance by this much each frame, rather than following the clock
+    fixed_timestep: Option<Duration>,
+    playing: Vec<MacroPlayback<A>>,
+    /// Actions that were held by cancelled playbacks, and must be released for their target
+    releasing: Vec<(Option<Entity>, A)>,
+}
+
+// Implement manually to not require [`Default`] for `A`
+impl<A: Actionlike> Default for InputMacros<A> {
+    fn default() -> Self {
+        Self {
+            macros: Vec::new(),
+            fixed_timestep: None,
+            playing: Vec::new(),
+            releasing: Vec::new(),
+        }
+    }
+}
+
+impl<A: Actionlike> InputMacros<A> {
+    /// Registers a new `input_macro`
+    pub fn add(&mut self, input_macro: InputMacro<A>) -> &mut Self {
+        self.macros.push(input_macro);
+        self
+    }
+
+    /// Advances playing macros by `timestep` each frame, rather than following the clock
+    #[must_use]
+    pub fn with_fixed_timestep(mut self, timestep: Duration) -> Self {
+        self.fixed_timestep = Some(timestep);
+        self
+    }
+
+    /// Returns an iterator over the registered macros
+    pub fn iter(&self) -> impl Iterator<Item = &InputMacro<A>> {
+        self.macros.iter()
+    }
+
+    /// Is any macro currently playing for the `target` [`ActionState`]?
+    ///
+    /// A `target` of `None` corresponds to the [`ActionState`] resource.
+    #[must_use]
+    pub fn is_playing(&self, target: Option<Entity>) -> bool {
+        self.playing
+            .iter()
+            .any(|playback| playback.target == target)
+    }
+
+    /// Cancels every macro that is playing for the `target` [`ActionState`]
+    ///
+    /// The actions held by the cancelled macros are released by [`InputMacros::release_cancelled`],
+    /// unless their inputs are pressed.
+    pub fn cancel(&mut self, target: Option<Entity>) {
+        let releasing = &mut self.releasing;
+        self.playing.retain(|playback| {
+            let cancelled = playback.target == target;
+            if cancelled {
+                releasing.extend(playback.held());
+            }
+            !cancelled
+        });
+    }
+
+    /// Cancels every playing macro
+    ///
+    /// The actions held by the cancelled macros are released by [`InputMacros::release_cancelled`],
+    /// unless their inputs are pressed.
+    pub fn cancel_all(&mut self) {
+        for playback in self.playing.drain(..) {
+            self.releasing.extend(playback.held());
+        }
+    }
+
+    /// Releases the actions of the `target` [`ActionState`] that were held by cancelled or restarted macros
+    ///
+    /// Actions that are still pressed by real inputs are no longer held, but stay pressed.
+    /// This is called by [`InputMacros::apply`], before any macros are played back.
+    pub fn release_cancelled(&mut self, target: Option<Entity>, action_state: &mut ActionState<A>) {
+        self.releasing.retain(|(releasing_target, action)| {
+            if *releasing_target != target {
+                return true;
+            }
+
+            release_held(action_state, action.clone());
+            false
+        });
+    }
+
+    /// Starts any macros triggered by the `action_state` of the `target`, and then plays back each of its macros until `current_time`
+    ///
+    /// Typically called by [`apply_input_macros`](crate::systems::apply_input_macros).
+    pub fn apply(
+        &mut self,
+        target: Option<Entity>,
+        action_state: &mut ActionState<A>,
+        current_time: Instant,
+    ) {
+        for (macro_index, input_macro) in self.macros.iter().enumerate() {
+            if action_state.just_pressed(input_macro.trigger.clone()) {
+                let releasing = &mut self.releasing;
+                self.playing.retain(|playback| {
+                    let restarted =
+                        playback.target == target && playback.macro_index == macro_index;
+                    if restarted {
+                        releasing.extend(playback.held());
+                    }
+                    !restarted
+                });
+
+                let mut timeline = input_macro.timeline();
+                if let Some(timestep) = self.fixed_timestep {
+                    timeline = timeline.with_fixed_timestep(timestep);
+                }
+
+                self.playing.push(MacroPlayback {
+                    target,
+                    macro_index,
+                    timeline,
+                });
+            }
+        }
+
+        self.release_cancelled(target, action_state);
+
+        let macros = &self.macros;
+        self.playing.retain_mut(|playback| {
+            if playback.target != target {
+                return true;
+            }
+
+            let input_macro = &macros[playback.macro_index];
+            if input_macro.cancel_on_release && action_state.released(input_macro.trigger.clone()) {
+                for action in playback.timeline.held() {
+                    release_held(action_state, action);
+                }
+                return false;
+            }
+
+            for event in playback.timeline.advance(current_time) {
+                let action = event.action.clone();
+                match event.kind {
+                    ScriptedEventKind::Press => action_state.hold(action),
+                    // Steps that begin and end within a single frame are still pressed for that frame,
+                    // and real inputs keep their actions pressed
+                    ScriptedEventKind::Release
+                        if action_state.just_pressed(action.clone())
+                            || !action_state.reasons_pressed(action.clone()).is_empty() =>
+                    {
+                        action_state.stop_holding(action)
+                    }
+                    ScriptedEventKind::Release => action_state.release(action),
+                }
+            }
+
+            for action in playback.timeline.held() {
+                action_state.hold(action);
+            }
+
+            !playback.timeline.is_finished()
+        });
+    }
+
+    /// Drops the macros whose targets no longer satisfy the `predicate`, such as despawned entities
+    ///
+    /// As these targets have no [`ActionState`] left to release, their held actions are discarded.
+    pub(crate) fn retain_targets(&mut self, predicate: impl Fn(Option<Entity>) -> bool) {
+        self.playing.retain(|playback| predicate(playback.target));
+        self.releasing.retain(|(target, _)| predicate(*target));
+    }
+}
+
+/// Stops holding an `action` that was held by a macro, releasing it unless it is pressed by real inputs
+fn release_held<A: Actionlike>(action_state: &mut ActionState<A>, action: A) {
+    action_state.stop_holding(action.clone());
+    if action_state.reasons_pressed(action.clone()).is_empty() {
+        action_state.release(action);
+    }
+}
+
+/// The inputs of a single frame, as captured by [`CapturedInput`]
+///
+/// Each vector is ordered by [`Actionlike::index`], in the form produced by
+/// [`InputMap::which_pressed`](crate::input_map::InputMap::which_pressed) and consumed by [`ActionState::update`].
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct CapturedFrame {
+    /// The inputs of the [`ActionState`] resource, if it exists
+    pub resource: Option<Vec<ActionData>>,
+    /// The inputs of each [`ActionState`] component, keyed by the [`Entity::to_bits`] of its entity
+    pub entities: Vec<(u64, Vec<ActionData>)>,
+}
+
+/// Whether a [`CapturedInput`] is currently recording or playing back
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum CaptureMode {
+    #[default]
+    Idle,
+    Recording,
+    Playing,
+}
+
+/// A resource that records the inputs of every [`ActionState<A>`] frame-by-frame, and plays them back for deterministic replays
+///
+/// While recording, the [`record_input`](crate::systems::record_input) system stores the result of
+/// each frame's update, after clashes have been resolved.
+/// During playback, the [`playback_input`](crate::systems::playback_input) system feeds each recorded frame back through
+/// [`ActionState::update`], in place of the real inputs.
+/// Both systems are part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin),
+/// and do nothing unless this resource exists and is recording or playing.
+///
+/// Entities are matched by their [`Entity`] identifier, so replays should spawn their entities in the same order.
+/// Captured frames can be saved to disk as they implement [`Serialize`] and [`Deserialize`];
+/// the recording or playback state is not stored.
+/// Use the [`InputCapture`] trait to conveniently control this resource on an [`App`] or [`World`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CapturedInput<A: Actionlike> {
+    frames: Vec<CapturedFrame>,
+    #[serde(skip)]
+    mode: CaptureMode,
+    /// The index of the next frame to play back
+    #[serde(skip)]
+    cursor: usize,
+    #[serde(skip)]
+    _phantom: PhantomData<A>,
+}
+
+// Implement manually to not require [`Default`] for `A`
+impl<A: Actionlike> Default for CapturedInput<A> {
+    fn default() -> Self {
+        Self {
+            frames: Vec::new(),
+            mode: CaptureMode::Idle,
+            cursor: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A: Actionlike> CapturedInput<A> {
+    /// Discards any previously captured frames, and begins recording new ones
+    pub fn start_recording(&mut self) {
+        self.frames.clear();
+        self.mode = CaptureMode::Recording;
+    }
+
+    /// Begins playing back the captured frames from the start
+    pub fn start_playback(&mut self) {
+        self.cursor = 0;
+        self.mode = CaptureMode::Playing;
+    }
+
+    /// Stops any recording or playback, keeping the captured frames
+    pub fn stop(&mut self) {
+        self.mode = CaptureMode::Idle;
+    }
+
+    /// Is input currently being recorded?
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.mode == CaptureMode::Recording
+    }
+
+    /// Is input currently being played back?
+    ///
+    /// Playback stops automatically once every captured frame has been played.
+    #[must_use]
+    pub fn is_playing(&self) -> bool {
+        self.mode == CaptureMode::Playing
+    }
+
+    /// The frames that have been captured, in order
+    #[must_use]
+    pub fn frames(&self) -> &[CapturedFrame] {
+        &self.frames
+    }
+
+    /// Stores a `frame` at the end of the recording, if input is being recorded
+    ///
+    /// Typically called by [`record_input`](crate::systems::record_input).
+    pub fn record(&mut self, frame: CapturedFrame) {
+        if self.is_recording() {
+            self.frames.push(frame);
+        }
+    }
+
+    /// Returns the next frame to play back, if input is being played back
+    ///
+    /// Typically called by [`playback_input`](crate::systems::playback_input).
+    pub fn next_frame(&mut self) -> Option<&CapturedFrame> {
+        if !self.is_playing() {
+            return None;
+        }
+
+        let frame = self.frames.get(self.cursor);
+        self.cursor += 1;
+        if self.cursor >= self.frames.len() {
+            self.mode = CaptureMode::Idle;
+        }
+        frame
+    }
+
+    /// Captures the current inputs of the `action_state`, in the form consumed by [`ActionState::update`]
+    #[must_use]
+    pub fn capture(action_state: &ActionState<A>) -> Vec<ActionData> {
+        A::variants()
+            .map(|action| {
+                let action_data = action_state.action_data(action);
+                ActionData {
+                    state: if action_data.state.pressed() {
+                        ButtonState::Pressed
+                    } else {
+                        ButtonState::Released
+                    },
+                    reasons_pressed: action_data.reasons_pressed,
+                    partial_chords: action_data.partial_chords,
+                    suppressed: action_data.suppressed,
+                    axis_data: action_data.axis_data,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+}
+
+/// Controls the [`CapturedInput`] resource, in the style of [`MockInput`](crate::MockInput)
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::scripted_input::{CapturedInput, InputCapture};
+///
+/// #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Action {
+///     Jump,
+/// }
+///
+/// let mut app = App::new();
+/// app.start_recording::<Action>();
+/// assert!(app.world.resource::<CapturedInput<Action>>().is_recording());
+///
+/// app.start_playback::<Action>();
+/// assert!(app.world.resource::<CapturedInput<Action>>().is_playing());
+/// ```
+pub trait InputCapture {
+    /// Begins recording the inputs of every [`ActionState<A>`], discarding any previous recording
+    ///
+    /// The [`CapturedInput<A>`] resource is inserted if it does not already exist.
+    fn start_recording<A: Actionlike>(&mut self);
+
+    /// Stops recording or playing back the inputs of [`ActionState<A>`]
+    fn stop_recording<A: Actionlike>(&mut self);
+
+    /// Begins playing back the recorded inputs of [`ActionState<A>`] from the start
+    fn start_playback<A: Actionlike>(&mut self);
+}
+
+impl InputCapture for World {
+    fn start_recording<A: Actionlike>(&mut self) {
+        self.get_resource_or_insert_with(CapturedInput::<A>::default)
+            .start_recording();
+    }
+
+    fn stop_recording<A: Actionlike>(&mut self) {
+        if let Some(mut captured_input) = self.get_resource_mut::<CapturedInput<A>>() {
+            captured_input.stop();
+        }
+    }
+
+    fn start_playback<A: Actionlike>(&mut self) {
+        self.get_resource_or_insert_with(CapturedInput::<A>::default)
+            .start_playback();
+    }
+}
+
+impl InputCapture for App {
+    fn start_recording<A: Actionlike>(&mut self) {
+        self.world.start_recording::<A>();
+    }
+
+    fn stop_recording<A: Actionlike>(&mut self) {
+        self.world.stop_recording::<A>();
+    }
+
+    fn start_playback<A: Actionlike>(&mut self) {
+        self.world.start_playback::<A>();
+    }
+}
@@ -3,17 +3,43 @@
 #[cfg(feature = "ui")]
 use crate::action_state::ActionStateDriver;
 use crate::{
-    action_state::{ActionDiff, ActionState},
-    clashing_inputs::ClashStrategy,
-    input_map::InputMap,
-    plugin::ToggleActions,
-    user_input::InputStreams,
+    action_state::{
+        ActionData, ActionDiff, ActionDiffBatch, ActionState, AxisChanged, ACTION_DIFF_EPSILON,
+    },
+    axislike::DualAxisData,
+    buttonlike::ButtonState,
+    clashing_inputs::{
+        ActionPriority, ClashResolver, ClashStrategy, CustomClashResolver, SocdCleaning,
+    },
+    input_context::InputContext,
+    input_map::{GamepadAssignment, InputMap, InputMapChanged, SharedInputMap},
+    plugin::{ExclusiveGroups, ReenablePolicy, ToggleActions, ToggleActionsOnPress},
+    rebinding::{BindingCapture, BindingCaptureOutcome},
+    scripted_input::{CapturedFrame, CapturedInput, InputMacros, ScriptedEventKind, ScriptedInput},
+    text_input::{TextInput, ToggleTextInputOnPress},
+    touch::{VirtualButton, VirtualJoystick},
+    user_input::{
+        ButtonPressTimes, GamepadTouchpadButton, InputStreams, MouseMotionConfig, UserInput,
+    },
     Actionlike,
 };
 
 use bevy_core::Time;
-use bevy_ecs::{prelude::*, schedule::ShouldRun};
-use bevy_input::{gamepad::GamepadButton, keyboard::KeyCode, mouse::MouseButton, Input};
+use bevy_ecs::{
+    event::{Events, ManualEventReader},
+    prelude::*,
+    schedule::ShouldRun,
+};
+use bevy_input::{
+    gamepad::{Gamepad, GamepadAxis, GamepadButton, GamepadEvent, GamepadEventType, Gamepads},
+    keyboard::KeyCode,
+    mouse::{MouseButton, MouseMotion, MouseWheel},
+    touch::Touches,
+    Axis, Input,
+};
+use bevy_math::Vec2;
+use bevy_utils::HashMap;
+use bevy_window::{ReceivedCharacter, WindowFocused};
 
 #[cfg(feature = "ui")]
 use bevy_ui::Interaction;
@@ -21,7 +47,8 @@ use bevy_ui::Interaction;
 /// Advances actions timer.
 ///
 /// Clears the just-pressed and just-released values of all [`ActionState`]s.
-/// Also resets the internal `pressed_this_tick` field, used to track whether or not to release an action.
+/// Also resets the internal `pressed_this_tick` field, used to track whether or not to release an action,
+/// and counts down the [`Cooldown`](crate::action_state::Cooldown) of each action.
 pub fn tick_action_state<A: Actionlike>(
     mut query: Query<&mut ActionState<A>>,
     action_state: Option<ResMut<ActionState<A>>>,
@@ -43,89 +70,647 @@ pub fn tick_action_state<A: Actionlike>(
 
 /// Fetches all of the releveant [`Input`] resources to update [`ActionState`] according to the [`InputMap`]
 ///
-/// Missing resources will be ignored, and treated as if none of the corresponding inputs were pressed
+/// Missing resources will be ignored, and treated as if none of the corresponding inputs were pressed.
+/// Real inputs are ignored while the [`CapturedInput`] resource is playing back.
+/// The [`AxisThresholds`](crate::axislike::AxisThresholds) of each [`InputMap`] are applied here,
+/// as they depend on whether each action was already pressed.
+/// Every action is released while `A` is suppressed by the [`InputContext`] resource,
+/// and actions whose inputs are held stay released until those inputs are pressed again.
+///
+/// Clashing inputs are resolved using the [`ClashStrategy`] override of each [`InputMap`], if it has one.
+/// [`ActionState`]s without a matching [`InputMap`] are skipped, so they can be driven purely by code.
 #[allow(clippy::too_many_arguments)]
 pub fn update_action_state<A: Actionlike>(
     maybe_gamepad_input_stream: Option<Res<Input<GamepadButton>>>,
     maybe_keyboard_input_stream: Option<Res<Input<KeyCode>>>,
     maybe_mouse_input_stream: Option<Res<Input<MouseButton>>>,
+    maybe_touchpad_input_stream: Option<Res<Input<GamepadTouchpadButton>>>,
+    maybe_gamepad_axis_stream: Option<Res<Axis<GamepadAxis>>>,
+    maybe_mouse_motion_events: Option<Res<Events<MouseMotion>>>,
+    maybe_mouse_wheel_events: Option<Res<Events<MouseWheel>>>,
+    maybe_press_times: Option<Res<ButtonPressTimes>>,
     clash_strategy: Res<ClashStrategy>,
+    custom_clash_resolver: Option<Res<CustomClashResolver<A>>>,
+    socd_cleaning: Option<Res<SocdCleaning<A>>>,
+    captured_input: Option<Res<CapturedInput<A>>>,
+    (toggle_actions, input_context, maybe_mouse_motion_config): (
+        Option<Res<ToggleActions<A>>>,
+        Option<Res<InputContext>>,
+        Option<Res<MouseMotionConfig>>,
+    ),
     mut action_state: Option<ResMut<ActionState<A>>>,
     mut input_map: Option<ResMut<InputMap<A>>>,
-    mut query: Query<(&mut ActionState<A>, &InputMap<A>)>,
+    mut query: Query<(
+        &mut ActionState<A>,
+        &InputMap<A>,
+        Option<&ActionPriority<A>>,
+    )>,
 ) {
-    let gamepad = maybe_gamepad_input_stream.as_deref();
-
-    let keyboard = maybe_keyboard_input_stream.as_deref();
+    if is_playing_back(captured_input.as_deref()) {
+        return;
+    }
 
-    let mouse = maybe_mouse_input_stream.as_deref();
+    let resolver = ActionDataResolver {
+        gamepad: maybe_gamepad_input_stream.as_deref(),
+        keyboard: maybe_keyboard_input_stream.as_deref(),
+        mouse: maybe_mouse_input_stream.as_deref(),
+        touchpad: maybe_touchpad_input_stream.as_deref(),
+        gamepad_axes: maybe_gamepad_axis_stream.as_deref(),
+        mouse_motion: maybe_mouse_motion_events.as_deref(),
+        mouse_wheel: maybe_mouse_wheel_events.as_deref(),
+        press_times: maybe_press_times.as_deref(),
+        mouse_motion_config: maybe_mouse_motion_config.as_deref(),
+        clash_resolver: active_clash_resolver(&clash_strategy, custom_clash_resolver.as_deref()),
+        socd_cleaning: socd_cleaning.as_deref(),
+        toggle_actions: toggle_actions.as_deref(),
+        suppressed: is_suppressed::<A>(input_context.as_deref()),
+    };
 
     if let (Some(input_map), Some(action_state)) = (&mut input_map, &mut action_state) {
-        let input_streams = InputStreams {
-            gamepad,
-            keyboard,
-            mouse,
-            associated_gamepad: input_map.gamepad(),
-        };
+        let action_data = resolver.action_data(input_map, None, action_state);
+        action_state.update(action_data);
+    }
 
-        action_state.update(input_map.which_pressed(&input_streams, *clash_strategy));
+    for (mut action_state, input_map, action_priority) in query.iter_mut() {
+        let action_data = resolver.action_data(input_map, action_priority, &mut action_state);
+        action_state.update(action_data);
     }
+}
 
-    for (mut action_state, input_map) in query.iter_mut() {
-        let input_streams = InputStreams {
-            gamepad,
-            keyboard,
-            mouse,
-            associated_gamepad: input_map.gamepad(),
+/// Records when each keyboard key, mouse button and gamepad button was pressed in the [`ButtonPressTimes`] resource
+///
+/// These times are used to check the windows of chords inserted with [`InputMap::insert_chord_within`].
+/// Does nothing if the [`ButtonPressTimes`] resource does not exist, or if [`Time`] has not yet been updated.
+pub fn record_button_press_times(
+    maybe_gamepad_input_stream: Option<Res<Input<GamepadButton>>>,
+    maybe_keyboard_input_stream: Option<Res<Input<KeyCode>>>,
+    maybe_mouse_input_stream: Option<Res<Input<MouseButton>>>,
+    time: Res<Time>,
+    press_times: Option<ResMut<ButtonPressTimes>>,
+) {
+    if let (Some(mut press_times), Some(now)) = (press_times, time.last_update()) {
+        press_times.update(
+            maybe_keyboard_input_stream.as_deref(),
+            maybe_mouse_input_stream.as_deref(),
+            maybe_gamepad_input_stream.as_deref(),
+            now,
+        );
+    }
+}
+
+/// Processes the [`MouseMotion`] events of this frame using the [`MouseMotionConfig`] resource
+///
+/// Does nothing if the [`MouseMotionConfig`] resource does not exist, or if [`Time`] has not yet been updated.
+pub fn update_mouse_motion(
+    time: Res<Time>,
+    mouse_motion: Option<Res<Events<MouseMotion>>>,
+    mouse_motion_config: Option<ResMut<MouseMotionConfig>>,
+) {
+    if let (Some(mut mouse_motion_config), Some(now)) = (mouse_motion_config, time.last_update()) {
+        let raw_motion = match mouse_motion {
+            Some(mouse_motion) => mouse_motion
+                .get_reader()
+                .iter(&mouse_motion)
+                .map(|event| &event.delta)
+                .sum(),
+            None => Vec2::ZERO,
         };
 
-        action_state.update(input_map.which_pressed(&input_streams, *clash_strategy));
+        mouse_motion_config.update(raw_motion, now);
+    }
+}
+
+/// Clears the `just_pressed` and `just_released` data of the [`Input<GamepadTouchpadButton>`] resource at the start of each frame
+///
+/// Bevy does not manage this resource, so its edges are cleared here instead.
+/// Does nothing if the [`Input<GamepadTouchpadButton>`] resource does not exist.
+pub fn clear_touchpad_input(
+    maybe_touchpad_input_stream: Option<ResMut<Input<GamepadTouchpadButton>>>,
+) {
+    if let Some(mut touchpad_input_stream) = maybe_touchpad_input_stream {
+        touchpad_input_stream.clear();
+    }
+}
+
+/// Releases all [`TouchpadButton`](crate::user_input::TouchpadButton) swipes, which only last for a single update
+///
+/// The `just_pressed` and `just_released` data of the resource is left in place for the rest of the frame,
+/// and cleared by [`clear_touchpad_input`] at the start of the next one.
+/// Does nothing if the [`Input<GamepadTouchpadButton>`] resource does not exist.
+pub fn release_touchpad_swipes(
+    maybe_touchpad_input_stream: Option<ResMut<Input<GamepadTouchpadButton>>>,
+) {
+    if let Some(mut touchpad_input_stream) = maybe_touchpad_input_stream {
+        let swipes: Vec<GamepadTouchpadButton> = touchpad_input_stream
+            .get_pressed()
+            .filter(|button| button.1.is_swipe())
+            .copied()
+            .collect();
+
+        for swipe in swipes {
+            touchpad_input_stream.release(swipe);
+        }
+    }
+}
+
+/// Ensures that at most one action in each of the [`ExclusiveGroups`] is pressed, using [`ActionState::enforce_exclusive`]
+///
+/// Does nothing if the [`ExclusiveGroups`] resource does not exist.
+pub fn enforce_exclusive_groups<A: Actionlike>(
+    exclusive_groups: Option<Res<ExclusiveGroups<A>>>,
+    mut query: Query<&mut ActionState<A>>,
+    action_state: Option<ResMut<ActionState<A>>>,
+) {
+    if let Some(exclusive_groups) = exclusive_groups {
+        if let Some(mut action_state) = action_state {
+            for group in exclusive_groups.iter() {
+                action_state.enforce_exclusive(group);
+            }
+        }
+
+        for mut action_state in query.iter_mut() {
+            for group in exclusive_groups.iter() {
+                action_state.enforce_exclusive(group);
+            }
+        }
+    }
+}
+
+/// Releases any pressed actions that are no longer held by any of their bound inputs
+///
+/// Actions can become stuck in a pressed state if a system misbehaves or an entity is modified mid-press.
+/// This system checks every [`ActionState`] against its [`InputMap`] using [`ActionState::reconcile`].
+/// Inputs are resolved exactly as they are by [`update_action_state`],
+/// so actions that are disabled by [`ToggleActions`] or suppressed by the [`InputContext`] count as released.
+///
+/// This system is not added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin):
+/// add it yourself, after [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update), if you need it.
+#[allow(clippy::too_many_arguments)]
+pub fn reconcile_action_state<A: Actionlike>(
+    maybe_gamepad_input_stream: Option<Res<Input<GamepadButton>>>,
+    maybe_keyboard_input_stream: Option<Res<Input<KeyCode>>>,
+    maybe_mouse_input_stream: Option<Res<Input<MouseButton>>>,
+    maybe_touchpad_input_stream: Option<Res<Input<GamepadTouchpadButton>>>,
+    maybe_gamepad_axis_stream: Option<Res<Axis<GamepadAxis>>>,
+    maybe_mouse_motion_events: Option<Res<Events<MouseMotion>>>,
+    maybe_mouse_wheel_events: Option<Res<Events<MouseWheel>>>,
+    maybe_press_times: Option<Res<ButtonPressTimes>>,
+    clash_strategy: Res<ClashStrategy>,
+    custom_clash_resolver: Option<Res<CustomClashResolver<A>>>,
+    socd_cleaning: Option<Res<SocdCleaning<A>>>,
+    (toggle_actions, input_context, maybe_mouse_motion_config): (
+        Option<Res<ToggleActions<A>>>,
+        Option<Res<InputContext>>,
+        Option<Res<MouseMotionConfig>>,
+    ),
+    mut action_state: Option<ResMut<ActionState<A>>>,
+    input_map: Option<Res<InputMap<A>>>,
+    mut query: Query<(
+        &mut ActionState<A>,
+        &InputMap<A>,
+        Option<&ActionPriority<A>>,
+    )>,
+) {
+    let resolver = ActionDataResolver {
+        gamepad: maybe_gamepad_input_stream.as_deref(),
+        keyboard: maybe_keyboard_input_stream.as_deref(),
+        mouse: maybe_mouse_input_stream.as_deref(),
+        touchpad: maybe_touchpad_input_stream.as_deref(),
+        gamepad_axes: maybe_gamepad_axis_stream.as_deref(),
+        mouse_motion: maybe_mouse_motion_events.as_deref(),
+        mouse_wheel: maybe_mouse_wheel_events.as_deref(),
+        press_times: maybe_press_times.as_deref(),
+        mouse_motion_config: maybe_mouse_motion_config.as_deref(),
+        clash_resolver: active_clash_resolver(&clash_strategy, custom_clash_resolver.as_deref()),
+        socd_cleaning: socd_cleaning.as_deref(),
+        toggle_actions: toggle_actions.as_deref(),
+        suppressed: is_suppressed::<A>(input_context.as_deref()),
+    };
+
+    if let (Some(input_map), Some(action_state)) = (&input_map, &mut action_state) {
+        let action_data = resolver.action_data(input_map, None, action_state);
+        action_state.reconcile(&action_data);
+    }
+
+    for (mut action_state, input_map, action_priority) in query.iter_mut() {
+        let action_data = resolver.action_data(input_map, action_priority, &mut action_state);
+        action_state.reconcile(&action_data);
     }
 }
 
-/// When a button with a component of type `A` is clicked, press the corresponding action in the [`ActionState`]
+/// Immediately re-runs [`update_action_state`] for every [`ActionState`], using the current [`ClashStrategy`]
+///
+/// Changes to the [`ClashStrategy`] resource normally only take effect the next time inputs are processed.
+/// Call this from an exclusive system, after changing the resource, to re-resolve inputs during the same frame:
+/// this is useful for live previews in settings menus.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_input_manager::prelude::*;
+/// use leafwing_input_manager::systems::refresh_action_state;
+///
+/// #[derive(Actionlike, Clone)]
+/// enum Action {
+///     Jump,
+/// }
 ///
-/// The action triggered is determined by the variant stored in your UI-defined button.
+/// fn set_clash_strategy(world: &mut World) {
+///     world.insert_resource(ClashStrategy::PressAll);
+///     refresh_action_state::<Action>(world);
+/// }
+///
+/// App::new().add_system(set_clash_strategy.exclusive_system());
+/// ```
+pub fn refresh_action_state<A: Actionlike>(world: &mut World) {
+    let mut system = IntoSystem::into_system(update_action_state::<A>);
+    system.initialize(world);
+    system.run((), world);
+    system.apply_buffers(world);
+}
+
+/// Updates the [`ActionState`] of every target of each [`SharedInputMap`], using the [`InputMap`] on the same entity
+///
+/// Inputs are only resolved once per [`SharedInputMap`].
+/// Missing resources will be ignored, and treated as if none of the corresponding inputs were pressed.
+/// Real inputs are ignored while the [`CapturedInput`] resource is playing back.
+/// Every action is released while `A` is suppressed by the [`InputContext`] resource,
+/// and actions whose inputs are held stay released until those inputs are pressed again.
+#[allow(clippy::too_many_arguments)]
+pub fn update_shared_action_states<A: Actionlike>(
+    maybe_gamepad_input_stream: Option<Res<Input<GamepadButton>>>,
+    maybe_keyboard_input_stream: Option<Res<Input<KeyCode>>>,
+    maybe_mouse_input_stream: Option<Res<Input<MouseButton>>>,
+    maybe_touchpad_input_stream: Option<Res<Input<GamepadTouchpadButton>>>,
+    maybe_gamepad_axis_stream: Option<Res<Axis<GamepadAxis>>>,
+    maybe_mouse_motion_events: Option<Res<Events<MouseMotion>>>,
+    maybe_mouse_wheel_events: Option<Res<Events<MouseWheel>>>,
+    maybe_press_times: Option<Res<ButtonPressTimes>>,
+    clash_strategy: Res<ClashStrategy>,
+    custom_clash_resolver: Option<Res<CustomClashResolver<A>>>,
+    socd_cleaning: Option<Res<SocdCleaning<A>>>,
+    captured_input: Option<Res<CapturedInput<A>>>,
+    (toggle_actions, input_context, maybe_mouse_motion_config): (
+        Option<Res<ToggleActions<A>>>,
+        Option<Res<InputContext>>,
+        Option<Res<MouseMotionConfig>>,
+    ),
+    shared_query: Query<(&InputMap<A>, &SharedInputMap<A>, Option<&ActionPriority<A>>)>,
+    mut action_state_query: Query<&mut ActionState<A>>,
+) {
+    if is_playing_back(captured_input.as_deref()) {
+        return;
+    }
+
+    let resolver = ActionDataResolver {
+        gamepad: maybe_gamepad_input_stream.as_deref(),
+        keyboard: maybe_keyboard_input_stream.as_deref(),
+        mouse: maybe_mouse_input_stream.as_deref(),
+        touchpad: maybe_touchpad_input_stream.as_deref(),
+        gamepad_axes: maybe_gamepad_axis_stream.as_deref(),
+        mouse_motion: maybe_mouse_motion_events.as_deref(),
+        mouse_wheel: maybe_mouse_wheel_events.as_deref(),
+        press_times: maybe_press_times.as_deref(),
+        mouse_motion_config: maybe_mouse_motion_config.as_deref(),
+        clash_resolver: active_clash_resolver(&clash_strategy, custom_clash_resolver.as_deref()),
+        socd_cleaning: socd_cleaning.as_deref(),
+        toggle_actions: toggle_actions.as_deref(),
+        suppressed: is_suppressed::<A>(input_context.as_deref()),
+    };
+
+    for (input_map, shared_input_map, action_priority) in shared_query.iter() {
+        let action_data = resolver.which_pressed(input_map, action_priority);
+
+        for &target in shared_input_map.targets.iter() {
+            // Targets may have been despawned since the shared input map was created
+            if let Ok(mut action_state) = action_state_query.get_mut(target) {
+                let mut action_data = action_data.clone();
+                resolver.process(input_map, &mut action_data, &mut action_state);
+                action_state.update(action_data);
+            }
+        }
+    }
+}
+
+/// While any touch is inside of the region of a [`VirtualButton`], press its action in the [`ActionState`] of its entity
+///
+/// Like [`update_action_state_from_interaction`], this must run after [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update),
+/// or the action will be released again because none of its bound inputs are pressed.
+/// Does nothing if the [`Touches`] resource does not exist, or while `A` is suppressed by the [`InputContext`] resource.
+pub fn update_action_state_from_touches<A: Actionlike>(
+    touches: Option<Res<Touches>>,
+    input_context: Option<Res<InputContext>>,
+    virtual_buttons: Query<&VirtualButton<A>>,
+    mut action_state_query: Query<&mut ActionState<A>>,
+) {
+    let touches = match touches {
+        Some(touches) if !is_suppressed::<A>(input_context.as_deref()) => touches,
+        _ => return,
+    };
+
+    for virtual_button in virtual_buttons.iter() {
+        if touches
+            .iter()
+            .any(|touch| virtual_button.region.contains(touch.position()))
+        {
+            let mut action_state = action_state_query
+                .get_mut(virtual_button.entity)
+                .expect("Entity does not exist, or does not have an `ActionState` component.");
+            action_state.press(virtual_button.action.clone());
+        }
+    }
+}
+
+/// While a touch that started on a [`VirtualJoystick`] is held, press its action and set its analog values in the [`ActionState`] of its entity
+///
+/// Like [`update_action_state_from_touches`], this must run after [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update).
+/// Does nothing if the [`Touches`] resource does not exist, or while `A` is suppressed by the [`InputContext`] resource.
+pub fn update_action_state_from_virtual_joysticks<A: Actionlike>(
+    touches: Option<Res<Touches>>,
+    input_context: Option<Res<InputContext>>,
+    virtual_joysticks: Query<&VirtualJoystick<A>>,
+    mut action_state_query: Query<&mut ActionState<A>>,
+) {
+    let touches = match touches {
+        Some(touches) if !is_suppressed::<A>(input_context.as_deref()) => touches,
+        _ => return,
+    };
+
+    for virtual_joystick in virtual_joysticks.iter() {
+        if let Some(touch) = touches
+            .iter()
+            .find(|touch| virtual_joystick.contains(touch.start_position()))
+        {
+            let offset = virtual_joystick.offset(touch.position());
+            let mut action_state = action_state_query
+                .get_mut(virtual_joystick.entity)
+                .expect("Entity does not exist, or does not have an `ActionState` component.");
+            action_state.press(virtual_joystick.action.clone());
+            action_state.set_axis_value(virtual_joystick.action.clone(), offset.length());
+            action_state.set_axis_pair(virtual_joystick.action.clone(), DualAxisData::new(offset));
+        }
+    }
+}
+
+/// When a button with a component of type `A` is clicked, press the corresponding actions in the [`ActionState`]
+///
+/// The actions triggered are determined by the variants stored in your UI-defined button.
+/// When the [`Interaction`] of the button changes from [`Interaction::Clicked`] to anything else,
+/// the actions are released, so held UI buttons behave like held physical buttons.
+/// The same happens if a clicked button is despawned, or loses its [`Interaction`] or [`ActionStateDriver`].
+/// Buttons are ignored while `A` is suppressed by the [`InputContext`] resource.
 #[cfg(feature = "ui")]
 pub fn update_action_state_from_interaction<A: Actionlike>(
-    ui_query: Query<(&Interaction, &ActionStateDriver<A>)>,
+    ui_query: Query<(Entity, &Interaction, &ActionStateDriver<A>)>,
+    input_context: Option<Res<InputContext>>,
     mut action_state_query: Query<&mut ActionState<A>>,
+    mut clicked_buttons: Local<HashMap<Entity, ActionStateDriver<A>>>,
 ) {
-    for (&interaction, action_state_driver) in ui_query.iter() {
-        if interaction == Interaction::Clicked {
+    let suppressed = is_suppressed::<A>(input_context.as_deref());
+
+    // Buttons that no longer exist can never stop being clicked, so their actions are released here
+    clicked_buttons.retain(|&button_entity, action_state_driver| {
+        if ui_query.get(button_entity).is_ok() {
+            return true;
+        }
+
+        // The driven entity may have been despawned along with the button
+        if let Ok(mut action_state) = action_state_query.get_mut(action_state_driver.entity) {
+            for action in action_state_driver.actions.iter() {
+                action_state.release(action.clone());
+            }
+        }
+        false
+    });
+
+    for (button_entity, &interaction, action_state_driver) in ui_query.iter() {
+        let was_clicked = if interaction == Interaction::Clicked {
+            clicked_buttons
+                .insert(button_entity, action_state_driver.clone())
+                .is_some()
+        } else {
+            clicked_buttons.remove(&button_entity).is_some()
+        };
+
+        if interaction == Interaction::Clicked && !suppressed {
+            let mut action_state = action_state_query
+                .get_mut(action_state_driver.entity)
+                .expect("Entity does not exist, or does not have an `ActionState` component.");
+            for action in action_state_driver.actions.iter() {
+                action_state.press(action.clone());
+            }
+        } else if was_clicked {
             let mut action_state = action_state_query
                 .get_mut(action_state_driver.entity)
                 .expect("Entity does not exist, or does not have an `ActionState` component.");
-            action_state.press(action_state_driver.action.clone());
+            for action in action_state_driver.actions.iter() {
+                action_state.release(action.clone());
+            }
         }
     }
 }
 
+/// Sends an [`InputMapChanged`] event whenever an [`InputMap`] resource or component is modified
+///
+/// Each map is compared to its state when this system last saw it, so mutable accesses that do not change anything send no events.
+pub fn detect_input_map_changes<A: Actionlike>(
+    input_map_resource: Option<Res<InputMap<A>>>,
+    input_map_query: Query<(Entity, &InputMap<A>), Changed<InputMap<A>>>,
+    existing_maps: Query<(), With<InputMap<A>>>,
+    mut previous_maps: Local<HashMap<Option<Entity>, InputMap<A>>>,
+    mut input_map_changed: EventWriter<InputMapChanged<A>>,
+) {
+    // Forget the maps that were removed, so that re-added maps are compared to an empty map
+    previous_maps.retain(|entity, _| match entity {
+        Some(entity) => existing_maps.get(*entity).is_ok(),
+        None => input_map_resource.is_some(),
+    });
+
+    let mut detect_change = |entity: Option<Entity>, input_map: &InputMap<A>| {
+        let previous_map = previous_maps.entry(entity).or_default();
+        let actions = input_map.changed_actions(previous_map);
+        if !actions.is_empty() || !input_map.shared_settings_eq(previous_map) {
+            input_map_changed.send(InputMapChanged { entity, actions });
+            *previous_map = input_map.clone();
+        }
+    };
+
+    if let Some(input_map) = &input_map_resource {
+        if input_map.is_changed() {
+            detect_change(None, input_map);
+        }
+    }
+
+    for (entity, input_map) in input_map_query.iter() {
+        detect_change(Some(entity), input_map);
+    }
+}
+
+/// Sends an [`AxisChanged`] event whenever the [`axis_value`](ActionState::axis_value) of an action changes
+///
+/// Each [`ActionState`] is compared to the values it had when this system last ran,
+/// so newly added [`ActionState`]s only send events for actions whose value is not zero.
+///
+/// This system is only added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin)
+/// if [`InputManagerPlugin::with_axis_events`](crate::plugin::InputManagerPlugin::with_axis_events) is used.
+pub fn generate_axis_events<A: Actionlike>(
+    action_state_resource: Option<Res<ActionState<A>>>,
+    action_state_query: Query<(Entity, &ActionState<A>)>,
+    mut previous_values: Local<HashMap<Option<Entity>, Vec<f32>>>,
+    mut axis_changed: EventWriter<AxisChanged<A>>,
+) {
+    let mut send_changes = |entity: Option<Entity>, action_state: &ActionState<A>| {
+        let previous_values = previous_values
+            .entry(entity)
+            .or_insert_with(|| vec![0.0; A::N_VARIANTS]);
+
+        for action in A::variants() {
+            let value = action_state.axis_value(action.clone());
+            let previous_value = &mut previous_values[action.index()];
+            if value != *previous_value {
+                *previous_value = value;
+                axis_changed.send(AxisChanged {
+                    entity,
+                    action,
+                    value,
+                });
+            }
+        }
+    };
+
+    if let Some(action_state) = &action_state_resource {
+        send_changes(None, action_state);
+    }
+
+    for (entity, action_state) in action_state_query.iter() {
+        send_changes(Some(entity), action_state);
+    }
+}
+
 /// Generates an [`Events`](bevy_ecs::event::Events) stream of [`ActionDiff`] from [`ActionState`]
 ///
 /// The `ID` generic type should be a stable entity identifer,
 /// suitable to be sent across a network.
 ///
-/// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually.
+/// Analog values are only sent once they differ from the last value sent for that action by more than [`ACTION_DIFF_EPSILON`],
+/// so buttons never produce [`ActionDiff::ValueChanged`] events.
+///
+/// This system is only added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin)
+/// if [`InputManagerPlugin::with_action_diffs`](crate::plugin::InputManagerPlugin::with_action_diffs) is used.
 pub fn generate_action_diffs<A: Actionlike, ID: Eq + Clone + Component>(
-    action_state_query: Query<(&ActionState<A>, &ID)>,
+    action_state_query: Query<(Entity, &ActionState<A>, &ID)>,
     mut action_diffs: EventWriter<ActionDiff<A, ID>>,
+    mut sent_values: Local<HashMap<Entity, Vec<(f32, Option<Vec2>)>>>,
 ) {
-    for (action_state, id) in action_state_query.iter() {
-        for action in action_state.get_just_pressed() {
-            action_diffs.send(ActionDiff::Pressed {
-                action: action.clone(),
+    // Forget the values sent for entities that no longer have an `ActionState` or `ID`
+    sent_values.retain(|&entity, _| action_state_query.get(entity).is_ok());
+
+    for (entity, action_state, id) in action_state_query.iter() {
+        let sent_values = sent_values
+            .entry(entity)
+            .or_insert_with(|| vec![(0.0, None); A::N_VARIANTS]);
+
+        for action_diff in collect_action_diffs(action_state, id, sent_values) {
+            action_diffs.send(action_diff);
+        }
+    }
+}
+
+/// Generates an [`Events`](bevy_ecs::event::Events) stream of [`ActionDiffBatch`] from [`ActionState`]
+///
+/// This works like [`generate_action_diffs`], but sends a single batch containing all of the diffs of each entity.
+/// No batch is sent for entities whose [`ActionState`] did not change.
+///
+/// This system is only added by the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin)
+/// if [`InputManagerPlugin::with_action_diff_batches`](crate::plugin::InputManagerPlugin::with_action_diff_batches) is used.
+pub fn generate_action_diff_batches<A: Actionlike, ID: Eq + Clone + Component>(
+    action_state_query: Query<(Entity, &ActionState<A>, &ID)>,
+    mut action_diff_batches: EventWriter<ActionDiffBatch<A, ID>>,
+    mut sent_values: Local<HashMap<Entity, Vec<(f32, Option<Vec2>)>>>,
+) {
+    // Forget the values sent for entities that no longer have an `ActionState` or `ID`
+    sent_values.retain(|&entity, _| action_state_query.get(entity).is_ok());
+
+    for (entity, action_state, id) in action_state_query.iter() {
+        let sent_values = sent_values
+            .entry(entity)
+            .or_insert_with(|| vec![(0.0, None); A::N_VARIANTS]);
+
+        let diffs = collect_action_diffs(action_state, id, sent_values);
+        if !diffs.is_empty() {
+            action_diff_batches.send(ActionDiffBatch {
                 id: id.clone(),
+                diffs,
             });
         }
+    }
+}
 
-        for action in action_state.get_just_released() {
-            action_diffs.send(ActionDiff::Released {
+/// The [`ActionDiff`]s needed to bring a receiver whose analog values are `sent_values` up to date with `action_state`
+///
+/// `sent_values` are updated to match what the receiver will have once the diffs are applied.
+/// Diffs are ordered as described in [`ActionDiffBatch`].
+fn collect_action_diffs<A: Actionlike, ID: Eq + Clone + Component>(
+    action_state: &ActionState<A>,
+    id: &ID,
+    sent_values: &mut [(f32, Option<Vec2>)],
+) -> Vec<ActionDiff<A, ID>> {
+    let mut diffs = Vec::new();
+
+    for action in action_state.get_just_pressed() {
+        // Pressing an action gives it a value of 1.0, unless it already has an analog value
+        let sent_value = &mut sent_values[action.index()].0;
+        if *sent_value == 0.0 {
+            *sent_value = 1.0;
+        }
+
+        diffs.push(ActionDiff::Pressed {
+            action,
+            id: id.clone(),
+        });
+    }
+
+    for action in action_state.get_just_released() {
+        sent_values[action.index()] = (0.0, None);
+
+        diffs.push(ActionDiff::Released {
+            action,
+            id: id.clone(),
+        });
+    }
+
+    for action in A::variants() {
+        let (sent_value, sent_axis_pair) = &mut sent_values[action.index()];
+
+        let value = action_state.axis_value(action.clone());
+        if (value - *sent_value).abs() > ACTION_DIFF_EPSILON {
+            *sent_value = value;
+            diffs.push(ActionDiff::ValueChanged {
                 action: action.clone(),
                 id: id.clone(),
+                value,
             });
         }
+
+        if let Some(axis_pair) = action_state.axis_pair(action.clone()) {
+            let axis_pair = axis_pair.xy();
+            let changed = match sent_axis_pair {
+                Some(sent_axis_pair) => sent_axis_pair.distance(axis_pair) > ACTION_DIFF_EPSILON,
+                None => true,
+            };
+
+            if changed {
+                *sent_axis_pair = Some(axis_pair);
+                diffs.push(ActionDiff::AxisPairChanged {
+                    action,
+                    id: id.clone(),
+                    axis_pair,
+                });
+            }
+        }
     }
+
+    diffs
 }
 
 /// Generates an [`Events`](bevy_ecs::event::Events) stream of [`ActionDiff`] from [`ActionState`]
@@ -133,7 +718,10 @@ pub fn generate_action_diffs<A: Actionlike, ID: Eq + Clone + Component>(
 /// The `ID` generic type should be a stable entity identifer,
 /// suitable to be sent across a network.
 ///
-/// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually.
+/// Each [`ActionState`] that a diff is applied to is marked as [replaying](ActionState::is_replaying).
+///
+/// This system is only added by the [`InputManagerPlugin::server`](crate::plugin::InputManagerPlugin::server)
+/// if [`InputManagerPlugin::with_action_diffs`](crate::plugin::InputManagerPlugin::with_action_diffs) is used.
 pub fn process_action_diffs<A: Actionlike, ID: Eq + Component + Clone>(
     mut action_state_query: Query<(&mut ActionState<A>, &ID)>,
     mut action_diffs: EventReader<ActionDiff<A, ID>>,
@@ -141,46 +729,826 @@ pub fn process_action_diffs<A: Actionlike, ID: Eq + Component + Clone>(
     // PERF: This would probably be faster with an index, but is much more fussy
     for action_diff in action_diffs.iter() {
         for (mut action_state, id) in action_state_query.iter_mut() {
-            match action_diff {
-                ActionDiff::Pressed {
-                    action,
-                    id: event_id,
-                } => {
-                    if event_id == id {
-                        action_state.press(action.clone());
-                        continue;
-                    }
-                }
-                ActionDiff::Released {
-                    action,
-                    id: event_id,
-                } => {
-                    if event_id == id {
-                        action_state.release(action.clone());
-                        continue;
-                    }
+            if action_diff.id() == id {
+                apply_action_diff(&mut action_state, action_diff);
+            }
+        }
+    }
+}
+
+/// Applies an [`Events`](bevy_ecs::event::Events) stream of [`ActionDiffBatch`] to [`ActionState`]
+///
+/// The diffs of each batch are applied in order, as described in [`ActionDiffBatch`].
+/// Each [`ActionState`] that a batch is applied to is marked as [replaying](ActionState::is_replaying).
+///
+/// This system is only added by the [`InputManagerPlugin::server`](crate::plugin::InputManagerPlugin::server)
+/// if [`InputManagerPlugin::with_action_diff_batches`](crate::plugin::InputManagerPlugin::with_action_diff_batches) is used.
+pub fn process_action_diff_batches<A: Actionlike, ID: Eq + Component + Clone>(
+    mut action_state_query: Query<(&mut ActionState<A>, &ID)>,
+    mut action_diff_batches: EventReader<ActionDiffBatch<A, ID>>,
+) {
+    for batch in action_diff_batches.iter() {
+        for (mut action_state, id) in action_state_query.iter_mut() {
+            if batch.id == *id {
+                for action_diff in &batch.diffs {
+                    apply_action_diff(&mut action_state, action_diff);
                 }
-            };
+            }
         }
     }
 }
 
+/// Applies a single [`ActionDiff`] to the `action_state`, marking it as [replaying](ActionState::is_replaying)
+fn apply_action_diff<A: Actionlike, ID: Eq + Component + Clone>(
+    action_state: &mut ActionState<A>,
+    action_diff: &ActionDiff<A, ID>,
+) {
+    match action_diff {
+        ActionDiff::Pressed { action, .. } => action_state.press(action.clone()),
+        ActionDiff::Released { action, .. } => action_state.release(action.clone()),
+        ActionDiff::ValueChanged { action, value, .. } => {
+            action_state.set_axis_value(action.clone(), *value)
+        }
+        ActionDiff::AxisPairChanged {
+            action, axis_pair, ..
+        } => action_state.set_axis_pair(action.clone(), DualAxisData::new(*axis_pair)),
+    }
+
+    #[cfg(feature = "action_source")]
+    action_state.set_source(
+        action_diff.action().clone(),
+        crate::action_state::ActionSource::Diff,
+    );
+    action_state.set_replaying(true);
+}
+
 /// Release all inputs if [`DisableInput`] was added
+///
+/// Actions that were individually disabled using [`ToggleActions::disable`] are released
+/// when they are disabled, leaving the other actions untouched.
+///
+/// Under [`ReenablePolicy::ContinueHeld`], the [`ActionData`] of each pressed action is stored when it is disabled.
+/// Actions that are pressed again on the frame they are re-enabled lose their `just_pressed` edge,
+/// and get back the timing, press counts and recent presses they had when they were disabled,
+/// so they continue as if they had been held all along.
 pub fn release_on_disable<A: Actionlike>(
+    mut query: Query<(Entity, &mut ActionState<A>)>,
+    mut resource: Option<ResMut<ActionState<A>>>,
+    toggle_actions: Res<ToggleActions<A>>,
+    mut previously_disabled: Local<Vec<A>>,
+    mut was_disabled: Local<bool>,
+    mut held_when_disabled: Local<HashMap<Option<Entity>, Vec<Option<ActionData>>>>,
+) {
+    if !toggle_actions.is_changed() {
+        return;
+    }
+
+    let continue_held = toggle_actions.reenable_policy == ReenablePolicy::ContinueHeld;
+    // Stores the data of the pressed `actions` of the `action_state`, so that they can continue once re-enabled
+    let mut store_held = |key: Option<Entity>,
+                          action_state: &ActionState<A>,
+                          actions: &mut dyn Iterator<Item = A>| {
+        let held = held_when_disabled
+            .entry(key)
+            .or_insert_with(|| vec![None; A::N_VARIANTS]);
+        for action in actions.filter(|action| action_state.pressed(action.clone())) {
+            held[action.index()] = Some(action_state.action_data(action.clone()));
+        }
+    };
+
+    if !toggle_actions.enabled {
+        let disable = |action_state: &mut ActionState<A>| match toggle_actions.reenable_policy {
+            ReenablePolicy::PressHeld | ReenablePolicy::ContinueHeld => action_state.release_all(),
+            ReenablePolicy::WaitForRelease => action_state.consume_all(),
+        };
+
+        for (entity, mut action_state) in query.iter_mut() {
+            if continue_held {
+                store_held(Some(entity), &action_state, &mut A::variants());
+            }
+            disable(&mut action_state);
+        }
+        if let Some(mut action_state) = resource {
+            if continue_held {
+                store_held(None, &action_state, &mut A::variants());
+            }
+            disable(&mut action_state);
+        }
+    } else {
+        let newly_disabled: Vec<A> = A::variants()
+            .filter(|action| toggle_actions.individually_disabled(action.clone()))
+            .filter(|action| {
+                !previously_disabled
+                    .iter()
+                    .any(|previous| previous.index() == action.index())
+            })
+            .collect();
+
+        let disable = |action_state: &mut ActionState<A>| {
+            for action in newly_disabled.iter() {
+                match toggle_actions.reenable_policy {
+                    ReenablePolicy::PressHeld | ReenablePolicy::ContinueHeld => {
+                        action_state.release(action.clone())
+                    }
+                    ReenablePolicy::WaitForRelease => action_state.consume(action.clone()),
+                }
+            }
+        };
+
+        for (entity, mut action_state) in query.iter_mut() {
+            if continue_held {
+                store_held(
+                    Some(entity),
+                    &action_state,
+                    &mut newly_disabled.iter().cloned(),
+                );
+            }
+            disable(&mut action_state);
+        }
+        if let Some(action_state) = resource.as_deref_mut() {
+            if continue_held {
+                store_held(None, action_state, &mut newly_disabled.iter().cloned());
+            }
+            disable(action_state);
+        }
+
+        let reenabled: Vec<A> = A::variants()
+            .filter(|action| {
+                *was_disabled
+                    || (previously_disabled
+                        .iter()
+                        .any(|previous| previous.index() == action.index())
+                        && !toggle_actions.individually_disabled(action.clone()))
+            })
+            .collect();
+
+        let mut reenable = |key: Option<Entity>, action_state: &mut ActionState<A>| {
+            let mut held = held_when_disabled.get_mut(&key);
+            for action in reenabled.iter() {
+                let stored = held.as_mut().and_then(|held| held[action.index()].take());
+                if !continue_held || !action_state.just_pressed(action.clone()) {
+                    continue;
+                }
+
+                let mut action_data = action_state.action_data(action.clone());
+                action_data.state = ButtonState::Pressed;
+                // Undo the press caused by re-enabling, restoring the history from before the action was disabled
+                if let Some(stored) = stored {
+                    action_data.timing = stored.timing;
+                    action_data.press_count = stored.press_count;
+                    action_data.release_count = stored.release_count;
+                    action_data.presses_this_tick = action_data.presses_this_tick.saturating_sub(1);
+                    action_data.pending_steps = stored.pending_steps;
+                    action_data.recent_presses = stored.recent_presses;
+                    action_data.double_press_windows = stored.double_press_windows;
+                }
+                action_state.set_action_data(action.clone(), action_data);
+            }
+        };
+
+        for (entity, mut action_state) in query.iter_mut() {
+            reenable(Some(entity), &mut action_state);
+        }
+        if let Some(action_state) = resource.as_deref_mut() {
+            reenable(None, action_state);
+        }
+    }
+
+    // Forget the actions of despawned entities, and those that have been re-enabled
+    held_when_disabled.retain(|key, held| {
+        let exists = match key {
+            Some(entity) => query.get(*entity).is_ok(),
+            None => true,
+        };
+        exists && held.iter().any(Option::is_some)
+    });
+
+    *was_disabled = !toggle_actions.enabled;
+
+    *previously_disabled = A::variants()
+        .filter(|action| toggle_actions.individually_disabled(action.clone()))
+        .collect();
+}
+
+/// Flips [`ToggleActions<Target>`] when the action stored in [`ToggleActionsOnPress`] is just pressed
+///
+/// Both the [`ActionState<Source>`] resource and any [`ActionState<Source>`] components are checked;
+/// the toggle is only flipped once per frame, no matter how many of them pressed the action.
+///
+/// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually.
+/// It should run after [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update)
+/// and before [`InputManagerSystem::ReleaseOnDisable`](crate::plugin::InputManagerSystem::ReleaseOnDisable),
+/// so that the disabled actions are released on the same frame.
+pub fn toggle_actions_on_press<Source: Actionlike, Target: Actionlike>(
+    query: Query<&ActionState<Source>>,
+    resource: Option<Res<ActionState<Source>>>,
+    toggle_actions_on_press: Res<ToggleActionsOnPress<Source, Target>>,
+    mut toggle_actions: ResMut<ToggleActions<Target>>,
+) {
+    let action = toggle_actions_on_press.action.clone();
+
+    let just_pressed = query
+        .iter()
+        .chain(resource.as_deref())
+        .any(|action_state| action_state.just_pressed(action.clone()));
+
+    if just_pressed {
+        toggle_actions.enabled = !toggle_actions.enabled;
+    }
+}
+
+/// Associates connected gamepads with each [`InputMap`] that has a [`GamepadAssignment`]
+///
+/// Reads the [`Gamepads`] resource, which is kept up to date with each [`GamepadEvent`](bevy_input::gamepad::GamepadEvent)
+/// by the [`InputPlugin`](bevy_input::InputPlugin).
+/// When an assigned gamepad disconnects, its association is cleared
+/// and the [`ActionState`] on the same entity is released, so no actions are left held.
+pub fn assign_gamepads<A: Actionlike>(
+    gamepads: Option<Res<Gamepads>>,
+    mut query: Query<(
+        &GamepadAssignment,
+        &mut InputMap<A>,
+        Option<&mut ActionState<A>>,
+    )>,
+) {
+    let gamepads = match gamepads {
+        Some(gamepads) => gamepads,
+        None => return,
+    };
+
+    let mut unassigned = Vec::new();
+    let mut in_use = Vec::new();
+    for (assignment, mut input_map, action_state) in query.iter_mut() {
+        match input_map.gamepad() {
+            Some(gamepad) if gamepads.contains(&gamepad) => in_use.push(gamepad),
+            Some(_) => {
+                input_map.clear_gamepad();
+                if let Some(mut action_state) = action_state {
+                    action_state.release_all();
+                }
+                unassigned.push(*assignment);
+            }
+            None => unassigned.push(*assignment),
+        }
+    }
+
+    if unassigned.is_empty() {
+        return;
+    }
+
+    let mut available: Vec<Gamepad> = gamepads
+        .iter()
+        .filter(|gamepad| !in_use.contains(gamepad))
+        .copied()
+        .collect();
+    available.sort_by_key(|gamepad| gamepad.0);
+    unassigned.sort();
+
+    let mut assigned = Vec::new();
+    for (assignment, gamepad) in unassigned.iter().zip(available) {
+        assigned.push((*assignment, gamepad));
+    }
+
+    for (assignment, mut input_map, _) in query.iter_mut() {
+        if input_map.gamepad().is_some() {
+            continue;
+        }
+
+        if let Some(index) = assigned.iter().position(|(slot, _)| slot == assignment) {
+            let (_, gamepad) = assigned.remove(index);
+            input_map.set_gamepad(gamepad);
+        }
+    }
+}
+
+/// Releases every [`ActionState`] whose [`InputMap`] is associated with a gamepad that has just disconnected
+///
+/// Otherwise, actions that were held when the gamepad disconnected could stay pressed,
+/// as the gamepad's inputs are no longer updated.
+/// Missing [`GamepadEvent`] resources are treated as if no gamepad ever disconnected.
+pub fn release_on_gamepad_disconnect<A: Actionlike>(
+    gamepad_events: Option<Res<Events<GamepadEvent>>>,
+    mut event_reader: Local<ManualEventReader<GamepadEvent>>,
+    action_state: Option<ResMut<ActionState<A>>>,
+    input_map: Option<Res<InputMap<A>>>,
+    mut query: Query<(&mut ActionState<A>, &InputMap<A>)>,
+) {
+    let gamepad_events = match gamepad_events {
+        Some(gamepad_events) => gamepad_events,
+        None => return,
+    };
+
+    let disconnected: Vec<Gamepad> = event_reader
+        .iter(&gamepad_events)
+        .filter(|GamepadEvent(_, event_type)| *event_type == GamepadEventType::Disconnected)
+        .map(|GamepadEvent(gamepad, _)| *gamepad)
+        .collect();
+
+    if disconnected.is_empty() {
+        return;
+    }
+
+    let is_disconnected = |input_map: &InputMap<A>| match input_map.gamepad() {
+        Some(gamepad) => disconnected.contains(&gamepad),
+        None => false,
+    };
+
+    if let (Some(mut action_state), Some(input_map)) = (action_state, input_map) {
+        if is_disconnected(&input_map) {
+            action_state.release_all();
+        }
+    }
+
+    for (mut action_state, input_map) in query.iter_mut() {
+        if is_disconnected(input_map) {
+            action_state.release_all();
+        }
+    }
+}
+
+/// Releases every [`ActionState`] when a window loses focus
+///
+/// Actions are consumed rather than merely released:
+/// the keyboard and mouse inputs held when focus was lost are never seen to be released,
+/// so they would otherwise press the action again on the very next frame.
+/// Each action stays released until its inputs are released, as described in [`ActionState::consume`].
+///
+/// Only added by the plugin when [`InputManagerPlugin::release_on_focus_lost`](crate::plugin::InputManagerPlugin::release_on_focus_lost) is used.
+/// Missing [`WindowFocused`] resources are treated as if focus was never lost.
+pub fn release_on_focus_lost<A: Actionlike>(
+    focus_events: Option<Res<Events<WindowFocused>>>,
+    mut event_reader: Local<ManualEventReader<WindowFocused>>,
+    action_state: Option<ResMut<ActionState<A>>>,
     mut query: Query<&mut ActionState<A>>,
+) {
+    let focus_events = match focus_events {
+        Some(focus_events) => focus_events,
+        None => return,
+    };
+
+    // Every event must be read, so that stale events are not seen on later frames
+    let focus_lost = event_reader
+        .iter(&focus_events)
+        .filter(|event| !event.focused)
+        .count()
+        > 0;
+
+    if !focus_lost {
+        return;
+    }
+
+    if let Some(mut action_state) = action_state {
+        action_state.consume_all();
+    }
+
+    for mut action_state in query.iter_mut() {
+        action_state.consume_all();
+    }
+}
+
+/// The inputs and settings used to compute the [`ActionData`] of each [`InputMap`]
+///
+/// This is shared by [`update_action_state`], [`update_shared_action_states`] and [`reconcile_action_state`],
+/// so that they all agree on which actions are pressed.
+struct ActionDataResolver<'a, A: Actionlike> {
+    gamepad: Option<&'a Input<GamepadButton>>,
+    keyboard: Option<&'a Input<KeyCode>>,
+    mouse: Option<&'a Input<MouseButton>>,
+    touchpad: Option<&'a Input<GamepadTouchpadButton>>,
+    gamepad_axes: Option<&'a Axis<GamepadAxis>>,
+    mouse_motion: Option<&'a Events<MouseMotion>>,
+    mouse_wheel: Option<&'a Events<MouseWheel>>,
+    press_times: Option<&'a ButtonPressTimes>,
+    mouse_motion_config: Option<&'a MouseMotionConfig>,
+    clash_resolver: &'a dyn ClashResolver<A>,
+    socd_cleaning: Option<&'a SocdCleaning<A>>,
+    toggle_actions: Option<&'a ToggleActions<A>>,
+    /// Is `A` suppressed by the [`InputContext`] resource?
+    suppressed: bool,
+}
+
+impl<'a, A: Actionlike> ActionDataResolver<'a, A> {
+    /// Resolves which actions of the `input_map` are pressed, using the [`ActionPriority`] of its entity if it has one
+    ///
+    /// This does not depend on any [`ActionState`], and must be [processed](Self::process) before it is used.
+    fn which_pressed(
+        &self,
+        input_map: &InputMap<A>,
+        action_priority: Option<&ActionPriority<A>>,
+    ) -> Vec<ActionData> {
+        let input_streams = InputStreams {
+            gamepad: self.gamepad,
+            keyboard: self.keyboard,
+            mouse: self.mouse,
+            touchpad: self.touchpad,
+            gamepad_axes: self.gamepad_axes,
+            mouse_motion: self.mouse_motion,
+            mouse_wheel: self.mouse_wheel,
+            associated_gamepad: input_map.gamepad(),
+            controller_layout: input_map.controller_layout(),
+            mouse_motion_threshold: input_map.mouse_motion_threshold(),
+            press_times: self.press_times,
+            mouse_motion_config: self.mouse_motion_config,
+        };
+
+        input_map.which_pressed_with(
+            &input_streams,
+            input_map.clash_resolver(prioritized_clash_resolver(
+                self.clash_resolver,
+                action_priority,
+            )),
+        )
+    }
+
+    /// Applies the cooldowns, axis thresholds, SOCD cleaning, [`ToggleActions`] and [`InputContext`] to the `action_data`
+    ///
+    /// These depend on the previous state of the `action_state`, and may update its cooldowns and consumed actions.
+    fn process(
+        &self,
+        input_map: &InputMap<A>,
+        action_data: &mut [ActionData],
+        action_state: &mut ActionState<A>,
+    ) {
+        input_map.apply_cooldowns(action_state);
+        input_map.apply_axis_thresholds(action_data, action_state);
+        if let Some(socd_cleaning) = self.socd_cleaning {
+            socd_cleaning.clean(action_data, action_state);
+        }
+        if let Some(toggle_actions) = self.toggle_actions {
+            toggle_actions.filter_disabled(action_data, action_state);
+        }
+        if self.suppressed {
+            consume_held(action_data, action_state);
+        }
+    }
+
+    /// The fully [processed](Self::process) [`ActionData`] of the `input_map`, for its `action_state`
+    fn action_data(
+        &self,
+        input_map: &InputMap<A>,
+        action_priority: Option<&ActionPriority<A>>,
+        action_state: &mut ActionState<A>,
+    ) -> Vec<ActionData> {
+        let mut action_data = self.which_pressed(input_map, action_priority);
+        self.process(input_map, &mut action_data, action_state);
+        action_data
+    }
+}
+
+/// The [`CustomClashResolver`] for `A`, if it exists, or the global [`ClashStrategy`] otherwise
+fn active_clash_resolver<'a, A: Actionlike>(
+    clash_strategy: &'a ClashStrategy,
+    custom_clash_resolver: Option<&'a CustomClashResolver<A>>,
+) -> &'a dyn ClashResolver<A> {
+    match custom_clash_resolver {
+        Some(custom_clash_resolver) => custom_clash_resolver.resolver(),
+        None => clash_strategy,
+    }
+}
+
+/// The [`ActionPriority`] component of an entity, if it has one, or the `clash_resolver` otherwise
+fn prioritized_clash_resolver<'a, A: Actionlike>(
+    clash_resolver: &'a dyn ClashResolver<A>,
+    action_priority: Option<&'a ActionPriority<A>>,
+) -> &'a dyn ClashResolver<A> {
+    match action_priority {
+        Some(action_priority) => action_priority,
+        None => clash_resolver,
+    }
+}
+
+/// Is the action type `A` suppressed by a higher layer of the [`InputContext`] resource?
+fn is_suppressed<A: Actionlike>(input_context: Option<&InputContext>) -> bool {
+    match input_context {
+        Some(input_context) => !input_context.is_active::<A>(),
+        None => false,
+    }
+}
+
+/// Consumes every action whose inputs are held in the `action_data`, and releases the rest
+///
+/// Like [`ReenablePolicy::WaitForRelease`], this keeps actions released until their inputs are released and pressed again.
+fn consume_held<A: Actionlike>(action_data: &mut [ActionData], action_state: &mut ActionState<A>) {
+    for action in A::variants() {
+        let action_datum = &mut action_data[action.index()];
+        if !action_datum.state.pressed() {
+            *action_datum = ActionData::default();
+        } else if !action_state.action_data(action.clone()).consumed {
+            action_state.consume(action);
+        }
+    }
+}
+
+/// Is the [`CapturedInput`] resource replacing real inputs?
+fn is_playing_back<A: Actionlike>(captured_input: Option<&CapturedInput<A>>) -> bool {
+    match captured_input {
+        Some(captured_input) => captured_input.is_playing(),
+        None => false,
+    }
+}
+
+/// Records the inputs of every [`ActionState`] into the [`CapturedInput`] resource, while it is recording
+///
+/// This runs after [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update),
+/// but before any [manual control](crate::plugin::InputManagerSystem::ManualControl), so only real inputs are recorded.
+pub fn record_input<A: Actionlike>(
+    captured_input: Option<ResMut<CapturedInput<A>>>,
+    query: Query<(Entity, &ActionState<A>)>,
+    resource: Option<Res<ActionState<A>>>,
+) {
+    if let Some(mut captured_input) = captured_input {
+        if !captured_input.is_recording() {
+            return;
+        }
+
+        captured_input.record(CapturedFrame {
+            resource: resource.map(|action_state| CapturedInput::capture(&action_state)),
+            entities: query
+                .iter()
+                .map(|(entity, action_state)| {
+                    (entity.to_bits(), CapturedInput::capture(action_state))
+                })
+                .collect(),
+        });
+    }
+}
+
+/// Replaces the inputs of every [`ActionState`] with the next frame of the [`CapturedInput`] resource, while it is playing
+///
+/// [`ActionState`]s that were not present in the recorded frame are released.
+pub fn playback_input<A: Actionlike>(
+    captured_input: Option<ResMut<CapturedInput<A>>>,
+    mut query: Query<(Entity, &mut ActionState<A>)>,
     resource: Option<ResMut<ActionState<A>>>,
+) {
+    let mut captured_input = match captured_input {
+        Some(captured_input) => captured_input,
+        None => return,
+    };
+
+    let frame = match captured_input.next_frame() {
+        Some(frame) => frame.clone(),
+        None => return,
+    };
+
+    if let Some(mut action_state) = resource {
+        match frame.resource {
+            Some(action_data) => action_state.update(action_data),
+            None => action_state.release_all(),
+        }
+    }
+
+    for (entity, mut action_state) in query.iter_mut() {
+        match frame
+            .entities
+            .iter()
+            .find(|(bits, _)| *bits == entity.to_bits())
+        {
+            Some((_, action_data)) => action_state.update(action_data.clone()),
+            None => action_state.release_all(),
+        }
+    }
+}
+
+/// Applies the events of the [`ScriptedInput`] timeline to every [`ActionState`]
+///
+/// Actions pressed by the script are [held](ActionState::hold), so they are not released by the absence of real inputs,
+/// and stay pressed without being just pressed again each frame.
+/// Actions are only released by the script when a release event occurs.
+///
+/// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually,
+/// after [`InputManagerSystem::Update`](crate::plugin::InputManagerSystem::Update).
+pub fn apply_scripted_input<A: Actionlike>(
+    mut scripted_input: ResMut<ScriptedInput<A>>,
+    time: Res<Time>,
+    mut query: Query<&mut ActionState<A>>,
+    mut resource: Option<ResMut<ActionState<A>>>,
+) {
+    let current_time = time.last_update().unwrap_or_else(|| time.startup());
+    let events = scripted_input.advance(current_time).to_vec();
+    let held = scripted_input.held();
+
+    let apply = |action_state: &mut ActionState<A>| {
+        for event in events.iter() {
+            match event.kind {
+                ScriptedEventKind::Press => action_state.hold(event.action.clone()),
+                ScriptedEventKind::Release => action_state.release(event.action.clone()),
+            }
+        }
+
+        for action in held.iter() {
+            action_state.hold(action.clone());
+        }
+    };
+
+    for mut action_state in query.iter_mut() {
+        apply(&mut action_state);
+    }
+
+    if let Some(action_state) = &mut resource {
+        apply(action_state);
+    }
+}
+
+/// Plays back the [`InputMacros`] for every [`ActionState`], starting any macros whose trigger was just pressed
+///
+/// Does nothing if the [`InputMacros`] resource does not exist.
+/// Every playing macro is cancelled when [`ToggleActions`] is disabled, releasing the actions it was holding.
+pub fn apply_input_macros<A: Actionlike>(
+    input_macros: Option<ResMut<InputMacros<A>>>,
     toggle_actions: Res<ToggleActions<A>>,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut ActionState<A>)>,
+    resource: Option<ResMut<ActionState<A>>>,
 ) {
-    if toggle_actions.is_changed() && !toggle_actions.enabled {
-        for mut action_state in query.iter_mut() {
-            action_state.release_all();
+    let mut input_macros = match input_macros {
+        Some(input_macros) => input_macros,
+        None => return,
+    };
+
+    let has_resource = resource.is_some();
+
+    if !toggle_actions.enabled {
+        input_macros.cancel_all();
+
+        for (entity, mut action_state) in query.iter_mut() {
+            input_macros.release_cancelled(Some(entity), &mut action_state);
         }
+
         if let Some(mut action_state) = resource {
-            action_state.release_all();
+            input_macros.release_cancelled(None, &mut action_state);
+        }
+    } else {
+        let current_time = time.last_update().unwrap_or_else(|| time.startup());
+
+        for (entity, mut action_state) in query.iter_mut() {
+            input_macros.apply(Some(entity), &mut action_state, current_time);
+        }
+
+        if let Some(mut action_state) = resource {
+            input_macros.apply(None, &mut action_state, current_time);
+        }
+    }
+
+    input_macros.retain_targets(|target| match target {
+        Some(entity) => query.get(entity).is_ok(),
+        None => has_resource,
+    });
+}
+
+/// Binds the first input pressed while [`BindingCapture`] is active to the action being captured
+///
+/// Keyboard keys are checked first, then mouse buttons, then gamepad buttons.
+/// Inputs that were already held when capture began are ignored until they are pressed again,
+/// so the key used to open a rebinding menu is not immediately captured.
+/// Pressing the [`cancel_key`](BindingCapture::cancel_key) cancels capture instead.
+/// The captured input replaces the existing bindings of the action in the targeted [`InputMap`]
+/// that share an [`InputMode`](crate::user_input::InputMode) with it,
+/// so rebinding the keyboard controls of an action leaves its gamepad bindings alone.
+///
+/// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually.
+/// It should run after [`InputSystem`](bevy_input::InputSystem), so that it sees the inputs pressed during this frame.
+/// Missing input resources are treated as if no input was pressed.
+pub fn capture_binding<A: Actionlike>(
+    binding_capture: Option<ResMut<BindingCapture<A>>>,
+    keycodes: Option<Res<Input<KeyCode>>>,
+    mouse_buttons: Option<Res<Input<MouseButton>>>,
+    gamepad_buttons: Option<Res<Input<GamepadButton>>>,
+    input_map: Option<ResMut<InputMap<A>>>,
+    mut query: Query<&mut InputMap<A>>,
+    mut previously_pressed: Local<Vec<UserInput>>,
+) {
+    let mut pressed: Vec<UserInput> = Vec::new();
+    if let Some(keycodes) = &keycodes {
+        pressed.extend(keycodes.get_pressed().copied().map(UserInput::from));
+    }
+    if let Some(mouse_buttons) = &mouse_buttons {
+        pressed.extend(mouse_buttons.get_pressed().copied().map(UserInput::from));
+    }
+    if let Some(gamepad_buttons) = &gamepad_buttons {
+        pressed.extend(
+            gamepad_buttons
+                .get_pressed()
+                .map(|&GamepadButton(_, button_type)| UserInput::from(button_type)),
+        );
+    }
+
+    // Tracked even while idle, so that inputs held when capture starts are not treated as new
+    let newly_pressed: Vec<UserInput> = pressed
+        .iter()
+        .filter(|input| !previously_pressed.contains(input))
+        .cloned()
+        .collect();
+    *previously_pressed = pressed;
+
+    let mut binding_capture = match binding_capture {
+        Some(binding_capture) => binding_capture,
+        None => return,
+    };
+
+    if binding_capture.capturing().is_none() {
+        return;
+    }
+
+    if newly_pressed.contains(&UserInput::from(binding_capture.cancel_key)) {
+        binding_capture.cancel();
+        return;
+    }
+
+    let input = match newly_pressed.into_iter().next() {
+        Some(input) => input,
+        None => return,
+    };
+
+    let entity = binding_capture.target_entity();
+    binding_capture.complete(input);
+
+    if let Some(BindingCaptureOutcome::Bound { action, input }) = binding_capture.outcome() {
+        let input_map = match entity {
+            Some(entity) => query.get_mut(entity).ok().map(Mut::into_inner),
+            None => input_map.map(ResMut::into_inner),
+        };
+
+        if let Some(input_map) = input_map {
+            let input_modes = input.input_modes();
+            let replaced: Vec<UserInput> = input_map
+                .get(action.clone())
+                .iter()
+                .filter(|bound| {
+                    input_modes
+                        .iter()
+                        .any(|&input_mode| bound.matches_input_mode(input_mode))
+                })
+                .cloned()
+                .collect();
+
+            for bound in replaced {
+                input_map.remove(action.clone(), bound);
+            }
+            input_map.insert(action.clone(), input.clone());
+        }
+    }
+}
+
+/// Collects typed characters into the [`TextInput`] resource while it is capturing
+///
+/// Characters received on the frame that [`toggle_text_input_on_press`] starts capture are discarded.
+/// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually,
+/// along with the [`TextInput`] resource.
+pub fn capture_text_input(
+    mut text_input: ResMut<TextInput>,
+    mut received_characters: EventReader<ReceivedCharacter>,
+) {
+    let skip_frame = text_input.take_skip_frame();
+
+    for received_character in received_characters.iter() {
+        if !skip_frame {
+            text_input.push(received_character.char);
         }
     }
 }
 
+/// Starts or stops [`TextInput`] capture when the action stored in [`ToggleTextInputOnPress`] is just pressed
+///
+/// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually.
+/// It should run before [`capture_text_input`]: the characters received on the frame that capture starts,
+/// such as the one typed by the toggling key press, are then discarded rather than captured.
+pub fn toggle_text_input_on_press<A: Actionlike>(
+    query: Query<&ActionState<A>>,
+    resource: Option<Res<ActionState<A>>>,
+    toggle_text_input_on_press: Res<ToggleTextInputOnPress<A>>,
+    mut text_input: ResMut<TextInput>,
+) {
+    let action = toggle_text_input_on_press.action.clone();
+
+    let just_pressed = query
+        .iter()
+        .chain(resource.as_deref())
+        .any(|action_state| action_state.just_pressed(action.clone()));
+
+    if just_pressed {
+        if text_input.is_capturing() {
+            text_input.stop_capture();
+        } else {
+            text_input.start_capture_next_frame();
+        }
+    }
+}
+
+/// Overwrites the [`Input<A>`] resource to match the [`ActionState<A>`] resource, for use by code that expects Bevy's [`Input`] API
+///
+/// This system is not part of the [`InputManagerPlugin`](crate::plugin::InputManagerPlugin) and must be added manually,
+/// along with the [`Input<A>`] resource.
+/// It should run after [`InputManagerSystem::ManualControl`](crate::plugin::InputManagerSystem::ManualControl),
+/// so that the [`Input<A>`] reflects the final state of each action for the whole frame.
+/// See [`ActionState::to_input`] for more details.
+pub fn update_input_from_action_state<A: Actionlike + Copy + Eq + std::hash::Hash>(
+    action_state: Res<ActionState<A>>,
+    mut input: ResMut<Input<A>>,
+) {
+    action_state.write_to_input(&mut input);
+}
+
 /// Returns [`ShouldRun::No`] if [`DisableInput`] exists and [`ShouldRun::Yes`] otherwise
 pub(super) fn run_if_enabled<A: Actionlike>(toggle_actions: Res<ToggleActions<A>>) -> ShouldRun {
     if toggle_actions.enabled {
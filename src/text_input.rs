@@ -0,0 +1,121 @@
+//! Captures typed text, for chat boxes, naming screens and other text entry
+
+use crate::Actionlike;
+
+/// A resource that collects the characters typed by the user while capture is active
+///
+/// Unlike ordinary bindings, this records the text produced by the keyboard (respecting layouts, Shift and so on),
+/// rather than the physical [`KeyCode`](bevy_input::keyboard::KeyCode)s that were pressed.
+/// Characters are collected from the [`ReceivedCharacter`](bevy_window::ReceivedCharacter) events
+/// by the [`capture_text_input`](crate::systems::capture_text_input) system.
+///
+/// # Example
+/// ```rust
+/// use leafwing_input_manager::text_input::TextInput;
+///
+/// let mut text_input = TextInput::default();
+/// assert!(!text_input.is_capturing());
+///
+/// text_input.start_capture();
+/// assert!(text_input.is_capturing());
+///
+/// // Typically performed by the `capture_text_input` system
+/// text_input.push('h');
+/// text_input.push('i');
+/// assert_eq!(text_input.buffer(), "hi");
+///
+/// // Text typed after capture stops is ignored
+/// text_input.stop_capture();
+/// text_input.push('!');
+/// assert_eq!(text_input.take(), "hi");
+/// assert_eq!(text_input.buffer(), "");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TextInput {
+    capturing: bool,
+    buffer: String,
+    /// Should the characters received during this frame be discarded, because capture only just started?
+    skip_frame: bool,
+}
+
+impl TextInput {
+    /// Begins collecting typed characters
+    ///
+    /// Any previously accumulated text is discarded.
+    pub fn start_capture(&mut self) {
+        self.capturing = true;
+        self.buffer.clear();
+    }
+
+    /// Begins collecting typed characters, ignoring those typed during the current frame
+    ///
+    /// This is used by [`toggle_text_input_on_press`](crate::systems::toggle_text_input_on_press),
+    /// so that the character produced by the key that starts capture is not recorded.
+    pub(crate) fn start_capture_next_frame(&mut self) {
+        self.start_capture();
+        self.skip_frame = true;
+    }
+
+    /// Returns whether the characters received during this frame should be discarded, resetting it for the next frame
+    pub(crate) fn take_skip_frame(&mut self) -> bool {
+        std::mem::take(&mut self.skip_frame)
+    }
+
+    /// Stops collecting typed characters
+    ///
+    /// The accumulated text is kept until [`TextInput::take`] or [`TextInput::start_capture`] is called.
+    pub fn stop_capture(&mut self) {
+        self.capturing = false;
+        self.skip_frame = false;
+    }
+
+    /// Is text currently being captured?
+    #[inline]
+    #[must_use]
+    pub fn is_capturing(&self) -> bool {
+        self.capturing
+    }
+
+    /// The text accumulated since capture began
+    #[inline]
+    #[must_use]
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Removes and returns the accumulated text, without stopping capture
+    pub fn take(&mut self) -> String {
+        std::mem::take(&mut self.buffer)
+    }
+
+    /// Records a typed `character`, if capture is active
+    ///
+    /// Backspace removes the last character, and all other control characters are ignored.
+    pub fn push(&mut self, character: char) {
+        if !self.capturing {
+            return;
+        }
+
+        if character == '\u{8}' {
+            self.buffer.pop();
+        } else if !character.is_control() {
+            self.buffer.push(character);
+        }
+    }
+}
+
+/// Toggles [`TextInput`] capture whenever `action` is just pressed in an [`ActionState<A>`](crate::action_state::ActionState)
+///
+/// Insert this as a resource, and add the [`toggle_text_input_on_press`](crate::systems::toggle_text_input_on_press) system.
+pub struct ToggleTextInputOnPress<A: Actionlike> {
+    /// The action which starts and stops text capture
+    pub action: A,
+}
+
+impl<A: Actionlike> ToggleTextInputOnPress<A> {
+    /// Creates a new [`ToggleTextInputOnPress`], which is triggered by the provided `action`
+    #[must_use]
+    pub fn new(action: A) -> Self {
+        Self { action }
+    }
+}
@@ -0,0 +1,136 @@
+//! On-screen controls for touch screens, such as virtual buttons for mobile games
+
+use crate::Actionlike;
+use bevy_ecs::{component::Component, entity::Entity};
+use bevy_math::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// An axis-aligned rectangle of the screen, measured in the same coordinates as [`Touch::position`](bevy_input::touch::Touch::position)
+///
+/// # Example
+/// ```rust
+/// use bevy_math::Vec2;
+/// use leafwing_input_manager::touch::TouchRegion;
+///
+/// let region = TouchRegion::new(Vec2::new(0.0, 0.0), Vec2::new(100.0, 50.0));
+/// assert!(region.contains(Vec2::new(50.0, 25.0)));
+/// assert!(!region.contains(Vec2::new(150.0, 25.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TouchRegion {
+    /// The corner of the region with the smallest coordinates
+    pub min: Vec2,
+    /// The corner of the region with the largest coordinates
+    pub max: Vec2,
+}
+
+impl TouchRegion {
+    /// Creates a new [`TouchRegion`] spanning the two provided corners, in any order
+    #[must_use]
+    pub fn new(a: Vec2, b: Vec2) -> Self {
+        TouchRegion {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Creates a new [`TouchRegion`] with the provided `center` and `size`
+    #[must_use]
+    pub fn from_center_size(center: Vec2, size: Vec2) -> Self {
+        TouchRegion::new(center - size / 2.0, center + size / 2.0)
+    }
+
+    /// Is the `position` inside of this region, including its edges?
+    #[must_use]
+    pub fn contains(&self, position: Vec2) -> bool {
+        position.cmpge(self.min).all() && position.cmple(self.max).all()
+    }
+}
+
+/// A component that presses an action in the [`ActionState`](crate::action_state::ActionState) of the associated entity
+/// while any touch is inside of its `region`
+///
+/// This is the touch screen equivalent of an [`ActionStateDriver`](crate::action_state::ActionStateDriver),
+/// but does not require `bevy_ui`: the `region` is checked directly against the [`Touches`](bevy_input::touch::Touches) resource.
+/// Several touches can press several virtual buttons at once,
+/// so a virtual d-pad is simply four of these components, one for each direction.
+///
+/// Used in [`update_action_state_from_touches`](crate::systems::update_action_state_from_touches).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct VirtualButton<A: Actionlike> {
+    /// The action pressed by this button
+    pub action: A,
+    /// The area of the screen that must be touched to press the action
+    pub region: TouchRegion,
+    /// The entity whose action state should be updated
+    pub entity: Entity,
+}
+
+/// A component that turns touches into an analog stick for the action in the [`ActionState`](crate::action_state::ActionState)
+/// of the associated entity
+///
+/// The joystick is grabbed by any touch that starts within `radius` of its `center`,
+/// and follows that touch until it is lifted, even if it is dragged outside of the circle.
+/// While held, the action is pressed, and its [`axis_pair`](crate::action_state::ActionState::axis_pair)
+/// is the offset of the touch from the `center`, divided by the `radius` and capped to a length of 1.
+/// Its [`axis_value`](crate::action_state::ActionState::axis_value) is the length of that offset.
+///
+/// Combine this with [`VirtualButton`] for a complete on-screen gamepad.
+///
+/// Used in [`update_action_state_from_virtual_joysticks`](crate::systems::update_action_state_from_virtual_joysticks).
+///
+/// # Example
+/// ```rust
+/// use bevy_math::Vec2;
+/// use leafwing_input_manager::touch::VirtualJoystick;
+/// # use bevy_ecs::entity::Entity;
+/// # use leafwing_input_manager::prelude::*;
+/// # #[derive(Actionlike, Clone, Copy, PartialEq, Eq, Debug)]
+/// # enum Action {
+/// #     Move,
+/// # }
+/// # let player = Entity::from_raw(0);
+///
+/// let joystick = VirtualJoystick {
+///     action: Action::Move,
+///     center: Vec2::new(100.0, 100.0),
+///     radius: 50.0,
+///     entity: player,
+/// };
+///
+/// assert!(joystick.contains(Vec2::new(120.0, 80.0)));
+/// assert_eq!(joystick.offset(Vec2::new(125.0, 100.0)), Vec2::new(0.5, 0.0));
+/// // The offset is capped at the edge of the joystick
+/// assert_eq!(joystick.offset(Vec2::new(100.0, 300.0)), Vec2::new(0.0, 1.0));
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct VirtualJoystick<A: Actionlike> {
+    /// The action controlled by this joystick
+    pub action: A,
+    /// The resting position of the joystick, in the same coordinates as [`Touch::position`](bevy_input::touch::Touch::position)
+    pub center: Vec2,
+    /// The distance from the `center` at which the joystick is fully deflected
+    pub radius: f32,
+    /// The entity whose action state should be updated
+    pub entity: Entity,
+}
+
+impl<A: Actionlike> VirtualJoystick<A> {
+    /// Can a touch starting at `position` grab this joystick?
+    #[must_use]
+    pub fn contains(&self, position: Vec2) -> bool {
+        position.distance(self.center) <= self.radius
+    }
+
+    /// The deflection of this joystick when held at `position`, with a length of at most 1
+    ///
+    /// Returns [`Vec2::ZERO`] if the `radius` is not positive.
+    #[must_use]
+    pub fn offset(&self, position: Vec2) -> Vec2 {
+        if self.radius <= 0.0 {
+            return Vec2::ZERO;
+        }
+
+        ((position - self.center) / self.radius).clamp_length_max(1.0)
+    }
+}
@@ -1,13 +1,16 @@
 //! Helpful abstractions over user inputs of all sorts
 
+use bevy_ecs::event::Events;
 use bevy_input::{
-    gamepad::{Gamepad, GamepadButton, GamepadButtonType},
+    gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType},
     keyboard::KeyCode,
-    mouse::MouseButton,
-    Input,
+    mouse::{MouseButton, MouseMotion, MouseScrollUnit, MouseWheel},
+    Axis, Input,
 };
+use bevy_math::Vec2;
 
-use bevy_utils::HashSet;
+use bevy_utils::{Duration, HashMap, HashSet, Instant};
+use core::hash::Hash;
 use petitset::PetitSet;
 use serde::{Deserialize, Serialize};
 
@@ -22,7 +25,61 @@ pub enum UserInput {
     ///
     /// Up to 8 (!!) buttons can be chorded together at once.
     /// Chords are considered to belong to all of the [InputMode]s of their constituent buttons.
+    /// A chord is pressed only while all of its buttons are held: releasing any one of them releases the chord.
     Chord(PetitSet<InputButton, 8>),
+    /// A single analog axis of a gamepad, such as one direction of a joystick or a trigger
+    ///
+    /// Its value is read from the [`Axis<GamepadAxis>`] resource, and is clamped to `[-1.0, 1.0]`.
+    /// It is considered pressed whenever its value is not zero:
+    /// use [`InputMap::set_deadzone`](crate::input_map::InputMap::set_deadzone) to configure its dead zone.
+    /// Considered to be part of [`InputMode::Gamepad`].
+    SingleAxis(GamepadAxisType),
+    /// One half of a single analog axis of a gamepad, such as pushing a joystick down
+    ///
+    /// Its value is read like a [`UserInput::SingleAxis`], negated if `inverted`, and then only positive values are kept,
+    /// so that it ranges from `0.0` to `1.0`.
+    /// Bind both halves of an axis to opposing actions to treat a joystick axis like a pair of buttons:
+    /// see [`UserInput::positive_axis`] and [`UserInput::negative_axis`].
+    /// Considered to be part of [`InputMode::Gamepad`].
+    HalfAxis {
+        /// The axis that is read
+        axis_type: GamepadAxisType,
+        /// Is this the negative half of the axis?
+        inverted: bool,
+    },
+    /// Movement of the mouse in a single direction
+    ///
+    /// The [`MouseMotion`] events of each frame are summed together,
+    /// and this input is pressed if the total distance moved in `direction` exceeds the
+    /// [`InputMap::mouse_motion_threshold`](crate::input_map::InputMap::mouse_motion_threshold).
+    /// Considered to be part of [`InputMode::Mouse`].
+    MouseMotion {
+        /// The direction in which the mouse must move
+        direction: MouseMotionDirection,
+    },
+    /// Scrolling the mouse wheel in a single direction
+    ///
+    /// The [`MouseWheel`] events of each frame are summed together,
+    /// and this input is pressed during each frame in which the wheel is scrolled in `direction`.
+    /// The distance scrolled can be read using [`ActionState::mouse_wheel_value`](crate::action_state::ActionState::mouse_wheel_value).
+    /// Considered to be part of [`InputMode::Mouse`].
+    MouseWheel {
+        /// The direction in which the mouse wheel must be scrolled
+        direction: MouseWheelDirection,
+    },
+    /// A pair of analog axes of a gamepad, such as a joystick
+    ///
+    /// Each value is read from the [`Axis<GamepadAxis>`] resource and clamped to `[-1.0, 1.0]`,
+    /// and the combined values can be read using [`ActionState::axis_pair`](crate::action_state::ActionState::axis_pair).
+    /// It is considered pressed whenever either of its values is not zero,
+    /// and its magnitude is reported as its [`ActionState::axis_value`](crate::action_state::ActionState::axis_value).
+    /// Considered to be part of [`InputMode::Gamepad`].
+    DualAxis {
+        /// The axis that controls horizontal movement
+        x: GamepadAxisType,
+        /// The axis that controls vertical movement
+        y: GamepadAxisType,
+    },
 }
 
 impl UserInput {
@@ -45,6 +102,42 @@ impl UserInput {
         }
     }
 
+    /// Creates a [`UserInput::HalfAxis`] which is pressed when the `axis_type` is positive, such as pushing a joystick up or right
+    #[must_use]
+    pub fn positive_axis(axis_type: GamepadAxisType) -> Self {
+        UserInput::HalfAxis {
+            axis_type,
+            inverted: false,
+        }
+    }
+
+    /// Creates a [`UserInput::HalfAxis`] which is pressed when the `axis_type` is negative, such as pushing a joystick down or left
+    #[must_use]
+    pub fn negative_axis(axis_type: GamepadAxisType) -> Self {
+        UserInput::HalfAxis {
+            axis_type,
+            inverted: true,
+        }
+    }
+
+    /// Creates a [`UserInput::DualAxis`] for the left joystick of a gamepad
+    #[must_use]
+    pub fn left_stick() -> Self {
+        UserInput::DualAxis {
+            x: GamepadAxisType::LeftStickX,
+            y: GamepadAxisType::LeftStickY,
+        }
+    }
+
+    /// Creates a [`UserInput::DualAxis`] for the right joystick of a gamepad
+    #[must_use]
+    pub fn right_stick() -> Self {
+        UserInput::DualAxis {
+            x: GamepadAxisType::RightStickX,
+            y: GamepadAxisType::RightStickY,
+        }
+    }
+
     /// Which [`InputMode`]s does this input contain?
     pub fn input_modes(&self) -> PetitSet<InputMode, 3> {
         let mut set = PetitSet::default();
@@ -57,6 +150,12 @@ impl UserInput {
                     set.insert(button.into());
                 }
             }
+            UserInput::SingleAxis(_) | UserInput::HalfAxis { .. } | UserInput::DualAxis { .. } => {
+                set.insert(InputMode::Gamepad);
+            }
+            UserInput::MouseMotion { .. } | UserInput::MouseWheel { .. } => {
+                set.insert(InputMode::Mouse);
+            }
         }
         set
     }
@@ -81,14 +180,27 @@ impl UserInput {
                 }
                 false
             }
+            UserInput::SingleAxis(_) | UserInput::HalfAxis { .. } | UserInput::DualAxis { .. } => {
+                input_mode == InputMode::Gamepad
+            }
+            UserInput::MouseMotion { .. } | UserInput::MouseWheel { .. } => {
+                input_mode == InputMode::Mouse
+            }
         }
     }
 
     /// The number of buttons in the [`UserInput`]
+    ///
+    /// Axes, mouse motion and mouse wheel inputs count as a single button, even if they contain two axes.
     pub fn len(&self) -> usize {
         match self {
             UserInput::Single(_) => 1,
             UserInput::Chord(button_set) => button_set.len(),
+            UserInput::SingleAxis(_)
+            | UserInput::HalfAxis { .. }
+            | UserInput::MouseMotion { .. }
+            | UserInput::MouseWheel { .. }
+            | UserInput::DualAxis { .. } => 1,
         }
     }
 
@@ -97,6 +209,56 @@ impl UserInput {
         self.len() == 0
     }
 
+    /// Is this [`UserInput`] a [`UserInput::Chord`]?
+    #[must_use]
+    pub fn is_chord(&self) -> bool {
+        matches!(self, UserInput::Chord(_))
+    }
+
+    /// Returns the buttons that make up this input if it is a [`UserInput::Chord`], or [`None`] if it is a single button
+    #[must_use]
+    pub fn as_chord(&self) -> Option<&PetitSet<InputButton, 8>> {
+        match self {
+            UserInput::Chord(button_set) => Some(button_set),
+            _ => None,
+        }
+    }
+
+    /// Returns each of the buttons that make up this [`UserInput`], in order
+    ///
+    /// This treats single buttons and chords uniformly, which is useful for displaying each button with its own glyph.
+    /// Axes, mouse motion and mouse wheel inputs contain no buttons.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_input::keyboard::KeyCode;
+    /// use leafwing_input_manager::user_input::{InputButton, UserInput};
+    ///
+    /// let save = UserInput::chord([KeyCode::LControl, KeyCode::S]);
+    /// assert!(save.is_chord());
+    /// assert_eq!(
+    ///     save.buttons(),
+    ///     vec![InputButton::from(KeyCode::LControl), InputButton::from(KeyCode::S)]
+    /// );
+    ///
+    /// let jump = UserInput::from(KeyCode::Space);
+    /// assert!(!jump.is_chord());
+    /// assert_eq!(jump.as_chord(), None);
+    /// assert_eq!(jump.buttons(), vec![InputButton::from(KeyCode::Space)]);
+    /// ```
+    #[must_use]
+    pub fn buttons(&self) -> Vec<InputButton> {
+        match self {
+            UserInput::Single(button) => vec![*button],
+            UserInput::Chord(button_set) => button_set.iter().copied().collect(),
+            UserInput::SingleAxis(_)
+            | UserInput::HalfAxis { .. }
+            | UserInput::MouseMotion { .. }
+            | UserInput::MouseWheel { .. }
+            | UserInput::DualAxis { .. } => Vec::new(),
+        }
+    }
+
     /// How many of the provided `buttons` are found in the [`UserInput`]
     ///
     /// # Example
@@ -133,10 +295,17 @@ impl UserInput {
 
                 n_matching
             }
+            UserInput::SingleAxis(_)
+            | UserInput::HalfAxis { .. }
+            | UserInput::MouseMotion { .. }
+            | UserInput::MouseWheel { .. }
+            | UserInput::DualAxis { .. } => 0,
         }
     }
 
     /// Returns the raw inputs that make up this [`UserInput`]
+    ///
+    /// Each [`InputButton::Modifier`] is represented by its left key.
     pub fn raw_inputs(&self) -> (Vec<GamepadButtonType>, Vec<KeyCode>, Vec<MouseButton>) {
         let mut gamepad_buttons: Vec<GamepadButtonType> = Vec::default();
         let mut keyboard_buttons: Vec<KeyCode> = Vec::default();
@@ -147,6 +316,8 @@ impl UserInput {
                 InputButton::Gamepad(variant) => gamepad_buttons.push(variant),
                 InputButton::Keyboard(variant) => keyboard_buttons.push(variant),
                 InputButton::Mouse(variant) => mouse_buttons.push(variant),
+                InputButton::Touchpad(_) => (),
+                InputButton::Modifier(modifier) => keyboard_buttons.push(modifier.keycodes()[0]),
             },
             UserInput::Chord(button_set) => {
                 for button in button_set.iter() {
@@ -154,13 +325,35 @@ impl UserInput {
                         InputButton::Gamepad(variant) => gamepad_buttons.push(*variant),
                         InputButton::Keyboard(variant) => keyboard_buttons.push(*variant),
                         InputButton::Mouse(variant) => mouse_buttons.push(*variant),
+                        InputButton::Touchpad(_) => (),
+                        InputButton::Modifier(modifier) => {
+                            keyboard_buttons.push(modifier.keycodes()[0]);
+                        }
                     }
                 }
             }
+            UserInput::SingleAxis(_)
+            | UserInput::HalfAxis { .. }
+            | UserInput::MouseMotion { .. }
+            | UserInput::MouseWheel { .. }
+            | UserInput::DualAxis { .. } => (),
         };
 
         (gamepad_buttons, keyboard_buttons, mouse_buttons)
     }
+
+    /// Returns the [`TouchpadButton`]s that make up this [`UserInput`]
+    ///
+    /// These are not included in [`UserInput::raw_inputs`].
+    pub fn touchpad_buttons(&self) -> Vec<TouchpadButton> {
+        self.buttons()
+            .into_iter()
+            .filter_map(|button| match button {
+                InputButton::Touchpad(touchpad_button) => Some(touchpad_button),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 impl From<InputButton> for UserInput {
@@ -181,12 +374,149 @@ impl From<KeyCode> for UserInput {
     }
 }
 
+impl From<TouchpadButton> for UserInput {
+    fn from(input: TouchpadButton) -> Self {
+        UserInput::Single(InputButton::Touchpad(input))
+    }
+}
+
 impl From<MouseButton> for UserInput {
     fn from(input: MouseButton) -> Self {
         UserInput::Single(InputButton::Mouse(input))
     }
 }
 
+impl From<ModifierKey> for UserInput {
+    fn from(input: ModifierKey) -> Self {
+        UserInput::Single(InputButton::Modifier(input))
+    }
+}
+
+impl From<GamepadAxisType> for UserInput {
+    fn from(input: GamepadAxisType) -> Self {
+        UserInput::SingleAxis(input)
+    }
+}
+
+impl From<MouseMotionDirection> for UserInput {
+    fn from(direction: MouseMotionDirection) -> Self {
+        UserInput::MouseMotion { direction }
+    }
+}
+
+impl From<MouseWheelDirection> for UserInput {
+    fn from(direction: MouseWheelDirection) -> Self {
+        UserInput::MouseWheel { direction }
+    }
+}
+
+/// The directions in which the mouse can be moved, used by [`UserInput::MouseMotion`]
+///
+/// These follow the conventions of [`MouseMotion`]: moving the mouse [`MouseMotionDirection::Down`]
+/// produces a positive `y` delta.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MouseMotionDirection {
+    /// Moving the mouse away from the user
+    Up,
+    /// Moving the mouse towards the user
+    Down,
+    /// Moving the mouse to the left
+    Left,
+    /// Moving the mouse to the right
+    Right,
+}
+
+impl MouseMotionDirection {
+    /// The distance that `delta` travels in this direction
+    ///
+    /// Movement in the opposite direction is reported as a negative distance.
+    #[must_use]
+    pub fn distance(self, delta: Vec2) -> f32 {
+        match self {
+            MouseMotionDirection::Up => -delta.y,
+            MouseMotionDirection::Down => delta.y,
+            MouseMotionDirection::Left => -delta.x,
+            MouseMotionDirection::Right => delta.x,
+        }
+    }
+}
+
+/// The number of pixels of [`MouseScrollUnit::Pixel`] scrolling that are treated as a single line
+///
+/// Pixel-based scrolling is reported by trackpads and high-precision mouse wheels;
+/// this is used to normalize it so that all scrolling is measured in lines.
+pub const MOUSE_WHEEL_PIXELS_PER_LINE: f32 = 20.0;
+
+/// The directions in which the mouse wheel can be scrolled, used by [`UserInput::MouseWheel`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MouseWheelDirection {
+    /// Scrolling upwards, which produces a positive `y` value
+    Up,
+    /// Scrolling downwards, which produces a negative `y` value
+    Down,
+    /// Scrolling to the left, which produces a negative `x` value
+    Left,
+    /// Scrolling to the right, which produces a positive `x` value
+    Right,
+}
+
+impl MouseWheelDirection {
+    /// The distance that `scroll` travels in this direction
+    ///
+    /// Scrolling in the opposite direction is reported as a negative distance.
+    #[must_use]
+    pub fn distance(self, scroll: Vec2) -> f32 {
+        match self {
+            MouseWheelDirection::Up => scroll.y,
+            MouseWheelDirection::Down => -scroll.y,
+            MouseWheelDirection::Left => -scroll.x,
+            MouseWheelDirection::Right => scroll.x,
+        }
+    }
+}
+
+/// A keyboard modifier, which may be pressed using either the left or right variant of the key
+///
+/// Bind this directly as an [`InputButton::Modifier`], either on its own or inside of a chord,
+/// or use [`InputMap::bind_chord`](crate::input_map::InputMap::bind_chord) to concisely construct chords.
+///
+/// # Example
+/// ```rust
+/// use bevy_input::{keyboard::KeyCode, Input};
+/// use leafwing_input_manager::user_input::{InputButton, InputStreams, ModifierKey, UserInput};
+///
+/// let save = UserInput::chord([InputButton::Modifier(ModifierKey::Control), KeyCode::S.into()]);
+///
+/// let mut keyboard = Input::<KeyCode>::default();
+/// keyboard.press(KeyCode::RControl);
+/// keyboard.press(KeyCode::S);
+/// assert!(InputStreams::from_keyboard(&keyboard).input_pressed(&save));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModifierKey {
+    /// The Alt keys
+    Alt,
+    /// The Control keys
+    Control,
+    /// The Shift keys
+    Shift,
+    /// The Super (or "Windows" / "Command") keys
+    Super,
+}
+
+impl ModifierKey {
+    /// The [`KeyCode`]s for the left and right variants of this modifier key
+    #[must_use]
+    pub fn keycodes(self) -> [KeyCode; 2] {
+        match self {
+            ModifierKey::Alt => [KeyCode::LAlt, KeyCode::RAlt],
+            ModifierKey::Control => [KeyCode::LControl, KeyCode::RControl],
+            ModifierKey::Shift => [KeyCode::LShift, KeyCode::RShift],
+            ModifierKey::Super => [KeyCode::LWin, KeyCode::RWin],
+        }
+    }
+}
+
 /// A button-like input type
 ///
 /// See [`Button`] for the value-ful equivalent.
@@ -240,12 +570,33 @@ impl Iterator for InputModeIter {
     }
 }
 
+impl InputButton {
+    /// Is this a [`ModifierKey`], or one of the keyboard keys of a modifier?
+    #[must_use]
+    pub fn is_modifier(self) -> bool {
+        match self {
+            InputButton::Modifier(_) => true,
+            InputButton::Keyboard(keycode) => [
+                ModifierKey::Alt,
+                ModifierKey::Control,
+                ModifierKey::Shift,
+                ModifierKey::Super,
+            ]
+            .into_iter()
+            .any(|modifier| modifier.keycodes().contains(&keycode)),
+            _ => false,
+        }
+    }
+}
+
 impl From<InputButton> for InputMode {
     fn from(button: InputButton) -> Self {
         match button {
             InputButton::Gamepad(_) => InputMode::Gamepad,
             InputButton::Keyboard(_) => InputMode::Keyboard,
             InputButton::Mouse(_) => InputMode::Mouse,
+            InputButton::Touchpad(_) => InputMode::Gamepad,
+            InputButton::Modifier(_) => InputMode::Keyboard,
         }
     }
 }
@@ -267,6 +618,14 @@ pub enum InputButton {
     Keyboard(KeyCode),
     /// A button on a mouse
     Mouse(MouseButton),
+    /// A press or swipe on the touchpad of a gamepad
+    ///
+    /// Considered to be part of [`InputMode::Gamepad`].
+    Touchpad(TouchpadButton),
+    /// A keyboard modifier, pressed by either its left or right key
+    ///
+    /// Considered to be part of [`InputMode::Keyboard`].
+    Modifier(ModifierKey),
 }
 
 impl From<GamepadButtonType> for InputButton {
@@ -287,6 +646,346 @@ impl From<MouseButton> for InputButton {
     }
 }
 
+impl From<TouchpadButton> for InputButton {
+    fn from(input: TouchpadButton) -> Self {
+        InputButton::Touchpad(input)
+    }
+}
+
+impl From<ModifierKey> for InputButton {
+    fn from(input: ModifierKey) -> Self {
+        InputButton::Modifier(input)
+    }
+}
+
+/// The ways in which the touchpad of a gamepad (such as those on `DualShock` 4 and `DualSense` controllers) can be used as a button
+///
+/// Bevy does not currently report touchpad data, so these inputs are read from an [`Input<GamepadTouchpadButton>`] resource,
+/// which should be populated by your platform-specific integration.
+/// If this resource does not exist, or a controller does not have a touchpad, these inputs are simply never pressed.
+///
+/// Swipes are directional edges: they should be pressed on the frame that the swipe is detected,
+/// and are automatically released by [`release_touchpad_swipes`](crate::systems::release_touchpad_swipes)
+/// once the [`ActionState`](crate::action_state::ActionState) has been updated.
+/// Use [`TouchpadButton::swipe`] to convert the motion of a finger into a swipe direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TouchpadButton {
+    /// The touchpad is clicked down
+    Press,
+    /// A finger was swiped upwards across the touchpad
+    SwipeUp,
+    /// A finger was swiped downwards across the touchpad
+    SwipeDown,
+    /// A finger was swiped to the left across the touchpad
+    SwipeLeft,
+    /// A finger was swiped to the right across the touchpad
+    SwipeRight,
+}
+
+impl TouchpadButton {
+    /// Returns the swipe corresponding to the `delta` travelled by a finger, if it is at least `threshold` long
+    ///
+    /// `delta` should use a y-up convention. The dominant axis of the motion determines the direction.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::math::Vec2;
+    /// use leafwing_input_manager::user_input::TouchpadButton;
+    ///
+    /// assert_eq!(TouchpadButton::swipe(Vec2::new(0.8, 0.1), 0.5), Some(TouchpadButton::SwipeRight));
+    /// assert_eq!(TouchpadButton::swipe(Vec2::new(0.1, 0.2), 0.5), None);
+    /// ```
+    #[must_use]
+    pub fn swipe(delta: Vec2, threshold: f32) -> Option<TouchpadButton> {
+        if delta.length() < threshold {
+            return None;
+        }
+
+        let swipe = if delta.x.abs() >= delta.y.abs() {
+            if delta.x > 0.0 {
+                TouchpadButton::SwipeRight
+            } else {
+                TouchpadButton::SwipeLeft
+            }
+        } else if delta.y > 0.0 {
+            TouchpadButton::SwipeUp
+        } else {
+            TouchpadButton::SwipeDown
+        };
+
+        Some(swipe)
+    }
+
+    /// Is this button one of the swipe directions?
+    #[must_use]
+    pub fn is_swipe(self) -> bool {
+        self != TouchpadButton::Press
+    }
+}
+
+/// A [`TouchpadButton`] on a specific [`Gamepad`]
+///
+/// Stored in an [`Input`] resource, in the same way as [`GamepadButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GamepadTouchpadButton(pub Gamepad, pub TouchpadButton);
+
+/// The physical arrangement of the face buttons on a gamepad
+///
+/// Bindings are always written in terms of the [`ControllerLayout::Standard`] (Xbox-style) layout,
+/// where [`GamepadButtonType::South`] is the "A" button.
+/// When another layout is set on an [`InputMap`](crate::input_map::InputMap),
+/// gamepad bindings are remapped as they are resolved,
+/// so that the *semantic* meaning of each binding stays consistent across controllers.
+///
+/// If you would prefer to honor physical button positions instead,
+/// leave the layout set to [`ControllerLayout::Standard`] and use [`ControllerLayout::label`] for display purposes.
+///
+/// # Example
+/// ```rust
+/// use bevy_input::gamepad::GamepadButtonType;
+/// use leafwing_input_manager::user_input::ControllerLayout;
+///
+/// // Confirm is bound to the "A" button, which is on the right on Nintendo controllers
+/// let physical_button = ControllerLayout::Nintendo.remap(GamepadButtonType::South);
+/// assert_eq!(physical_button, GamepadButtonType::East);
+/// assert_eq!(ControllerLayout::Nintendo.label(physical_button), Some("A"));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ControllerLayout {
+    /// The Xbox-style layout, where A is south, B is east, X is west and Y is north
+    ///
+    /// This is the default layout, and does not remap any buttons.
+    #[default]
+    Standard,
+    /// The Nintendo-style layout, where A is east, B is south, X is north and Y is west
+    Nintendo,
+}
+
+impl ControllerLayout {
+    /// Converts a `button` from a binding into the physical button that should be checked on a controller with this layout
+    #[must_use]
+    pub fn remap(self, button: GamepadButtonType) -> GamepadButtonType {
+        use GamepadButtonType::*;
+
+        match self {
+            ControllerLayout::Standard => button,
+            ControllerLayout::Nintendo => match button {
+                South => East,
+                East => South,
+                West => North,
+                North => West,
+                other => other,
+            },
+        }
+    }
+
+    /// The label printed on the `physical_button` of a controller with this layout
+    ///
+    /// Returns `None` for buttons other than the four face buttons.
+    #[must_use]
+    pub fn label(self, physical_button: GamepadButtonType) -> Option<&'static str> {
+        use GamepadButtonType::*;
+
+        match (self, physical_button) {
+            (ControllerLayout::Standard, South) | (ControllerLayout::Nintendo, East) => Some("A"),
+            (ControllerLayout::Standard, East) | (ControllerLayout::Nintendo, South) => Some("B"),
+            (ControllerLayout::Standard, West) | (ControllerLayout::Nintendo, North) => Some("X"),
+            (ControllerLayout::Standard, North) | (ControllerLayout::Nintendo, West) => Some("Y"),
+            _ => None,
+        }
+    }
+}
+
+/// The [`Instant`] at which each keyboard key, mouse button and gamepad button that is currently held was pressed
+///
+/// This is used to check the chord windows set with [`InputMap::insert_chord_within`](crate::input_map::InputMap::insert_chord_within).
+/// It is stored as a resource, and kept up to date by the [`record_button_press_times`](crate::systems::record_button_press_times) system.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ButtonPressTimes {
+    keyboard: HashMap<KeyCode, Instant>,
+    mouse: HashMap<MouseButton, Instant>,
+    gamepad: HashMap<GamepadButton, Instant>,
+}
+
+impl ButtonPressTimes {
+    /// Records `now` as the press time of every button that has been pressed since the last update,
+    /// and forgets buttons that have been released
+    ///
+    /// Buttons that remain held keep their original press time.
+    pub fn update(
+        &mut self,
+        keyboard: Option<&Input<KeyCode>>,
+        mouse: Option<&Input<MouseButton>>,
+        gamepad: Option<&Input<GamepadButton>>,
+        now: Instant,
+    ) {
+        ButtonPressTimes::update_times(&mut self.keyboard, keyboard, now);
+        ButtonPressTimes::update_times(&mut self.mouse, mouse, now);
+        ButtonPressTimes::update_times(&mut self.gamepad, gamepad, now);
+    }
+
+    fn update_times<T: Copy + Eq + Hash + Send + Sync + 'static>(
+        times: &mut HashMap<T, Instant>,
+        input: Option<&Input<T>>,
+        now: Instant,
+    ) {
+        match input {
+            Some(input) => {
+                times.retain(|button, _| input.pressed(*button));
+                for &button in input.get_pressed() {
+                    times.entry(button).or_insert(now);
+                }
+            }
+            None => times.clear(),
+        }
+    }
+
+    /// The [`Instant`] at which the `key` was pressed, if it is held
+    #[must_use]
+    pub fn keyboard(&self, key: KeyCode) -> Option<Instant> {
+        self.keyboard.get(&key).copied()
+    }
+
+    /// The [`Instant`] at which the `mouse_button` was pressed, if it is held
+    #[must_use]
+    pub fn mouse(&self, mouse_button: MouseButton) -> Option<Instant> {
+        self.mouse.get(&mouse_button).copied()
+    }
+
+    /// The [`Instant`] at which the `gamepad_button` was pressed, if it is held
+    #[must_use]
+    pub fn gamepad(&self, gamepad_button: GamepadButton) -> Option<Instant> {
+        self.gamepad.get(&gamepad_button).copied()
+    }
+}
+
+/// A resource that scales and smooths mouse motion before it is read by [`UserInput::MouseMotion`] inputs
+///
+/// Raw [`MouseMotion`] deltas are multiplied by the `sensitivity` of each axis, flipped vertically if `invert_y` is set,
+/// and then optionally blended with the motion of previous frames.
+/// The processed motion is used both to check the [`InputMap::mouse_motion_threshold`](crate::input_map::InputMap::mouse_motion_threshold)
+/// and to produce the [`ActionState::mouse_motion_value`](crate::action_state::ActionState::mouse_motion_value) of each action.
+///
+/// This resource is not inserted by default, in which case raw deltas are used.
+/// Once inserted, it is kept up to date by the [`update_mouse_motion`](crate::systems::update_mouse_motion) system.
+///
+/// # Example
+/// ```rust
+/// use bevy_math::Vec2;
+/// use bevy_utils::{Duration, Instant};
+/// use leafwing_input_manager::user_input::MouseMotionConfig;
+///
+/// let mut config = MouseMotionConfig::default();
+/// config.sensitivity = Vec2::new(2.0, 1.0);
+/// config.invert_y = true;
+/// config.smoothing = Some(0.5);
+///
+/// let start = Instant::now();
+/// let frame = MouseMotionConfig::SMOOTHING_FRAME;
+/// config.update(Vec2::new(10.0, 10.0), start);
+/// // Half of the scaled motion is kept, with the other half blended in from previous frames
+/// assert_eq!(config.motion(), Vec2::new(10.0, -5.0));
+///
+/// // Without further movement, the motion decays smoothly
+/// config.update(Vec2::ZERO, start + frame);
+/// assert_eq!(config.motion(), Vec2::new(5.0, -2.5));
+///
+/// // At the same rate in real time, however long each frame takes
+/// config.update(Vec2::ZERO, start + frame * 3);
+/// assert!((config.motion() - Vec2::new(1.25, -0.625)).abs().max_element() < 1e-4);
+///
+/// // Until it is small enough to stop entirely
+/// for n_frames in 4..20 {
+///     config.update(Vec2::ZERO, start + frame * n_frames);
+/// }
+/// assert_eq!(config.motion(), Vec2::ZERO);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MouseMotionConfig {
+    /// The factor that each axis of the raw mouse motion is multiplied by
+    ///
+    /// Defaults to [`Vec2::ONE`].
+    pub sensitivity: Vec2,
+    /// Should vertical motion be flipped?
+    pub invert_y: bool,
+    /// The fraction of the previous motion retained over each [`MouseMotionConfig::SMOOTHING_FRAME`],
+    /// from 0 (no smoothing) to 1 (no movement)
+    ///
+    /// This is exponential smoothing: higher values reduce jitter, but make the motion lag behind the mouse.
+    /// The time between updates is taken into account, so the motion of previous frames decays at the same rate
+    /// in real time, regardless of the frame rate.
+    /// Once the smoothed motion falls below [`MouseMotionConfig::EPSILON`] on both axes, it is snapped to zero,
+    /// so that actions bound to mouse motion are released even with a threshold of `0.0`.
+    ///
+    /// If this is `None`, each frame's motion is used as-is.
+    pub smoothing: Option<f32>,
+    motion: Vec2,
+    last_update: Option<Instant>,
+}
+
+impl Default for MouseMotionConfig {
+    fn default() -> Self {
+        MouseMotionConfig {
+            sensitivity: Vec2::ONE,
+            invert_y: false,
+            smoothing: None,
+            motion: Vec2::ZERO,
+            last_update: None,
+        }
+    }
+}
+
+impl MouseMotionConfig {
+    /// Smoothed motion smaller than this on both axes is treated as no motion at all
+    pub const EPSILON: f32 = 0.01;
+
+    /// The length of time over which the [`smoothing`](MouseMotionConfig::smoothing) fraction of the motion is retained:
+    /// a single frame at 60 frames per second
+    ///
+    /// The first update is treated as lasting this long.
+    pub const SMOOTHING_FRAME: Duration = Duration::from_nanos(16_666_667);
+
+    /// Processes the `raw_motion` of the frame starting at `now`, updating the [`MouseMotionConfig::motion`]
+    ///
+    /// Repeated updates at the same `now` are ignored,
+    /// so that the motion is only smoothed once per frame, no matter how many systems update it.
+    pub fn update(&mut self, raw_motion: Vec2, now: Instant) {
+        if self.last_update == Some(now) {
+            return;
+        }
+        let elapsed = match self.last_update {
+            Some(last_update) => now.saturating_duration_since(last_update),
+            None => Self::SMOOTHING_FRAME,
+        };
+        self.last_update = Some(now);
+
+        let mut target = raw_motion * self.sensitivity;
+        if self.invert_y {
+            target.y = -target.y;
+        }
+
+        match self.smoothing {
+            Some(smoothing) => {
+                let frames = elapsed.as_secs_f32() / Self::SMOOTHING_FRAME.as_secs_f32();
+                let retained = smoothing.clamp(0.0, 1.0).powf(frames);
+                self.motion = self.motion.lerp(target, 1.0 - retained);
+
+                // Exponential smoothing never reaches zero on its own
+                if self.motion.abs().max_element() < Self::EPSILON {
+                    self.motion = Vec2::ZERO;
+                }
+            }
+            None => self.motion = target,
+        }
+    }
+
+    /// The scaled and smoothed mouse motion of the current frame
+    #[must_use]
+    pub fn motion(&self) -> Vec2 {
+        self.motion
+    }
+}
+
 /// A collection of [`Input`] structs, which can be used to update an [`InputMap`](crate::input_map::InputMap).
 ///
 /// Each of these streams is optional; if a stream does not exist, it is treated as if it were entirely unpressed.
@@ -300,8 +999,28 @@ pub struct InputStreams<'a> {
     pub keyboard: Option<&'a Input<KeyCode>>,
     /// An optional [`MouseButton`] [`Input`] stream
     pub mouse: Option<&'a Input<MouseButton>>,
+    /// An optional [`GamepadTouchpadButton`] [`Input`] stream
+    pub touchpad: Option<&'a Input<GamepadTouchpadButton>>,
+    /// An optional [`GamepadAxis`] [`Axis`] stream
+    pub gamepad_axes: Option<&'a Axis<GamepadAxis>>,
+    /// An optional [`MouseMotion`] event stream
+    pub mouse_motion: Option<&'a Events<MouseMotion>>,
+    /// An optional [`MouseWheel`] event stream
+    pub mouse_wheel: Option<&'a Events<MouseWheel>>,
     /// The [`Gamepad`] that this struct will detect inputs from
     pub associated_gamepad: Option<Gamepad>,
+    /// The [`ControllerLayout`] used to remap gamepad buttons before they are checked
+    pub controller_layout: ControllerLayout,
+    /// The distance that the mouse must move this frame for a [`UserInput::MouseMotion`] to be pressed
+    pub mouse_motion_threshold: f32,
+    /// The optional [`ButtonPressTimes`], used to check the windows of chords
+    ///
+    /// If this is `None`, chord windows are ignored.
+    pub press_times: Option<&'a ButtonPressTimes>,
+    /// The optional [`MouseMotionConfig`], which provides the processed mouse motion
+    ///
+    /// If this is `None`, the raw [`MouseMotion`] events are used.
+    pub mouse_motion_config: Option<&'a MouseMotionConfig>,
 }
 
 // Constructors
@@ -315,7 +1034,15 @@ impl<'a> InputStreams<'a> {
             gamepad: Some(gamepad_input_stream),
             keyboard: None,
             mouse: None,
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: Some(associated_gamepad),
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         }
     }
 
@@ -325,7 +1052,15 @@ impl<'a> InputStreams<'a> {
             gamepad: None,
             keyboard: Some(keyboard_input_stream),
             mouse: None,
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: None,
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         }
     }
 
@@ -335,7 +1070,15 @@ impl<'a> InputStreams<'a> {
             gamepad: None,
             keyboard: None,
             mouse: Some(mouse_input_stream),
+            touchpad: None,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: None,
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         }
     }
 }
@@ -347,6 +1090,257 @@ impl<'a> InputStreams<'a> {
         match input {
             UserInput::Single(button) => self.button_pressed(*button),
             UserInput::Chord(buttons) => self.all_buttons_pressed(buttons),
+            UserInput::SingleAxis(axis_type) => self.axis_value(*axis_type) != 0.0,
+            UserInput::HalfAxis {
+                axis_type,
+                inverted,
+            } => self.half_axis_value(*axis_type, *inverted) > 0.0,
+            UserInput::MouseMotion { direction } => {
+                direction.distance(self.mouse_motion()) > self.mouse_motion_threshold
+            }
+            UserInput::MouseWheel { direction } => self.mouse_wheel_distance(*direction) > 0.0,
+            UserInput::DualAxis { x, y } => {
+                self.axis_value(*x) != 0.0 || self.axis_value(*y) != 0.0
+            }
+        }
+    }
+
+    /// The analog value of the `input`, ranging from -1 to 1
+    ///
+    /// Buttons and chords report `1.0` when pressed and `0.0` when released,
+    /// while a [`UserInput::DualAxis`] reports its magnitude.
+    #[must_use]
+    pub fn input_value(&self, input: &UserInput) -> f32 {
+        match input {
+            UserInput::SingleAxis(axis_type) => self.axis_value(*axis_type),
+            UserInput::HalfAxis {
+                axis_type,
+                inverted,
+            } => self.half_axis_value(*axis_type, *inverted),
+            UserInput::DualAxis { x, y } => Vec2::new(self.axis_value(*x), self.axis_value(*y))
+                .length()
+                .min(1.0),
+            _ => {
+                if self.input_pressed(input) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    /// The value of the `axis_type` on the `associated_gamepad`, clamped to `[-1.0, 1.0]`
+    ///
+    /// Returns `0.0` if there is no associated gamepad or [`Axis<GamepadAxis>`] stream.
+    #[must_use]
+    pub fn axis_value(&self, axis_type: GamepadAxisType) -> f32 {
+        if let (Some(gamepad), Some(axis_stream)) = (self.associated_gamepad, self.gamepad_axes) {
+            axis_stream
+                .get(GamepadAxis(gamepad, axis_type))
+                .unwrap_or_default()
+                .clamp(-1.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// The value of one half of the `axis_type`, as read by a [`UserInput::HalfAxis`], ranging from 0 to 1
+    #[must_use]
+    pub fn half_axis_value(&self, axis_type: GamepadAxisType, inverted: bool) -> f32 {
+        let value = self.axis_value(axis_type);
+        let value = if inverted { -value } else { value };
+        value.max(0.0)
+    }
+
+    /// The total distance that the mouse has moved this frame
+    ///
+    /// Sums every [`MouseMotion`] event that is currently stored,
+    /// which covers all of the events since the previous frame when read after event buffers are updated.
+    /// If a [`MouseMotionConfig`] is provided, its scaled and smoothed motion is returned instead.
+    /// Returns [`Vec2::ZERO`] if there is no [`MouseMotion`] event stream.
+    #[must_use]
+    pub fn mouse_motion(&self) -> Vec2 {
+        if let Some(mouse_motion_config) = self.mouse_motion_config {
+            mouse_motion_config.motion()
+        } else if let Some(mouse_motion) = self.mouse_motion {
+            mouse_motion
+                .get_reader()
+                .iter(mouse_motion)
+                .map(|event| &event.delta)
+                .sum()
+        } else {
+            Vec2::ZERO
+        }
+    }
+
+    /// The total distance that the mouse wheel has scrolled this frame, measured in lines
+    ///
+    /// Like [`InputStreams::mouse_motion`], this sums every [`MouseWheel`] event that is currently stored.
+    /// Pixel-based scrolling is converted using [`MOUSE_WHEEL_PIXELS_PER_LINE`].
+    /// Returns [`Vec2::ZERO`] if there is no [`MouseWheel`] event stream.
+    #[must_use]
+    pub fn mouse_wheel(&self) -> Vec2 {
+        if let Some(mouse_wheel) = self.mouse_wheel {
+            mouse_wheel
+                .get_reader()
+                .iter(mouse_wheel)
+                .map(|event| {
+                    let scroll = Vec2::new(event.x, event.y);
+                    match event.unit {
+                        MouseScrollUnit::Line => scroll,
+                        MouseScrollUnit::Pixel => scroll / MOUSE_WHEEL_PIXELS_PER_LINE,
+                    }
+                })
+                .fold(Vec2::ZERO, |total, scroll| total + scroll)
+        } else {
+            Vec2::ZERO
+        }
+    }
+
+    /// The distance that the mouse wheel has scrolled in `direction` this frame, measured in lines
+    ///
+    /// Scrolling in the opposite direction is not counted, and so this is never negative.
+    #[must_use]
+    pub fn mouse_wheel_distance(&self, direction: MouseWheelDirection) -> f32 {
+        direction.distance(self.mouse_wheel()).max(0.0)
+    }
+
+    /// Is any [`ModifierKey`] held that is not part of the `input`?
+    ///
+    /// A modifier is part of the `input` if it contains the [`InputButton::Modifier`], or either of its keys.
+    #[must_use]
+    pub fn other_modifier_pressed(&self, input: &UserInput) -> bool {
+        let buttons = input.buttons();
+
+        [
+            ModifierKey::Alt,
+            ModifierKey::Control,
+            ModifierKey::Shift,
+            ModifierKey::Super,
+        ]
+        .into_iter()
+        .any(|modifier| {
+            let part_of_input = buttons.iter().any(|button| match *button {
+                InputButton::Modifier(other) => other == modifier,
+                InputButton::Keyboard(keycode) => modifier.keycodes().contains(&keycode),
+                _ => false,
+            });
+
+            !part_of_input && self.button_pressed(InputButton::Modifier(modifier))
+        })
+    }
+
+    /// Is the `input` a [`UserInput::Chord`] with some, but not all, of its buttons pressed?
+    #[must_use]
+    pub fn input_partially_pressed(&self, input: &UserInput) -> bool {
+        match input {
+            UserInput::Single(_)
+            | UserInput::SingleAxis(_)
+            | UserInput::HalfAxis { .. }
+            | UserInput::MouseMotion { .. }
+            | UserInput::MouseWheel { .. }
+            | UserInput::DualAxis { .. } => false,
+            UserInput::Chord(buttons) => {
+                let n_pressed = buttons
+                    .iter()
+                    .filter(|&&button| self.button_pressed(button))
+                    .count();
+
+                n_pressed > 0 && n_pressed < buttons.len()
+            }
+        }
+    }
+
+    /// The [`Instant`] at which the `button` was pressed, according to the [`ButtonPressTimes`]
+    ///
+    /// Returns `None` if the button is not held, if there are no [`ButtonPressTimes`],
+    /// or if the button is a [`InputButton::Touchpad`], whose presses are not timed.
+    /// An [`InputButton::Modifier`] was pressed when the first of its held keys was.
+    #[must_use]
+    pub fn button_press_time(&self, button: InputButton) -> Option<Instant> {
+        let press_times = self.press_times?;
+
+        match button {
+            InputButton::Gamepad(gamepad_button) => {
+                let physical_button = self.controller_layout.remap(gamepad_button);
+                press_times.gamepad(GamepadButton(self.associated_gamepad?, physical_button))
+            }
+            InputButton::Keyboard(keycode) => press_times.keyboard(keycode),
+            InputButton::Mouse(mouse_button) => press_times.mouse(mouse_button),
+            InputButton::Touchpad(_) => None,
+            InputButton::Modifier(modifier) => modifier
+                .keycodes()
+                .into_iter()
+                .filter_map(|keycode| press_times.keyboard(keycode))
+                .min(),
+        }
+    }
+
+    /// Were all of the `buttons` pressed within `within` of each other?
+    ///
+    /// Buttons whose press time is unknown never satisfy the window.
+    /// Always returns `true` if there are no [`ButtonPressTimes`].
+    #[must_use]
+    pub fn pressed_within(&self, buttons: &PetitSet<InputButton, 8>, within: Duration) -> bool {
+        if self.press_times.is_none() {
+            return true;
+        }
+
+        let mut earliest: Option<Instant> = None;
+        let mut latest: Option<Instant> = None;
+
+        for &button in buttons.iter() {
+            let press_time = match self.button_press_time(button) {
+                Some(press_time) => press_time,
+                None => return false,
+            };
+
+            earliest = Some(earliest.map_or(press_time, |earliest| earliest.min(press_time)));
+            latest = Some(latest.map_or(press_time, |latest| latest.max(press_time)));
+        }
+
+        match (earliest, latest) {
+            (Some(earliest), Some(latest)) => latest - earliest <= within,
+            _ => true,
+        }
+    }
+
+    /// Were all of the `buttons` that are not [`ModifierKey`]s pressed after (or at the same time as) every modifier in `buttons`?
+    ///
+    /// Buttons whose press time is unknown never satisfy the order.
+    /// Always returns `true` if there are no [`ButtonPressTimes`].
+    #[must_use]
+    pub fn modifiers_pressed_first(&self, buttons: &PetitSet<InputButton, 8>) -> bool {
+        if self.press_times.is_none() {
+            return true;
+        }
+
+        let mut latest_modifier: Option<Instant> = None;
+        let mut earliest_trigger: Option<Instant> = None;
+
+        for &button in buttons.iter() {
+            let press_time = match self.button_press_time(button) {
+                Some(press_time) => press_time,
+                None => return false,
+            };
+
+            if button.is_modifier() {
+                latest_modifier = Some(match latest_modifier {
+                    Some(latest) => latest.max(press_time),
+                    None => press_time,
+                });
+            } else {
+                earliest_trigger = Some(match earliest_trigger {
+                    Some(earliest) => earliest.min(press_time),
+                    None => press_time,
+                });
+            }
+        }
+
+        match (latest_modifier, earliest_trigger) {
+            (Some(latest_modifier), Some(earliest_trigger)) => earliest_trigger >= latest_modifier,
+            _ => true,
         }
     }
 
@@ -370,7 +1364,8 @@ impl<'a> InputStreams<'a> {
                 // If no gamepad is registered, we know for sure that no match was found
                 if let Some(gamepad) = self.associated_gamepad {
                     if let Some(gamepad_stream) = self.gamepad {
-                        gamepad_stream.pressed(GamepadButton(gamepad, gamepad_button))
+                        let physical_button = self.controller_layout.remap(gamepad_button);
+                        gamepad_stream.pressed(GamepadButton(gamepad, physical_button))
                     } else {
                         false
                     }
@@ -392,7 +1387,85 @@ impl<'a> InputStreams<'a> {
                     false
                 }
             }
+            InputButton::Touchpad(touchpad_button) => {
+                if let (Some(gamepad), Some(touchpad_stream)) =
+                    (self.associated_gamepad, self.touchpad)
+                {
+                    touchpad_stream.pressed(GamepadTouchpadButton(gamepad, touchpad_button))
+                } else {
+                    false
+                }
+            }
+            InputButton::Modifier(modifier) => {
+                if let Some(keyboard_stream) = self.keyboard {
+                    keyboard_stream.any_pressed(modifier.keycodes())
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Returns every [`UserInput::Single`] that is currently pressed, across all of the input streams
+    ///
+    /// This is independent of any [`InputMap`](crate::input_map::InputMap), and is useful for debugging and input-display overlays.
+    /// Only gamepad buttons belonging to the `associated_gamepad` are included,
+    /// and gamepad buttons are reported after undoing the [`ControllerLayout`] remapping.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy_input::{keyboard::KeyCode, Input};
+    /// use leafwing_input_manager::user_input::{InputStreams, UserInput};
+    ///
+    /// let mut keyboard = Input::<KeyCode>::default();
+    /// keyboard.press(KeyCode::Space);
+    ///
+    /// let input_streams = InputStreams::from_keyboard(&keyboard);
+    /// assert_eq!(input_streams.all_pressed(), vec![UserInput::from(KeyCode::Space)]);
+    /// ```
+    #[must_use]
+    pub fn all_pressed(&self) -> Vec<UserInput> {
+        let mut pressed = Vec::new();
+
+        if let (Some(gamepad_stream), Some(associated_gamepad)) =
+            (self.gamepad, self.associated_gamepad)
+        {
+            for &GamepadButton(gamepad, button_type) in gamepad_stream.get_pressed() {
+                if gamepad == associated_gamepad {
+                    // Remapping is its own inverse
+                    let button_type = self.controller_layout.remap(button_type);
+                    pressed.push(UserInput::from(button_type));
+                }
+            }
         }
+
+        if let (Some(touchpad_stream), Some(associated_gamepad)) =
+            (self.touchpad, self.associated_gamepad)
+        {
+            for &GamepadTouchpadButton(gamepad, button) in touchpad_stream.get_pressed() {
+                if gamepad == associated_gamepad {
+                    pressed.push(UserInput::from(button));
+                }
+            }
+        }
+
+        if let Some(keyboard_stream) = self.keyboard {
+            pressed.extend(
+                keyboard_stream
+                    .get_pressed()
+                    .map(|&key| UserInput::from(key)),
+            );
+        }
+
+        if let Some(mouse_stream) = self.mouse {
+            pressed.extend(
+                mouse_stream
+                    .get_pressed()
+                    .map(|&button| UserInput::from(button)),
+            );
+        }
+
+        pressed
     }
 
     /// Are all of the `buttons` pressed?
@@ -422,6 +1495,8 @@ pub struct MutableInputStreams<'a> {
     pub keyboard: Option<&'a mut Input<KeyCode>>,
     /// An optional [`MouseButton`] [`Input`] stream
     pub mouse: Option<&'a mut Input<MouseButton>>,
+    /// An optional [`GamepadTouchpadButton`] [`Input`] stream
+    pub touchpad: Option<&'a mut Input<GamepadTouchpadButton>>,
     /// The [`Gamepad`] that this struct will detect inputs from
     pub associated_gamepad: Option<Gamepad>,
 }
@@ -434,11 +1509,21 @@ impl<'a> From<MutableInputStreams<'a>> for InputStreams<'a> {
 
         let mouse = mutable_streams.mouse.map(|mutable_ref| &*mutable_ref);
 
+        let touchpad = mutable_streams.touchpad.map(|mutable_ref| &*mutable_ref);
+
         InputStreams {
             gamepad,
             keyboard,
             mouse,
+            touchpad,
+            gamepad_axes: None,
+            mouse_motion: None,
+            mouse_wheel: None,
             associated_gamepad: mutable_streams.associated_gamepad,
+            controller_layout: ControllerLayout::Standard,
+            mouse_motion_threshold: 0.0,
+            press_times: None,
+            mouse_motion_config: None,
         }
     }
 }
@@ -30,6 +30,14 @@ enum NamedFieldVariantsAction {
     Jump,
 }
 
+#[derive(Actionlike, Hash, PartialEq, Eq, Clone, Copy, Debug)]
+enum CountedVariantsAction {
+    Run,
+    #[actionlike(count = 3)]
+    SelectSlot(u8),
+    Jump,
+}
+
 #[test]
 fn in_order_iteration() {
     let constructed_vec = vec![SimpleAction::Zero, SimpleAction::One, SimpleAction::Two];
@@ -55,3 +63,26 @@ fn index() {
     assert_eq!(SimpleAction::One.index(), 1);
     assert_eq!(SimpleAction::Two.index(), 2);
 }
+
+#[test]
+fn counted_variants() {
+    use CountedVariantsAction::*;
+
+    assert_eq!(CountedVariantsAction::N_VARIANTS, 5);
+    assert_eq!(
+        CountedVariantsAction::variants().collect::<Vec<_>>(),
+        vec![Run, SelectSlot(0), SelectSlot(1), SelectSlot(2), Jump]
+    );
+
+    for (index, action) in CountedVariantsAction::variants().enumerate() {
+        assert_eq!(action.index(), index);
+        assert_eq!(CountedVariantsAction::get_at(index), Some(action));
+    }
+    assert_eq!(CountedVariantsAction::get_at(5), None);
+}
+
+#[test]
+#[should_panic]
+fn counted_variant_out_of_bounds() {
+    CountedVariantsAction::SelectSlot(3).index();
+}
@@ -156,3 +156,47 @@ fn input_clash_handling() {
     app.assert_input_map_actions_eq(ClashStrategy::PrioritizeLongest, [TwoAndThree]);
     app.assert_input_map_actions_eq(ClashStrategy::UseActionOrder, [Two]);
 }
+
+#[test]
+fn refresh_clash_strategy() {
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::systems::refresh_action_state;
+    use leafwing_input_manager::MockInput;
+    use Action::*;
+    use KeyCode::*;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .insert_resource(ClashStrategy::PrioritizeLongest)
+        .add_startup_system(spawn_input_map);
+    app.update();
+
+    let entity = app
+        .world
+        .query_filtered::<Entity, With<InputMap<Action>>>()
+        .iter(&app.world)
+        .next()
+        .unwrap();
+    app.world
+        .entity_mut(entity)
+        .insert(ActionState::<Action>::default());
+
+    app.send_input(Key1);
+    app.send_input(Key2);
+    app.update();
+
+    let action_state = app.world.get::<ActionState<Action>>(entity).unwrap();
+    assert_eq!(action_state.get_pressed(), vec![OneAndTwo]);
+
+    // The new strategy takes effect without waiting for the next update
+    app.insert_resource(ClashStrategy::PressAll);
+    refresh_action_state::<Action>(&mut app.world);
+
+    let action_state = app.world.get::<ActionState<Action>>(entity).unwrap();
+    assert_eq!(action_state.get_pressed(), vec![One, Two, OneAndTwo]);
+    // Re-resolving does not disturb actions that were already pressed
+    assert!(action_state.just_pressed(OneAndTwo));
+}
@@ -127,6 +127,2000 @@ fn disable_input() {
     assert_eq!(*respect, Respect(false));
 }
 
+#[test]
+fn reenable_policy() {
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::plugin::ReenablePolicy;
+
+    for (reenable_policy, pressed_after_reenable, just_pressed_after_reenable) in [
+        (ReenablePolicy::PressHeld, true, true),
+        (ReenablePolicy::WaitForRelease, false, false),
+        (ReenablePolicy::ContinueHeld, true, false),
+    ] {
+        let mut app = App::new();
+
+        app.add_plugins(MinimalPlugins)
+            .add_plugin(InputPlugin)
+            .add_plugin(InputManagerPlugin::<Action>::default())
+            .init_resource::<ActionState<Action>>()
+            .insert_resource(InputMap::<Action>::new([(Action::PayRespects, KeyCode::F)]));
+        app.world
+            .resource_mut::<ToggleActions<Action>>()
+            .reenable_policy = reenable_policy;
+
+        // F is held down throughout
+        app.send_input(KeyCode::F);
+        app.update();
+        assert!(app
+            .world
+            .resource::<ActionState<Action>>()
+            .pressed(Action::PayRespects));
+
+        app.world.resource_mut::<ToggleActions<Action>>().enabled = false;
+        app.update();
+        assert!(app
+            .world
+            .resource::<ActionState<Action>>()
+            .released(Action::PayRespects));
+
+        app.world.resource_mut::<ToggleActions<Action>>().enabled = true;
+        app.update();
+        let action_state = app.world.resource::<ActionState<Action>>();
+        assert_eq!(
+            action_state.pressed(Action::PayRespects),
+            pressed_after_reenable,
+            "{reenable_policy:?}"
+        );
+        assert_eq!(
+            action_state.just_pressed(Action::PayRespects),
+            just_pressed_after_reenable,
+            "{reenable_policy:?}"
+        );
+
+        // Once released, every policy allows the action to be pressed again
+        app.release_input(KeyCode::F);
+        app.update();
+        app.send_input(KeyCode::F);
+        app.update();
+        assert!(app
+            .world
+            .resource::<ActionState<Action>>()
+            .just_pressed(Action::PayRespects));
+    }
+}
+
+#[test]
+fn continue_held_keeps_timing() {
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::plugin::ReenablePolicy;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(Action::PayRespects, KeyCode::F)]));
+    app.world
+        .resource_mut::<ToggleActions<Action>>()
+        .reenable_policy = ReenablePolicy::ContinueHeld;
+
+    // F is held down throughout
+    app.send_input(KeyCode::F);
+    app.update();
+    app.update();
+    let instant_started = app
+        .world
+        .resource::<ActionState<Action>>()
+        .instant_started(Action::PayRespects);
+    assert!(instant_started.is_some());
+
+    app.world.resource_mut::<ToggleActions<Action>>().enabled = false;
+    app.update();
+    app.world.resource_mut::<ToggleActions<Action>>().enabled = true;
+    app.update();
+    app.update();
+
+    // The action continues as if it had never been released
+    let action_state = app.world.resource::<ActionState<Action>>();
+    assert!(action_state.pressed(Action::PayRespects));
+    assert_eq!(
+        action_state.instant_started(Action::PayRespects),
+        instant_started
+    );
+    assert_eq!(action_state.press_count(Action::PayRespects), 1);
+    assert_eq!(action_state.release_count(Action::PayRespects), 0);
+}
+
+#[test]
+fn toggle_individual_actions() {
+    use bevy_input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum CutsceneAction {
+        Walk,
+        Attack,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<CutsceneAction>::default())
+        .init_resource::<ActionState<CutsceneAction>>()
+        .insert_resource(InputMap::<CutsceneAction>::new([
+            (CutsceneAction::Walk, KeyCode::W),
+            (CutsceneAction::Attack, KeyCode::Space),
+        ]));
+
+    // Both keys are held down throughout
+    app.send_input(KeyCode::W);
+    app.send_input(KeyCode::Space);
+    app.update();
+
+    app.world
+        .resource_mut::<ToggleActions<CutsceneAction>>()
+        .disable(CutsceneAction::Attack);
+    for _ in 0..2 {
+        app.update();
+        let action_state = app.world.resource::<ActionState<CutsceneAction>>();
+        assert!(action_state.pressed(CutsceneAction::Walk));
+        assert!(!action_state.just_pressed(CutsceneAction::Walk));
+        assert!(action_state.released(CutsceneAction::Attack));
+    }
+
+    app.world
+        .resource_mut::<ToggleActions<CutsceneAction>>()
+        .enable(CutsceneAction::Attack);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<CutsceneAction>>()
+        .just_pressed(CutsceneAction::Attack));
+}
+
+#[test]
+fn scripted_input() {
+    use bevy_input::InputPlugin;
+    use bevy_utils::Duration;
+    use leafwing_input_manager::plugin::InputManagerSystem;
+    use leafwing_input_manager::scripted_input::ScriptedInput;
+    use leafwing_input_manager::systems::apply_scripted_input;
+
+    let mut scripted_input = ScriptedInput::<Action>::default();
+    scripted_input
+        .release_at(Duration::from_millis(900), Action::PayRespects)
+        .press_at(Duration::from_millis(500), Action::PayRespects);
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_startup_system(spawn_player)
+        .insert_resource(scripted_input.with_fixed_timestep(Duration::from_millis(100)))
+        .add_system_to_stage(
+            CoreStage::PreUpdate,
+            apply_scripted_input::<Action>
+                .label(InputManagerSystem::ManualControl)
+                .after(InputManagerSystem::Update),
+        );
+
+    let mut pressed_frames = Vec::new();
+    let mut just_pressed_frames = Vec::new();
+    let mut durations = Vec::new();
+    for frame in 0..12 {
+        app.update();
+
+        let action_state = app
+            .world
+            .query_filtered::<&ActionState<Action>, With<Player>>()
+            .iter(&app.world)
+            .next()
+            .unwrap();
+        if action_state.pressed(Action::PayRespects) {
+            pressed_frames.push(frame);
+            durations.push(action_state.current_duration(Action::PayRespects));
+        }
+        if action_state.just_pressed(Action::PayRespects) {
+            just_pressed_frames.push(frame);
+        }
+    }
+
+    // Held from t = 0.5s until t = 0.9s, despite F never being pressed
+    assert_eq!(pressed_frames, vec![5, 6, 7, 8]);
+    // Holding the action does not press it again each frame
+    assert_eq!(just_pressed_frames, vec![5]);
+    assert!(durations[2] > Duration::ZERO);
+    assert!(durations[3] > durations[2]);
+    assert!(app.world.resource::<ScriptedInput<Action>>().is_finished());
+}
+
+#[test]
+fn input_macros() {
+    use bevy_input::InputPlugin;
+    use bevy_utils::Duration;
+    use leafwing_input_manager::scripted_input::{InputMacro, InputMacros};
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum ComboAction {
+        Combo,
+        Kick,
+        Punch,
+    }
+
+    let mut input_macros = InputMacros::<ComboAction>::default();
+    input_macros.add(
+        InputMacro::new(ComboAction::Combo)
+            .step(
+                ComboAction::Kick,
+                Duration::ZERO,
+                Duration::from_millis(200),
+            )
+            .step(
+                ComboAction::Punch,
+                Duration::from_millis(200),
+                Duration::ZERO,
+            ),
+    );
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_system_to_stage(CoreStage::Last, reset_inputs.exclusive_system())
+        .add_plugin(InputManagerPlugin::<ComboAction>::default())
+        .init_resource::<ActionState<ComboAction>>()
+        .insert_resource(InputMap::<ComboAction>::new([
+            (ComboAction::Combo, KeyCode::C),
+            (ComboAction::Punch, KeyCode::P),
+        ]))
+        .insert_resource(input_macros.with_fixed_timestep(Duration::from_millis(100)));
+
+    let mut pressed = Vec::new();
+    let mut kick_just_pressed = Vec::new();
+    for frame in 0..5 {
+        match frame {
+            0 => app.send_input(KeyCode::C),
+            // Real inputs are combined with the macro
+            1 => app.send_input(KeyCode::P),
+            _ => (),
+        }
+        app.update();
+
+        let action_state = app.world.resource::<ActionState<ComboAction>>();
+        pressed.push((
+            action_state.pressed(ComboAction::Kick),
+            action_state.pressed(ComboAction::Punch),
+        ));
+        kick_just_pressed.push(action_state.just_pressed(ComboAction::Kick));
+    }
+
+    assert_eq!(
+        pressed,
+        vec![
+            (true, false),
+            (true, true),
+            // Zero-length steps are still pressed for a frame
+            (false, true),
+            (false, false),
+            (false, false)
+        ]
+    );
+    // Steps that last several frames are only just pressed once
+    assert_eq!(kick_just_pressed, vec![true, false, false, false, false]);
+    assert!(!app
+        .world
+        .resource::<InputMacros<ComboAction>>()
+        .is_playing(None));
+}
+
+#[test]
+fn cancelled_input_macros_release_held_actions() {
+    use bevy_input::InputPlugin;
+    use bevy_utils::Duration;
+    use leafwing_input_manager::scripted_input::{InputMacro, InputMacros};
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum ComboAction {
+        Combo,
+        Charge,
+        Block,
+        Guard,
+    }
+
+    let mut input_macros = InputMacros::<ComboAction>::default();
+    input_macros
+        .add(InputMacro::new(ComboAction::Combo).step(
+            ComboAction::Guard,
+            Duration::ZERO,
+            Duration::from_secs(1),
+        ))
+        .add(
+            InputMacro::new(ComboAction::Charge)
+                .step(ComboAction::Block, Duration::ZERO, Duration::from_secs(1))
+                .cancel_on_release(),
+        );
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_system_to_stage(CoreStage::Last, reset_inputs.exclusive_system())
+        .add_plugin(InputManagerPlugin::<ComboAction>::default())
+        .init_resource::<ActionState<ComboAction>>()
+        .insert_resource(InputMap::<ComboAction>::new([
+            (ComboAction::Combo, KeyCode::C),
+            (ComboAction::Charge, KeyCode::H),
+        ]))
+        .insert_resource(input_macros.with_fixed_timestep(Duration::from_millis(100)));
+
+    app.send_input(KeyCode::C);
+    app.send_input(KeyCode::H);
+    app.update();
+    let action_state = app.world.resource::<ActionState<ComboAction>>();
+    assert!(action_state.pressed(ComboAction::Guard));
+    assert!(action_state.pressed(ComboAction::Block));
+
+    // Releasing the trigger cancels the second macro, while the first keeps playing
+    app.send_input(KeyCode::C);
+    app.update();
+    let action_state = app.world.resource::<ActionState<ComboAction>>();
+    assert!(action_state.pressed(ComboAction::Guard));
+    assert!(action_state.just_released(ComboAction::Block));
+
+    // Cancelling the first macro in the middle of its hold releases its action
+    app.world
+        .resource_mut::<InputMacros<ComboAction>>()
+        .cancel(None);
+    app.update();
+    app.update();
+    let action_state = app.world.resource::<ActionState<ComboAction>>();
+    assert!(action_state.released(ComboAction::Guard));
+    assert!(action_state.released(ComboAction::Block));
+    assert!(!app
+        .world
+        .resource::<InputMacros<ComboAction>>()
+        .is_playing(None));
+}
+
+#[test]
+fn input_capture() {
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::scripted_input::{CapturedInput, InputCapture};
+
+    fn pressed_state(app: &mut App) -> (bool, bool, bool) {
+        let resource = app.world.resource::<ActionState<Action>>().clone();
+        let component = app
+            .world
+            .query_filtered::<&ActionState<Action>, With<Player>>()
+            .iter(&app.world)
+            .next()
+            .unwrap()
+            .clone();
+        (
+            resource.pressed(Action::PayRespects),
+            resource.just_pressed(Action::PayRespects),
+            component.pressed(Action::PayRespects),
+        )
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_startup_system(spawn_player)
+        .add_system_to_stage(CoreStage::Last, reset_inputs.exclusive_system())
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(Action::PayRespects, KeyCode::F)]));
+
+    let script = [true, false, true, true, false];
+
+    app.start_recording::<Action>();
+    let mut recorded = Vec::new();
+    for pressed in script {
+        if pressed {
+            app.send_input(KeyCode::F);
+        }
+        app.update();
+        recorded.push(pressed_state(&mut app));
+    }
+    app.stop_recording::<Action>();
+    assert_eq!(
+        app.world.resource::<CapturedInput<Action>>().frames().len(),
+        script.len()
+    );
+
+    // No real inputs are sent during playback
+    app.start_playback::<Action>();
+    let mut played_back = Vec::new();
+    for _ in script {
+        app.update();
+        played_back.push(pressed_state(&mut app));
+    }
+
+    assert_eq!(recorded, played_back);
+    assert!(!app.world.resource::<CapturedInput<Action>>().is_playing());
+}
+
+#[test]
+fn toggle_actions_on_press() {
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::plugin::{InputManagerSystem, ToggleActionsOnPress};
+    use leafwing_input_manager::systems::toggle_actions_on_press;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum MenuAction {
+        Pause,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_system_to_stage(CoreStage::Last, reset_inputs.exclusive_system())
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_plugin(InputManagerPlugin::<MenuAction>::default())
+        .add_startup_system(spawn_player)
+        .init_resource::<ActionState<MenuAction>>()
+        .insert_resource(InputMap::<MenuAction>::new([(
+            MenuAction::Pause,
+            KeyCode::Escape,
+        )]))
+        .insert_resource(ToggleActionsOnPress::<MenuAction, Action>::new(
+            MenuAction::Pause,
+        ))
+        .add_system_to_stage(
+            CoreStage::PreUpdate,
+            toggle_actions_on_press::<MenuAction, Action>
+                .after(InputManagerSystem::Update)
+                .before(InputManagerSystem::ReleaseOnDisable),
+        )
+        .init_resource::<Respect>()
+        .add_system(pay_respects)
+        .add_system_to_stage(CoreStage::PreUpdate, respect_fades);
+
+    app.update();
+    assert!(app.world.resource::<ToggleActions<Action>>().enabled);
+
+    // Pausing disables gameplay input
+    app.send_input(KeyCode::Escape);
+    app.update();
+    assert!(!app.world.resource::<ToggleActions<Action>>().enabled);
+    assert!(app.world.resource::<ToggleActions<MenuAction>>().enabled);
+
+    app.send_input(KeyCode::F);
+    app.update();
+    assert_eq!(*app.world.resource::<Respect>(), Respect(false));
+
+    // Unpausing re-enables it
+    app.send_input(KeyCode::Escape);
+    app.update();
+    assert!(app.world.resource::<ToggleActions<Action>>().enabled);
+
+    app.send_input(KeyCode::F);
+    app.update();
+    assert_eq!(*app.world.resource::<Respect>(), Respect(true));
+}
+
+#[test]
+fn text_input_capture() {
+    use bevy::window::{ReceivedCharacter, WindowId};
+    use bevy_ecs::event::Events;
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::systems::{capture_text_input, toggle_text_input_on_press};
+    use leafwing_input_manager::text_input::{TextInput, ToggleTextInputOnPress};
+
+    fn type_text(app: &mut App, text: &str) {
+        let mut events = app.world.resource_mut::<Events<ReceivedCharacter>>();
+        for char in text.chars() {
+            events.send(ReceivedCharacter {
+                id: WindowId::primary(),
+                char,
+            });
+        }
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_event::<ReceivedCharacter>()
+        .init_resource::<TextInput>()
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(Action::PayRespects, KeyCode::F)]))
+        .insert_resource(ToggleTextInputOnPress::new(Action::PayRespects))
+        .add_system(toggle_text_input_on_press::<Action>.before("capture"))
+        .add_system(capture_text_input.label("capture"));
+
+    // Text is ignored until capture begins
+    type_text(&mut app, "ignored");
+    app.update();
+    assert_eq!(app.world.resource::<TextInput>().buffer(), "");
+
+    // The character typed by the key that starts capture is not captured
+    app.send_input(KeyCode::F);
+    type_text(&mut app, "f");
+    app.update();
+    app.release_input(KeyCode::F);
+    assert!(app.world.resource::<TextInput>().is_capturing());
+    assert_eq!(app.world.resource::<TextInput>().buffer(), "");
+
+    // Backspace removes characters
+    type_text(&mut app, "gg wp!\u{8}");
+    app.update();
+    assert_eq!(app.world.resource::<TextInput>().buffer(), "gg wp");
+
+    // Pressing the action again stops capture
+    app.send_input(KeyCode::F);
+    app.update();
+    let mut text_input = app.world.resource_mut::<TextInput>();
+    assert!(!text_input.is_capturing());
+    assert_eq!(text_input.take(), "gg wp");
+}
+
+#[test]
+fn shared_input_map() {
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::input_map::SharedInputMap;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default());
+
+    let targets: Vec<Entity> = (0..2)
+        .map(|_| {
+            app.world
+                .spawn()
+                .insert(ActionState::<Action>::default())
+                .id()
+        })
+        .collect();
+
+    app.world
+        .spawn()
+        .insert(InputMap::<Action>::new([(Action::PayRespects, KeyCode::F)]))
+        .insert(SharedInputMap::<Action>::new(targets.clone()));
+
+    // Press F to pay respects, twice
+    app.send_input(KeyCode::F);
+    app.update();
+
+    for &target in targets.iter() {
+        let action_state = app.world.get::<ActionState<Action>>(target).unwrap();
+        assert!(action_state.pressed(Action::PayRespects));
+    }
+
+    // Disabling input releases every target
+    let mut toggle_actions = app.world.resource_mut::<ToggleActions<Action>>();
+    toggle_actions.enabled = false;
+    app.update();
+
+    for &target in targets.iter() {
+        let action_state = app.world.get::<ActionState<Action>>(target).unwrap();
+        assert!(action_state.released(Action::PayRespects));
+    }
+}
+
+#[test]
+fn touchpad_swipes() {
+    use bevy_input::{gamepad::Gamepad, InputPlugin};
+    use leafwing_input_manager::plugin::InputManagerSystem;
+    use leafwing_input_manager::user_input::{GamepadTouchpadButton, TouchpadButton};
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<Input<GamepadTouchpadButton>>();
+
+    let mut input_map = InputMap::<Action>::new([(Action::PayRespects, TouchpadButton::SwipeUp)]);
+    input_map.set_gamepad(Gamepad(0));
+    app.insert_resource(input_map);
+    app.init_resource::<ActionState<Action>>();
+
+    // Swipes are pressed for a single update
+    app.world
+        .resource_mut::<Input<GamepadTouchpadButton>>()
+        .press(GamepadTouchpadButton(Gamepad(0), TouchpadButton::SwipeUp));
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_pressed(Action::PayRespects));
+
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_released(Action::PayRespects));
+
+    // Buttons pressed during the frame stay just pressed for the rest of it
+    fn press_touchpad(mut touchpad: ResMut<Input<GamepadTouchpadButton>>) {
+        touchpad.press(GamepadTouchpadButton(Gamepad(0), TouchpadButton::Press));
+    }
+    app.add_system_to_stage(
+        CoreStage::PreUpdate,
+        press_touchpad.before(InputManagerSystem::Update),
+    );
+    app.update();
+    let touchpad = app.world.resource::<Input<GamepadTouchpadButton>>();
+    assert!(touchpad.just_pressed(GamepadTouchpadButton(Gamepad(0), TouchpadButton::Press)));
+
+    // Swipes on other gamepads are ignored
+    app.world
+        .resource_mut::<Input<GamepadTouchpadButton>>()
+        .press(GamepadTouchpadButton(Gamepad(1), TouchpadButton::SwipeUp));
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::PayRespects));
+}
+
+#[test]
+fn touchpad_swipes_with_several_action_types() {
+    use bevy_input::{gamepad::Gamepad, InputPlugin};
+    use leafwing_input_manager::user_input::{GamepadTouchpadButton, TouchpadButton};
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum MenuAction {
+        Scroll,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_plugin(InputManagerPlugin::<MenuAction>::default())
+        .init_resource::<Input<GamepadTouchpadButton>>()
+        .init_resource::<ActionState<Action>>()
+        .init_resource::<ActionState<MenuAction>>();
+
+    let mut input_map = InputMap::<Action>::new([(Action::PayRespects, TouchpadButton::SwipeUp)]);
+    input_map.set_gamepad(Gamepad(0));
+    app.insert_resource(input_map);
+    let mut menu_input_map =
+        InputMap::<MenuAction>::new([(MenuAction::Scroll, TouchpadButton::SwipeUp)]);
+    menu_input_map.set_gamepad(Gamepad(0));
+    app.insert_resource(menu_input_map);
+
+    // Every action type sees the swipe before it is released
+    app.world
+        .resource_mut::<Input<GamepadTouchpadButton>>()
+        .press(GamepadTouchpadButton(Gamepad(0), TouchpadButton::SwipeUp));
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_pressed(Action::PayRespects));
+    assert!(app
+        .world
+        .resource::<ActionState<MenuAction>>()
+        .just_pressed(MenuAction::Scroll));
+
+    // The swipe then ends for every action type
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_released(Action::PayRespects));
+    assert!(app
+        .world
+        .resource::<ActionState<MenuAction>>()
+        .just_released(MenuAction::Scroll));
+}
+
+#[test]
+fn per_gamepad_inputs() {
+    use bevy_input::{gamepad::Gamepad, InputPlugin};
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_system_to_stage(CoreStage::Last, reset_inputs.exclusive_system());
+
+    let players: Vec<Entity> = [Gamepad(0), Gamepad(1)]
+        .into_iter()
+        .map(|gamepad| {
+            let mut input_map =
+                InputMap::<Action>::new([(Action::PayRespects, GamepadButtonType::South)]);
+            input_map.set_gamepad(gamepad);
+
+            app.world
+                .spawn()
+                .insert_bundle(InputManagerBundle::<Action> {
+                    input_map,
+                    ..Default::default()
+                })
+                .id()
+        })
+        .collect();
+
+    let pressed = |app: &App| -> Vec<bool> {
+        players
+            .iter()
+            .map(|&player| {
+                app.world
+                    .get::<ActionState<Action>>(player)
+                    .unwrap()
+                    .pressed(Action::PayRespects)
+            })
+            .collect()
+    };
+
+    app.send_input_to_gamepad(GamepadButtonType::South, Some(Gamepad(1)));
+    app.update();
+    assert_eq!(pressed(&app), vec![false, true]);
+
+    app.send_input_to_gamepad(GamepadButtonType::South, Some(Gamepad(0)));
+    app.update();
+    assert_eq!(pressed(&app), vec![true, false]);
+}
+
+#[test]
+fn gamepad_assignment() {
+    use bevy_ecs::event::Events;
+    use bevy_input::gamepad::{Gamepad, GamepadEventRaw, GamepadEventType};
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::input_map::GamepadAssignment;
+
+    fn send_gamepad_event(app: &mut App, gamepad: Gamepad, event_type: GamepadEventType) {
+        app.world
+            .resource_mut::<Events<GamepadEventRaw>>()
+            .send(GamepadEventRaw(gamepad, event_type));
+        // The `Gamepads` resource is not ordered relative to `assign_gamepads`
+        app.update();
+        app.update();
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default());
+
+    let input_map = InputMap::<Action>::new([(Action::PayRespects, GamepadButtonType::South)]);
+    let player_two = app
+        .world
+        .spawn()
+        .insert(GamepadAssignment { slot: 1 })
+        .insert_bundle(InputManagerBundle::<Action> {
+            input_map: input_map.clone(),
+            ..Default::default()
+        })
+        .id();
+    let player_one = app
+        .world
+        .spawn()
+        .insert(GamepadAssignment { slot: 0 })
+        .insert_bundle(InputManagerBundle::<Action> {
+            input_map,
+            ..Default::default()
+        })
+        .id();
+
+    send_gamepad_event(&mut app, Gamepad(5), GamepadEventType::Connected);
+    send_gamepad_event(&mut app, Gamepad(3), GamepadEventType::Connected);
+
+    let gamepad = |app: &App, player| app.world.get::<InputMap<Action>>(player).unwrap().gamepad();
+    // The first gamepad to connect goes to the only free slot, and is kept when another connects
+    assert_eq!(gamepad(&app, player_one), Some(Gamepad(5)));
+    assert_eq!(gamepad(&app, player_two), Some(Gamepad(3)));
+
+    // Disconnecting releases held actions and frees the slot
+    app.send_input_to_gamepad(GamepadButtonType::South, Some(Gamepad(5)));
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player_one)
+        .unwrap()
+        .pressed(Action::PayRespects));
+
+    send_gamepad_event(&mut app, Gamepad(5), GamepadEventType::Disconnected);
+    assert_eq!(gamepad(&app, player_one), None);
+    assert_eq!(gamepad(&app, player_two), Some(Gamepad(3)));
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player_one)
+        .unwrap()
+        .released(Action::PayRespects));
+}
+
+#[test]
+fn gamepad_disconnect() {
+    use bevy_ecs::event::Events;
+    use bevy_input::gamepad::{Gamepad, GamepadEvent, GamepadEventType};
+    use bevy_input::InputPlugin;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .init_resource::<ActionState<Action>>();
+
+    let mut input_map = InputMap::<Action>::new([(Action::PayRespects, GamepadButtonType::South)]);
+    input_map.set_gamepad(Gamepad(1));
+    app.insert_resource(input_map);
+
+    app.send_input_to_gamepad(GamepadButtonType::South, Some(Gamepad(1)));
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .pressed(Action::PayRespects));
+
+    // Disconnects of other gamepads are ignored
+    app.world
+        .resource_mut::<Events<GamepadEvent>>()
+        .send(GamepadEvent(Gamepad(0), GamepadEventType::Disconnected));
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .pressed(Action::PayRespects));
+
+    // The button is never released, as if the gamepad stopped reporting inputs
+    app.world
+        .resource_mut::<Events<GamepadEvent>>()
+        .send(GamepadEvent(Gamepad(1), GamepadEventType::Disconnected));
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::PayRespects));
+}
+
+#[test]
+fn release_on_focus_lost() {
+    use bevy_ecs::event::Events;
+    use bevy_input::InputPlugin;
+    use bevy_window::{WindowFocused, WindowId};
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default().release_on_focus_lost())
+        .add_event::<WindowFocused>()
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(Action::PayRespects, KeyCode::F)]));
+
+    app.send_input(KeyCode::F);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .pressed(Action::PayRespects));
+
+    // The key is never released, as the window no longer receives keyboard events
+    app.world
+        .resource_mut::<Events<WindowFocused>>()
+        .send(WindowFocused {
+            id: WindowId::primary(),
+            focused: false,
+        });
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::PayRespects));
+
+    // The stuck key does not press the action again
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::PayRespects));
+}
+
+#[test]
+fn plugin_builder() {
+    use bevy_app::CoreStage;
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::clashing_inputs::ClashStrategy;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(
+            InputManagerPlugin::<Action>::new()
+                .with_clash_strategy(ClashStrategy::PressAll)
+                .run_in(CoreStage::Update),
+        )
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([(Action::PayRespects, KeyCode::F)]));
+
+    assert_eq!(
+        *app.world.resource::<ClashStrategy>(),
+        ClashStrategy::PressAll
+    );
+
+    app.send_input(KeyCode::F);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .just_pressed(Action::PayRespects));
+}
+
+#[test]
+fn server_applies_action_diffs() {
+    use bevy_ecs::event::Events;
+    use leafwing_input_manager::action_state::ActionDiff;
+
+    #[derive(Component, Clone, PartialEq, Eq)]
+    struct StableId(u64);
+
+    let mut app = App::new();
+
+    // No `InputPlugin`: the server never reads inputs
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputManagerPlugin::<Action>::server().with_action_diffs::<StableId>());
+
+    let entity = app
+        .world
+        .spawn()
+        .insert(ActionState::<Action>::default())
+        .insert(StableId(1))
+        .id();
+
+    app.world
+        .resource_mut::<Events<ActionDiff<Action, StableId>>>()
+        .send(ActionDiff::Pressed {
+            action: Action::PayRespects,
+            id: StableId(1),
+        });
+    app.update();
+
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(entity)
+        .unwrap()
+        .pressed(Action::PayRespects));
+
+    app.world
+        .resource_mut::<Events<ActionDiff<Action, StableId>>>()
+        .send(ActionDiff::ValueChanged {
+            action: Action::PayRespects,
+            id: StableId(1),
+            value: 0.5,
+        });
+    app.update();
+
+    let action_state = app.world.get::<ActionState<Action>>(entity).unwrap();
+    assert!(action_state.pressed(Action::PayRespects));
+    assert_eq!(action_state.axis_value(Action::PayRespects), 0.5);
+}
+
+#[test]
+fn client_generates_value_diffs() {
+    use bevy_ecs::event::Events;
+    use leafwing_input_manager::action_state::ActionDiff;
+
+    #[derive(Component, Clone, PartialEq, Eq)]
+    struct StableId(u64);
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputManagerPlugin::<Action>::new().with_action_diffs::<StableId>());
+
+    let entity = app
+        .world
+        .spawn()
+        .insert(ActionState::<Action>::default())
+        .insert(StableId(1))
+        .id();
+    let mut event_reader = app
+        .world
+        .resource::<Events<ActionDiff<Action, StableId>>>()
+        .get_reader();
+
+    app.world
+        .get_mut::<ActionState<Action>>(entity)
+        .unwrap()
+        .set_axis_value(Action::PayRespects, 0.5);
+    app.update();
+
+    let events = app.world.resource::<Events<ActionDiff<Action, StableId>>>();
+    let diffs: Vec<_> = event_reader.iter(events).cloned().collect();
+    assert_eq!(diffs.len(), 1);
+    assert!(matches!(
+        diffs[0],
+        ActionDiff::ValueChanged {
+            action: Action::PayRespects,
+            id: StableId(1),
+            value,
+        } if value == 0.5
+    ));
+
+    // Tiny changes are not worth sending
+    app.world
+        .get_mut::<ActionState<Action>>(entity)
+        .unwrap()
+        .set_axis_value(Action::PayRespects, 0.505);
+    app.update();
+
+    let events = app.world.resource::<Events<ActionDiff<Action, StableId>>>();
+    assert_eq!(event_reader.iter(events).count(), 0);
+
+    // A re-inserted `ActionState` starts from scratch, so its values are sent again
+    app.world.entity_mut(entity).remove::<ActionState<Action>>();
+    app.update();
+    let mut action_state = ActionState::<Action>::default();
+    action_state.set_axis_value(Action::PayRespects, 0.505);
+    app.world.entity_mut(entity).insert(action_state);
+    app.update();
+
+    let events = app.world.resource::<Events<ActionDiff<Action, StableId>>>();
+    assert_eq!(event_reader.iter(events).count(), 1);
+}
+
+#[test]
+fn action_diff_batches() {
+    use bevy_ecs::event::Events;
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::action_state::{ActionDiff, ActionDiffBatch};
+
+    #[derive(Component, Clone, PartialEq, Eq)]
+    struct StableId(u64);
+
+    let mut client_app = App::new();
+    client_app
+        .add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::new().with_action_diff_batches::<StableId>());
+    client_app
+        .world
+        .spawn()
+        .insert_bundle(InputManagerBundle::<Action> {
+            input_map: InputMap::new([(Action::PayRespects, KeyCode::F)]),
+            ..Default::default()
+        })
+        .insert(StableId(1));
+
+    let mut server_app = App::new();
+    server_app
+        .add_plugins(MinimalPlugins)
+        .add_plugin(InputManagerPlugin::<Action>::server().with_action_diff_batches::<StableId>());
+    let server_entity = server_app
+        .world
+        .spawn()
+        .insert(ActionState::<Action>::default())
+        .insert(StableId(1))
+        .id();
+
+    client_app.send_input(KeyCode::F);
+    client_app.update();
+
+    let client_events = client_app
+        .world
+        .resource::<Events<ActionDiffBatch<Action, StableId>>>();
+    let batches: Vec<_> = client_events
+        .get_reader()
+        .iter(client_events)
+        .cloned()
+        .collect();
+    assert_eq!(batches.len(), 1);
+    assert!(matches!(
+        batches[0].diffs[..],
+        [ActionDiff::Pressed {
+            action: Action::PayRespects,
+            ..
+        }]
+    ));
+
+    let mut server_events = server_app
+        .world
+        .resource_mut::<Events<ActionDiffBatch<Action, StableId>>>();
+    for batch in batches {
+        server_events.send(batch);
+    }
+    server_app.update();
+
+    assert!(server_app
+        .world
+        .get::<ActionState<Action>>(server_entity)
+        .unwrap()
+        .pressed(Action::PayRespects));
+
+    // A re-inserted `ActionState` starts from scratch, so its values are sent again
+    let mut action_state = ActionState::<Action>::default();
+    action_state.set_axis_value(Action::PayRespects, 0.5);
+    let client_entity = client_app
+        .world
+        .spawn()
+        .insert(action_state.clone())
+        .insert(StableId(2))
+        .id();
+    client_app.update();
+    client_app
+        .world
+        .entity_mut(client_entity)
+        .remove::<ActionState<Action>>();
+    client_app.update();
+    client_app
+        .world
+        .entity_mut(client_entity)
+        .insert(action_state);
+    client_app.update();
+
+    let client_events = client_app
+        .world
+        .resource::<Events<ActionDiffBatch<Action, StableId>>>();
+    let mut event_reader = client_events.get_reader();
+    assert!(event_reader
+        .iter(client_events)
+        .any(|batch| batch.id == StableId(2)));
+}
+
+#[test]
+fn binding_capture() {
+    use bevy_input::{InputPlugin, InputSystem};
+    use leafwing_input_manager::rebinding::{
+        BindingCapture, BindingCaptureOutcome, CaptureBinding,
+    };
+    use leafwing_input_manager::systems::capture_binding;
+    use leafwing_input_manager::user_input::InputMode;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_system_to_stage(
+            CoreStage::PreUpdate,
+            capture_binding::<Action>.after(InputSystem),
+        )
+        .init_resource::<ActionState<Action>>()
+        .insert_resource(InputMap::<Action>::new([
+            (Action::PayRespects, UserInput::from(KeyCode::F)),
+            (Action::PayRespects, GamepadButtonType::South.into()),
+        ]));
+
+    // Only the bindings of the same input mode are replaced
+    app.start_binding_capture(Action::PayRespects, None);
+    app.send_input(KeyCode::G);
+    app.update();
+
+    let input_map = app.world.resource::<InputMap<Action>>();
+    let mut bindings: Vec<&UserInput> = input_map.get(Action::PayRespects).iter().collect();
+    bindings.sort_by_key(|input| input.input_modes().contains(&InputMode::Keyboard));
+    assert_eq!(
+        bindings,
+        vec![
+            &UserInput::from(GamepadButtonType::South),
+            &UserInput::from(KeyCode::G)
+        ]
+    );
+    assert!(matches!(
+        app.world
+            .resource_mut::<BindingCapture<Action>>()
+            .take_outcome(),
+        Some(BindingCaptureOutcome::Bound {
+            action: Action::PayRespects,
+            ..
+        })
+    ));
+
+    // Escape cancels capture, leaving the bindings untouched
+    app.reset_inputs();
+    app.update();
+    app.start_binding_capture(Action::PayRespects, None);
+    app.send_input(KeyCode::Escape);
+    app.update();
+
+    let input_map = app.world.resource::<InputMap<Action>>();
+    assert_eq!(input_map.get(Action::PayRespects).len(), 2);
+    assert!(input_map
+        .get(Action::PayRespects)
+        .contains(&UserInput::from(KeyCode::G)));
+    assert!(matches!(
+        app.world.resource::<BindingCapture<Action>>().outcome(),
+        Some(BindingCaptureOutcome::Cancelled {
+            action: Action::PayRespects
+        })
+    ));
+}
+
+#[test]
+fn virtual_buttons() {
+    use bevy_ecs::event::Events;
+    use bevy_input::touch::{TouchInput, TouchPhase};
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::touch::{TouchRegion, VirtualButton};
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default());
+
+    let player = app
+        .world
+        .spawn()
+        .insert_bundle(InputManagerBundle::<Action>::default())
+        .id();
+    app.world.spawn().insert(VirtualButton {
+        action: Action::PayRespects,
+        region: TouchRegion::new(Vec2::ZERO, Vec2::new(100.0, 100.0)),
+        entity: player,
+    });
+
+    // Touching outside of the region does nothing
+    app.world
+        .resource_mut::<Events<TouchInput>>()
+        .send(TouchInput {
+            phase: TouchPhase::Started,
+            position: Vec2::new(200.0, 50.0),
+            force: None,
+            id: 0,
+        });
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player)
+        .unwrap()
+        .released(Action::PayRespects));
+
+    // A second finger inside of the region presses the action
+    app.world
+        .resource_mut::<Events<TouchInput>>()
+        .send(TouchInput {
+            phase: TouchPhase::Started,
+            position: Vec2::new(50.0, 50.0),
+            force: None,
+            id: 1,
+        });
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player)
+        .unwrap()
+        .pressed(Action::PayRespects));
+
+    // Lifting the finger releases it
+    app.world
+        .resource_mut::<Events<TouchInput>>()
+        .send(TouchInput {
+            phase: TouchPhase::Ended,
+            position: Vec2::new(50.0, 50.0),
+            force: None,
+            id: 1,
+        });
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player)
+        .unwrap()
+        .released(Action::PayRespects));
+}
+
+#[test]
+fn virtual_joysticks() {
+    use bevy_ecs::event::Events;
+    use bevy_input::touch::{TouchInput, TouchPhase};
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::touch::VirtualJoystick;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default());
+
+    let player = app
+        .world
+        .spawn()
+        .insert_bundle(InputManagerBundle::<Action>::default())
+        .id();
+    app.world.spawn().insert(VirtualJoystick {
+        action: Action::PayRespects,
+        center: Vec2::new(100.0, 100.0),
+        radius: 50.0,
+        entity: player,
+    });
+
+    app.world
+        .resource_mut::<Events<TouchInput>>()
+        .send(TouchInput {
+            phase: TouchPhase::Started,
+            position: Vec2::new(100.0, 100.0),
+            force: None,
+            id: 0,
+        });
+    app.update();
+    // Dragging the finger outside of the joystick still controls it
+    app.world
+        .resource_mut::<Events<TouchInput>>()
+        .send(TouchInput {
+            phase: TouchPhase::Moved,
+            position: Vec2::new(100.0, 200.0),
+            force: None,
+            id: 0,
+        });
+    app.update();
+
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.pressed(Action::PayRespects));
+    assert_eq!(
+        action_state.axis_pair(Action::PayRespects).unwrap().xy(),
+        Vec2::new(0.0, 1.0)
+    );
+    assert_eq!(action_state.axis_value(Action::PayRespects), 1.0);
+
+    app.world
+        .resource_mut::<Events<TouchInput>>()
+        .send(TouchInput {
+            phase: TouchPhase::Ended,
+            position: Vec2::new(100.0, 200.0),
+            force: None,
+            id: 0,
+        });
+    app.update();
+
+    let action_state = app.world.get::<ActionState<Action>>(player).unwrap();
+    assert!(action_state.released(Action::PayRespects));
+    assert_eq!(action_state.axis_pair(Action::PayRespects), None);
+}
+
+#[test]
+fn input_map_changed_events() {
+    use bevy_ecs::event::{Events, ManualEventReader};
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::input_map::InputMapChanged;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_startup_system(spawn_player);
+
+    let mut reader = ManualEventReader::<InputMapChanged<Action>>::default();
+    let mut read_events = |app: &App| -> Vec<InputMapChanged<Action>> {
+        let events = app.world.resource::<Events<InputMapChanged<Action>>>();
+        reader.iter(events).cloned().collect()
+    };
+
+    // Newly added input maps are reported
+    app.update();
+    let player = app
+        .world
+        .query_filtered::<Entity, With<Player>>()
+        .iter(&app.world)
+        .next()
+        .unwrap();
+    let events = read_events(&app);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].entity, Some(player));
+    assert!(matches!(events[0].actions[..], [Action::PayRespects]));
+
+    // Accessing the input map without changing it sends no events
+    app.world.get_mut::<InputMap<Action>>(player).unwrap();
+    app.update();
+    assert!(read_events(&app).is_empty());
+
+    // Rebinding reports the rebound action
+    app.world
+        .get_mut::<InputMap<Action>>(player)
+        .unwrap()
+        .insert(Action::PayRespects, KeyCode::G);
+    app.update();
+    let events = read_events(&app);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0].actions[..], [Action::PayRespects]));
+
+    // Re-inserting a removed input map reports it as new
+    let input_map = app
+        .world
+        .entity_mut(player)
+        .remove::<InputMap<Action>>()
+        .unwrap();
+    app.update();
+    app.world.entity_mut(player).insert(input_map);
+    app.update();
+    let events = read_events(&app);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0].actions[..], [Action::PayRespects]));
+}
+
+#[test]
+fn input_context_layers() {
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::input_context::{InputContext, InputLayer};
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum MenuAction {
+        Confirm,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_plugin(InputManagerPlugin::<MenuAction>::default())
+        .add_startup_system(spawn_player)
+        .init_resource::<ActionState<MenuAction>>()
+        .insert_resource(InputMap::<MenuAction>::new([(
+            MenuAction::Confirm,
+            KeyCode::F,
+        )]))
+        .init_resource::<InputContext>();
+
+    app.world
+        .resource_mut::<InputContext>()
+        .push(InputLayer::new::<Action>());
+    app.send_input(KeyCode::F);
+    app.update();
+
+    let mut query = app.world.query::<&ActionState<Action>>();
+    assert!(query
+        .iter(&app.world)
+        .next()
+        .unwrap()
+        .pressed(Action::PayRespects));
+    assert!(!app
+        .world
+        .resource::<ActionState<MenuAction>>()
+        .pressed(MenuAction::Confirm));
+
+    // Opening the menu releases gameplay actions, even though their inputs are held
+    app.world
+        .resource_mut::<InputContext>()
+        .push(InputLayer::new::<MenuAction>());
+    app.update();
+
+    assert!(query
+        .iter(&app.world)
+        .next()
+        .unwrap()
+        .just_released(Action::PayRespects));
+    // Inputs held from before the menu was opened do not leak into it
+    assert!(app
+        .world
+        .resource::<ActionState<MenuAction>>()
+        .released(MenuAction::Confirm));
+
+    app.release_input(KeyCode::F);
+    app.update();
+    app.send_input(KeyCode::F);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<MenuAction>>()
+        .pressed(MenuAction::Confirm));
+
+    // Passthrough layers do not block the layers beneath them
+    app.world
+        .resource_mut::<InputContext>()
+        .push(InputLayer::named("Tooltip").passthrough());
+    app.update();
+
+    assert!(app
+        .world
+        .resource::<ActionState<MenuAction>>()
+        .pressed(MenuAction::Confirm));
+
+    // Closing the menu does not press gameplay actions whose inputs were held the whole time
+    app.world.resource_mut::<InputContext>().pop();
+    app.world.resource_mut::<InputContext>().pop();
+    app.update();
+
+    assert!(query
+        .iter(&app.world)
+        .next()
+        .unwrap()
+        .released(Action::PayRespects));
+
+    // Until those inputs are released and pressed again
+    app.release_input(KeyCode::F);
+    app.update();
+    app.send_input(KeyCode::F);
+    app.update();
+
+    assert!(query
+        .iter(&app.world)
+        .next()
+        .unwrap()
+        .just_pressed(Action::PayRespects));
+}
+
+#[test]
+fn reconcile_suppressed_actions() {
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::input_context::{InputContext, InputLayer};
+    use leafwing_input_manager::systems::reconcile_action_state;
+    use leafwing_input_manager::user_input::InputStreams;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum MenuAction {
+        Confirm,
+    }
+
+    let input_map = InputMap::<Action>::new([(Action::PayRespects, KeyCode::F)]);
+    let mut keyboard = Input::<KeyCode>::default();
+    keyboard.press(KeyCode::F);
+    let mut action_state = ActionState::<Action>::default();
+    action_state.update(input_map.which_pressed(
+        &InputStreams::from_keyboard(&keyboard),
+        ClashStrategy::PressAll,
+    ));
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_system(reconcile_action_state::<Action>)
+        .init_resource::<ClashStrategy>()
+        .insert_resource(action_state)
+        .insert_resource(input_map)
+        .init_resource::<InputContext>();
+
+    app.send_input(KeyCode::F);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .pressed(Action::PayRespects));
+
+    // Suppressed actions are released, even though their inputs are still held
+    app.world
+        .resource_mut::<InputContext>()
+        .push(InputLayer::new::<MenuAction>());
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Action>>()
+        .released(Action::PayRespects));
+}
+
+#[test]
+fn manual_cooldowns_are_kept() {
+    use bevy_input::InputPlugin;
+    use bevy_utils::Duration;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_startup_system(spawn_player);
+    app.update();
+
+    let mut query = app.world.query::<&mut ActionState<Action>>();
+    query
+        .iter_mut(&mut app.world)
+        .next()
+        .unwrap()
+        .set_cooldown(Action::PayRespects, Duration::from_secs(1));
+    app.update();
+
+    // The input map of the player has no cooldown for this action, so it leaves the manual one alone
+    let cooldown = query
+        .iter(&app.world)
+        .next()
+        .unwrap()
+        .cooldown(Action::PayRespects)
+        .unwrap();
+    assert_eq!(cooldown.duration(), Duration::from_secs(1));
+
+    // But a cooldown set on the map takes precedence
+    let mut input_map_query = app.world.query::<&mut InputMap<Action>>();
+    input_map_query
+        .iter_mut(&mut app.world)
+        .next()
+        .unwrap()
+        .set_cooldown(Action::PayRespects, Duration::from_secs(2));
+    app.update();
+    let cooldown = query
+        .iter(&app.world)
+        .next()
+        .unwrap()
+        .cooldown(Action::PayRespects)
+        .unwrap();
+    assert_eq!(cooldown.duration(), Duration::from_secs(2));
+
+    // And is removed again once it is cleared from the map
+    input_map_query
+        .iter_mut(&mut app.world)
+        .next()
+        .unwrap()
+        .clear_cooldown(Action::PayRespects);
+    app.update();
+    assert!(query
+        .iter(&app.world)
+        .next()
+        .unwrap()
+        .cooldown(Action::PayRespects)
+        .is_none());
+}
+
+#[test]
+fn action_state_without_input_map() {
+    use bevy_input::InputPlugin;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::default());
+
+    // Controlled by AI, rather than by an input map
+    let bot = app
+        .world
+        .spawn()
+        .insert(ActionState::<Action>::default())
+        .id();
+    app.update();
+
+    app.world
+        .get_mut::<ActionState<Action>>(bot)
+        .unwrap()
+        .press(Action::PayRespects);
+
+    // The action is still ticked, and stays pressed until released by code
+    for _ in 0..3 {
+        app.update();
+        let action_state = app.world.get::<ActionState<Action>>(bot).unwrap();
+        assert!(action_state.pressed(Action::PayRespects));
+        assert!(!action_state.just_pressed(Action::PayRespects));
+    }
+
+    app.world
+        .get_mut::<ActionState<Action>>(bot)
+        .unwrap()
+        .release(Action::PayRespects);
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(bot)
+        .unwrap()
+        .released(Action::PayRespects));
+}
+
+#[test]
+fn axis_events() {
+    use bevy_ecs::event::{Events, ManualEventReader};
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::action_state::AxisChanged;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Action>::new().with_axis_events())
+        .add_startup_system(spawn_player);
+
+    let mut reader = ManualEventReader::<AxisChanged<Action>>::default();
+    let mut read_values = |app: &App| -> Vec<f32> {
+        let events = app.world.resource::<Events<AxisChanged<Action>>>();
+        reader.iter(events).map(|event| event.value).collect()
+    };
+
+    app.update();
+    assert!(read_values(&app).is_empty());
+
+    app.send_input(KeyCode::F);
+    app.update();
+    assert_eq!(read_values(&app), vec![1.0]);
+
+    // Unchanged values send no events
+    app.update();
+    assert!(read_values(&app).is_empty());
+
+    app.release_input(KeyCode::F);
+    app.update();
+    assert_eq!(read_values(&app), vec![0.0]);
+
+    // Disabling actions releases them, which is reported even though the actions no longer update
+    app.send_input(KeyCode::F);
+    app.update();
+    assert_eq!(read_values(&app), vec![1.0]);
+    app.world.resource_mut::<ToggleActions<Action>>().enabled = false;
+    app.update();
+    assert_eq!(read_values(&app), vec![0.0]);
+    app.update();
+    assert!(read_values(&app).is_empty());
+}
+
+#[test]
+fn per_map_clash_strategy() {
+    use bevy_input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum Shortcut {
+        Save,
+        MoveDown,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(
+            InputManagerPlugin::<Shortcut>::new()
+                .with_clash_strategy(ClashStrategy::PrioritizeLongest),
+        );
+
+    let mut input_map = InputMap::<Shortcut>::default();
+    input_map
+        .insert_chord(Shortcut::Save, [KeyCode::LControl, KeyCode::S])
+        .insert(Shortcut::MoveDown, KeyCode::S);
+
+    let global = app
+        .world
+        .spawn()
+        .insert_bundle(InputManagerBundle {
+            input_map: input_map.clone(),
+            action_state: ActionState::default(),
+        })
+        .id();
+    let overridden = app
+        .world
+        .spawn()
+        .insert_bundle(InputManagerBundle {
+            input_map: input_map
+                .set_clash_strategy(ClashStrategy::PressAll)
+                .build(),
+            action_state: ActionState::default(),
+        })
+        .id();
+
+    app.send_input(KeyCode::LControl);
+    app.send_input(KeyCode::S);
+    app.update();
+
+    let action_state = app.world.get::<ActionState<Shortcut>>(global).unwrap();
+    assert!(action_state.pressed(Shortcut::Save));
+    assert!(!action_state.pressed(Shortcut::MoveDown));
+
+    let action_state = app.world.get::<ActionState<Shortcut>>(overridden).unwrap();
+    assert!(action_state.pressed(Shortcut::Save));
+    assert!(action_state.pressed(Shortcut::MoveDown));
+}
+
+#[test]
+fn chord_release() {
+    use bevy_input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum ChordAction {
+        OneAndTwo,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<ChordAction>::default())
+        .init_resource::<ActionState<ChordAction>>()
+        .insert_resource(
+            InputMap::<ChordAction>::default()
+                .insert_chord(ChordAction::OneAndTwo, [KeyCode::Key1, KeyCode::Key2])
+                .build(),
+        );
+
+    app.send_input(KeyCode::Key1);
+    app.send_input(KeyCode::Key2);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<ChordAction>>()
+        .just_pressed(ChordAction::OneAndTwo));
+
+    // Releasing either button releases the whole chord
+    app.release_input(KeyCode::Key1);
+    app.update();
+    let action_state = app.world.resource::<ActionState<ChordAction>>();
+    assert!(action_state.released(ChordAction::OneAndTwo));
+    assert!(action_state.just_released(ChordAction::OneAndTwo));
+
+    // Key2 is still held, but the chord is neither pressed nor released again
+    app.update();
+    let action_state = app.world.resource::<ActionState<ChordAction>>();
+    assert!(action_state.released(ChordAction::OneAndTwo));
+    assert!(!action_state.just_released(ChordAction::OneAndTwo));
+}
+
+#[test]
+fn per_entity_action_priority() {
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::clashing_inputs::ActionPriority;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum Shortcut {
+        Save,
+        MoveDown,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Shortcut>::default());
+
+    let mut input_map = InputMap::<Shortcut>::default();
+    input_map
+        .insert_chord(Shortcut::Save, [KeyCode::LControl, KeyCode::S])
+        .insert(Shortcut::MoveDown, KeyCode::S);
+
+    let unprioritized = app
+        .world
+        .spawn()
+        .insert_bundle(InputManagerBundle {
+            input_map: input_map.clone(),
+            action_state: ActionState::default(),
+        })
+        .id();
+    let mut action_priority = ActionPriority::default();
+    action_priority.set(Shortcut::MoveDown, 1);
+    let prioritized = app
+        .world
+        .spawn()
+        .insert_bundle(InputManagerBundle {
+            input_map: input_map.clone(),
+            action_state: ActionState::default(),
+        })
+        .insert(action_priority.clone())
+        .id();
+    // The clash strategy of the map takes precedence over the priorities of its entity
+    let overridden = app
+        .world
+        .spawn()
+        .insert_bundle(InputManagerBundle {
+            input_map: input_map
+                .set_clash_strategy(ClashStrategy::UseActionOrder)
+                .build(),
+            action_state: ActionState::default(),
+        })
+        .insert(action_priority)
+        .id();
+
+    app.send_input(KeyCode::LControl);
+    app.send_input(KeyCode::S);
+    app.update();
+
+    // Without priorities, the longer chord wins
+    let action_state = app
+        .world
+        .get::<ActionState<Shortcut>>(unprioritized)
+        .unwrap();
+    assert!(action_state.pressed(Shortcut::Save));
+    assert!(!action_state.pressed(Shortcut::MoveDown));
+
+    let action_state = app.world.get::<ActionState<Shortcut>>(prioritized).unwrap();
+    assert!(!action_state.pressed(Shortcut::Save));
+    assert!(action_state.pressed(Shortcut::MoveDown));
+
+    let action_state = app.world.get::<ActionState<Shortcut>>(overridden).unwrap();
+    assert!(action_state.pressed(Shortcut::Save));
+    assert!(!action_state.pressed(Shortcut::MoveDown));
+}
+
+#[test]
+fn mouse_motion_config() {
+    use bevy::ecs::event::Events;
+    use bevy::input::mouse::MouseMotion;
+    use bevy_input::InputPlugin;
+    use leafwing_input_manager::user_input::{MouseMotionConfig, MouseMotionDirection};
+    use std::thread::sleep;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum CameraAction {
+        LookUp,
+    }
+
+    let mut app = App::new();
+
+    let mut config = MouseMotionConfig::default();
+    config.sensitivity = Vec2::new(1.0, 2.0);
+    config.invert_y = true;
+    config.smoothing = Some(0.5);
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<CameraAction>::default())
+        .init_resource::<ActionState<CameraAction>>()
+        .insert_resource(InputMap::<CameraAction>::new([(
+            CameraAction::LookUp,
+            MouseMotionDirection::Up,
+        )]))
+        .insert_resource(config);
+
+    // Moving the mouse down looks up, as the y-axis is inverted
+    app.world
+        .resource_mut::<Events<MouseMotion>>()
+        .send(MouseMotion {
+            delta: Vec2::new(0.0, 5.0),
+        });
+    app.update();
+    let action_state = app.world.resource::<ActionState<CameraAction>>();
+    assert!(action_state.pressed(CameraAction::LookUp));
+    assert_eq!(action_state.mouse_motion_value(CameraAction::LookUp), 5.0);
+
+    // Smoothing carries the motion into the following frames, decaying over time
+    sleep(MouseMotionConfig::SMOOTHING_FRAME);
+    app.update();
+    let action_state = app.world.resource::<ActionState<CameraAction>>();
+    assert!(action_state.pressed(CameraAction::LookUp));
+    let smoothed = action_state.mouse_motion_value(CameraAction::LookUp);
+    assert!(smoothed > 0.0 && smoothed <= 2.5);
+
+    // The smoothed motion eventually stops, releasing the action
+    for _ in 0..10 {
+        sleep(MouseMotionConfig::SMOOTHING_FRAME);
+        app.update();
+    }
+    let action_state = app.world.resource::<ActionState<CameraAction>>();
+    assert!(action_state.released(CameraAction::LookUp));
+    assert_eq!(action_state.mouse_motion_value(CameraAction::LookUp), 0.0);
+}
+
+#[test]
+fn shortcut_requires_fresh_trigger() {
+    use bevy_input::InputPlugin;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum Shortcut {
+        Save,
+    }
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputPlugin)
+        .add_plugin(InputManagerPlugin::<Shortcut>::default())
+        .init_resource::<ActionState<Shortcut>>()
+        .insert_resource(
+            InputMap::<Shortcut>::default()
+                .insert_shortcut(Shortcut::Save, [KeyCode::LControl, KeyCode::S])
+                .build(),
+        );
+
+    // Hold Ctrl, then tap S twice
+    app.send_input(KeyCode::LControl);
+    app.update();
+    for _ in 0..2 {
+        app.send_input(KeyCode::S);
+        app.update();
+        assert!(app
+            .world
+            .resource::<ActionState<Shortcut>>()
+            .just_pressed(Shortcut::Save));
+
+        app.release_input(KeyCode::S);
+        app.update();
+        assert!(app
+            .world
+            .resource::<ActionState<Shortcut>>()
+            .just_released(Shortcut::Save));
+    }
+
+    // Pressing Ctrl while S is already held does not trigger the shortcut
+    app.release_input(KeyCode::LControl);
+    app.send_input(KeyCode::S);
+    app.update();
+    app.send_input(KeyCode::LControl);
+    app.update();
+    assert!(app
+        .world
+        .resource::<ActionState<Shortcut>>()
+        .released(Shortcut::Save));
+}
+
 #[test]
 #[cfg(feature = "ui")]
 fn action_state_driver() {
@@ -152,10 +2146,7 @@ fn action_state_driver() {
             .spawn()
             .insert(ButtonMarker)
             .insert(Interaction::None)
-            .insert(ActionStateDriver::<Action> {
-                action: Action::PayRespects,
-                entity: player_entity,
-            });
+            .insert(ActionStateDriver::new(Action::PayRespects, player_entity));
     }
 
     app.add_plugins(MinimalPlugins)
@@ -198,3 +2189,153 @@ fn action_state_driver() {
     let respect = app.world.resource::<Respect>();
     assert_eq!(*respect, Respect(false));
 }
+
+#[test]
+#[cfg(feature = "ui")]
+fn action_state_driver_with_actions() {
+    use bevy_input::InputPlugin;
+    use bevy_ui::Interaction;
+
+    #[derive(Actionlike, Clone, Copy, Debug)]
+    enum MenuAction {
+        Confirm,
+        Close,
+        Back,
+    }
+
+    #[derive(Component)]
+    struct ButtonMarker;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputManagerPlugin::<MenuAction>::default())
+        .add_plugin(InputPlugin);
+
+    let player = app
+        .world
+        .spawn()
+        .insert_bundle(InputManagerBundle::<MenuAction>::default())
+        .id();
+    app.world
+        .spawn()
+        .insert(ButtonMarker)
+        .insert(Interaction::None)
+        .insert(ActionStateDriver::with_actions(
+            [MenuAction::Confirm, MenuAction::Close],
+            player,
+        ));
+
+    // Clicking the button presses every one of its actions
+    app.click_button::<ButtonMarker>();
+    app.update();
+    let action_state = app.world.get::<ActionState<MenuAction>>(player).unwrap();
+    assert!(action_state.pressed(MenuAction::Confirm));
+    assert!(action_state.pressed(MenuAction::Close));
+    assert!(action_state.released(MenuAction::Back));
+
+    // And releasing it releases all of them
+    app.hover_button::<ButtonMarker>();
+    app.update();
+    let action_state = app.world.get::<ActionState<MenuAction>>(player).unwrap();
+    assert!(action_state.released(MenuAction::Confirm));
+    assert!(action_state.released(MenuAction::Close));
+}
+
+#[test]
+#[cfg(feature = "ui")]
+fn action_state_driver_releases() {
+    use bevy_input::InputPlugin;
+    use bevy_ui::Interaction;
+
+    #[derive(Component)]
+    struct ButtonMarker;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_plugin(InputPlugin);
+
+    // Without an `InputMap`, nothing else releases the action
+    let player = app
+        .world
+        .spawn()
+        .insert(ActionState::<Action>::default())
+        .id();
+    app.world
+        .spawn()
+        .insert(ButtonMarker)
+        .insert(Interaction::None)
+        .insert(ActionStateDriver::new(Action::PayRespects, player));
+
+    app.click_button::<ButtonMarker>();
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player)
+        .unwrap()
+        .pressed(Action::PayRespects));
+
+    // Holding the button keeps the action pressed
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player)
+        .unwrap()
+        .pressed(Action::PayRespects));
+
+    app.hover_button::<ButtonMarker>();
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player)
+        .unwrap()
+        .released(Action::PayRespects));
+}
+
+#[test]
+#[cfg(feature = "ui")]
+fn action_state_driver_releases_on_despawn() {
+    use bevy_input::InputPlugin;
+    use bevy_ui::Interaction;
+
+    #[derive(Component)]
+    struct ButtonMarker;
+
+    let mut app = App::new();
+
+    app.add_plugins(MinimalPlugins)
+        .add_plugin(InputManagerPlugin::<Action>::default())
+        .add_plugin(InputPlugin);
+
+    let player = app
+        .world
+        .spawn()
+        .insert(ActionState::<Action>::default())
+        .id();
+    let button = app
+        .world
+        .spawn()
+        .insert(ButtonMarker)
+        .insert(Interaction::None)
+        .insert(ActionStateDriver::new(Action::PayRespects, player))
+        .id();
+
+    app.click_button::<ButtonMarker>();
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player)
+        .unwrap()
+        .pressed(Action::PayRespects));
+
+    // Without an `InputMap`, only despawning the clicked button can release its actions
+    app.world.despawn(button);
+    app.update();
+    assert!(app
+        .world
+        .get::<ActionState<Action>>(player)
+        .unwrap()
+        .released(Action::PayRespects));
+}
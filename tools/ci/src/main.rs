@@ -16,4 +16,9 @@ fn main() {
     cmd!("cargo clippy --workspace --all-features -- -D warnings -A clippy::type_complexity")
         .run()
         .expect("Please fix clippy errors in output above.");
+
+    // Run the tests that only compile with optional features enabled
+    cmd!("cargo test --features action_source")
+        .run()
+        .expect("Please fix failing tests in output above.");
 }